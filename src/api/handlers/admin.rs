@@ -0,0 +1,125 @@
+use crate::api::schemas::{DealInfo, ErrorResponse, GameDealsResponse};
+use crate::game::hand::Hand;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::player::repository::PlayerRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Regenerate the hands of every deal in a game
+///
+/// Rebuilds each deal from its stored shuffle seed, so disputed or buggy deals
+/// can be reproduced exactly. Only users in the `admin_users` set may call it.
+#[utoipa::path(
+    get,
+    path = "/admin/games/{game_id}/deals",
+    tag = "Admin",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("game_id" = String, Path, description = "Game to inspect")
+    ),
+    responses(
+        (status = 200, description = "Deals regenerated", body = GameDealsResponse),
+        (status = 403, description = "Admin access required", body = ErrorResponse),
+        (status = 404, description = "No deals recorded for this game", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Regenerate a game's deals",
+    description = "Returns the seed, redeal count and regenerated hands for every deal of a game. Admin only."
+)]
+#[axum::debug_handler]
+pub async fn get_game_deals_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Path(game_id): Path<String>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // Only admins may see other players' hands
+    match PlayerRepository::is_admin(&mut conn, &user_id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse {
+                    error: "Admin access required".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    let records = match GameStateRepository::get_deal_records(&mut conn, &game_id).await {
+        Ok(records) if records.is_empty() => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "No deals recorded for this game".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Ok(records) => records,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // Regenerate each deal from its seed
+    let deals = records
+        .iter()
+        .enumerate()
+        .map(|(deal_number, record)| DealInfo {
+            deal_number: deal_number as u32,
+            seed: record.seed.to_string(),
+            attempts: record.attempts,
+            hands: record
+                .regenerate_hands()
+                .into_iter()
+                .enumerate()
+                .map(|(position, cards)| Hand::new(cards, position).to_codes())
+                .collect(),
+        })
+        .collect();
+
+    let response = GameDealsResponse { game_id, deals };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,377 @@
+use crate::api::handlers::{game_bidding, game_playing};
+use crate::api::schemas::{
+    AddBotRequest, AddBotResponse, BidRequest, CardPlayRequest, ErrorResponse, PlayerInfo,
+};
+use crate::bots;
+use crate::game::bot::BotDifficulty;
+use crate::game::engine::GameAction;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::lobby::LobbyRepository;
+use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::notification::repository::NotificationRepository;
+use crate::redis::player::repository::{player_in_seat, PlayerRepository, BOT_ROLE};
+use crate::redis::trick_state::TrickStateRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::time::Duration;
+
+/// Pause before each bot action so humans can follow the table
+const BOT_THINK_TIME: Duration = Duration::from_millis(800);
+
+/// Upper bound on bot actions per run (a full game is under 40)
+const MAX_BOT_ACTIONS: usize = 200;
+
+/// Add a bot to the match
+///
+/// Fills an empty seat with a server-controlled player so a match can start
+/// with fewer than four humans. Only the host can add bots, and only while
+/// the match is waiting for players.
+#[utoipa::path(
+    post,
+    path = "/normal-match/bots",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body(content = Option<AddBotRequest>, description = "Optional bot settings"),
+    responses(
+        (status = 200, description = "Bot added", body = AddBotResponse),
+        (status = 400, description = "Not in a game, match is full or unknown difficulty", body = ErrorResponse),
+        (status = 403, description = "Only the host can add bots", body = ErrorResponse),
+        (status = 409, description = "Match is no longer waiting for players", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Add a bot player",
+    description = "Adds a bot to an empty seat. Bots bid and play automatically when it is their turn. Difficulty is \"normal\" (rule-based, default), \"hard\" (Monte Carlo search) or \"external:<name>\" for an engine the server operator configured in SJAVS_EXTERNAL_BOTS. Host only."
+)]
+#[axum::debug_handler]
+pub async fn add_bot_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    payload: Option<Json<AddBotRequest>>,
+) -> Response {
+    let difficulty = match payload.and_then(|Json(request)| request.difficulty) {
+        None => BotDifficulty::default(),
+        Some(value) => match BotDifficulty::parse(&value).filter(bots::is_available) {
+            Some(difficulty) => difficulty,
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse {
+                        error: "Unknown bot difficulty".to_string(),
+                        message: Some(
+                            "Difficulty must be \"normal\", \"hard\" or \"external:<name>\" for a configured engine"
+                                .to_string(),
+                        ),
+                    }),
+                )
+                    .into_response();
+            }
+        },
+    };
+
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get the host's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must be in a game to add a bot".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Bots can only join a match that hasn't started
+    match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) if game_match.status == NormalMatchStatus::Waiting => {}
+        Ok(Some(_)) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    error: "Game not joinable".to_string(),
+                    message: Some("Bots can only be added before the game starts".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    // 3. Only the host can fill seats, and only up to four players
+    let players = match PlayerRepository::get_players_in_game(&mut conn, &game_id).await {
+        Ok(players) => players,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get players: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if !players
+        .iter()
+        .any(|p| p.user_id == user_id && p.role == "host")
+    {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: "Only host can add bots".to_string(),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    if players.len() >= 4 {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Match is full".to_string(),
+                message: Some("A match has exactly 4 seats".to_string()),
+            }),
+        )
+            .into_response();
+    }
+
+    // 4. Seat the bot like any other player
+    let bot_id = format!("bot_{}", uuid::Uuid::new_v4().simple());
+    let bot_name = format!("Bot {}", players.iter().filter(|p| p.is_bot()).count() + 1);
+
+    if let Err(e) = NormalMatchRepository::add_player(&mut conn, &game_id, &bot_id, BOT_ROLE).await
+    {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("Failed to add bot to game: {}", e),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    let _ = redis::cmd("HSET")
+        .arg("usernames")
+        .arg(&bot_id)
+        .arg(&bot_name)
+        .query_async::<_, ()>(&mut conn)
+        .await;
+
+    if let Err(e) =
+        PlayerRepository::set_bot_difficulty(&mut conn, &game_id, &bot_id, &difficulty).await
+    {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e,
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    if let Err(e) = LobbyRepository::refresh(&mut conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
+    let mut players: Vec<PlayerInfo> = players
+        .into_iter()
+        .map(|p| PlayerInfo {
+            user_id: p.user_id,
+            role: p.role,
+        })
+        .collect();
+    players.push(PlayerInfo {
+        user_id: bot_id.clone(),
+        role: BOT_ROLE.to_string(),
+    });
+
+    // 5. Tell the humans at the table
+    let human_players = players
+        .iter()
+        .filter(|p| p.role != BOT_ROLE)
+        .map(|p| p.user_id.clone())
+        .collect();
+    if let Err(e) = NotificationRepository::publish_player_joined(
+        &mut conn,
+        &game_id,
+        &bot_id,
+        &bot_name,
+        human_players,
+    )
+    .await
+    {
+        eprintln!("Failed to publish bot joined event: {}", e);
+    }
+
+    let response = AddBotResponse {
+        message: format!("{} joined the game", bot_name),
+        game_id,
+        bot_id,
+        bot_name,
+        difficulty: difficulty.to_string(),
+        players,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Let bots take their turns in the background
+///
+/// Called after a human acts. Bots go through the same handlers as humans,
+/// so every rule check, state update and broadcast is shared.
+pub fn spawn_bot_turns(redis_pool: RedisPool, game_id: String) {
+    tokio::spawn(async move {
+        if let Err(e) = run_bot_turns(&redis_pool, &game_id).await {
+            eprintln!("Bot turn failed in game {}: {}", game_id, e);
+        }
+    });
+}
+
+/// Play bot turns until a human is to act or the game stops
+async fn run_bot_turns(redis_pool: &RedisPool, game_id: &str) -> Result<(), String> {
+    for _ in 0..MAX_BOT_ACTIONS {
+        let Some((bot_id, action)) = next_bot_action(redis_pool, game_id).await? else {
+            return Ok(());
+        };
+
+        tokio::time::sleep(BOT_THINK_TIME).await;
+
+        let response = match action {
+            GameAction::Bid { length, suit, .. } => {
+                game_bidding::make_bid_handler(
+                    Extension(bot_id.clone()),
+                    State(redis_pool.clone()),
+                    Json(BidRequest { length, suit }),
+                )
+                .await
+            }
+            GameAction::Pass { .. } => {
+                game_bidding::pass_bid_handler(Extension(bot_id.clone()), State(redis_pool.clone()))
+                    .await
+            }
+            GameAction::PlayCard { card, .. } => {
+                game_playing::play_card_handler(
+                    Extension(bot_id.clone()),
+                    State(redis_pool.clone()),
+                    Json(CardPlayRequest { card: card.code() }),
+                )
+                .await
+            }
+        };
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Action by {} was rejected with status {}",
+                bot_id,
+                response.status()
+            ));
+        }
+    }
+
+    Err(format!("Stopped after {} bot actions", MAX_BOT_ACTIONS))
+}
+
+/// The bot whose turn it is and the action it chooses, if a bot is to act
+async fn next_bot_action(
+    redis_pool: &RedisPool,
+    game_id: &str,
+) -> Result<Option<(String, GameAction)>, String> {
+    let mut conn = redis_pool
+        .get()
+        .await
+        .map_err(|e| format!("Failed to get Redis connection: {}", e))?;
+
+    let game_match = match NormalMatchRepository::get_by_id(&mut conn, game_id).await? {
+        Some(game_match) => game_match,
+        None => return Ok(None),
+    };
+
+    let tricks = match game_match.status {
+        NormalMatchStatus::Bidding => None,
+        NormalMatchStatus::Playing => TrickStateRepository::get_trick_state(&mut conn, game_id)
+            .await
+            .map_err(|e| format!("Failed to get trick state: {}", e))?,
+        _ => return Ok(None),
+    };
+
+    let players = PlayerRepository::get_players_in_game(&mut conn, game_id).await?;
+    let hands = GameStateRepository::get_dealt_hands(&mut conn, game_id).await?;
+    let game = game_match.to_engine(hands, tricks)?;
+
+    let Some(seat) = game.current_player() else {
+        return Ok(None);
+    };
+    let bot_id = match player_in_seat(&players, seat) {
+        Some(player) if player.is_bot() => player.user_id.clone(),
+        _ => return Ok(None),
+    };
+    let difficulty = PlayerRepository::get_bot_difficulty(&mut conn, game_id, &bot_id).await?;
+    let bidding = GameStateRepository::get_bidding_actions(&mut conn, game_id).await?;
+    drop(conn);
+
+    // Searching or waiting on an external engine can take a while - keep it off the async workers
+    let action = tokio::task::spawn_blocking(move || {
+        difficulty.choose_action(&game, seat, &bidding, Some(bots::choose_action))
+    })
+    .await
+    .map_err(|e| format!("Bot search failed: {}", e))?;
+
+    Ok(action.map(|action| (bot_id, action)))
+}
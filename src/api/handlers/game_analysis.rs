@@ -0,0 +1,183 @@
+use crate::api::schemas::{BidReview, CardMistake, ErrorResponse, GameAnalysisResponse};
+use crate::game::analysis;
+use crate::game::engine::Bid;
+use crate::game::trick::PlayedDeal;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::id::NormalMatch;
+use crate::redis::trick_state::repository::TrickStateRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Archive the bid and tricks of the deal that just finished
+///
+/// Called from game completion before the next deal is dealt, while the
+/// match still holds the winning bid and the trick history is intact.
+pub async fn archive_finished_deal(
+    conn: &mut deadpool_redis::Connection,
+    game_match: &NormalMatch,
+) -> Result<(), String> {
+    let bid = match (
+        game_match.trump_declarer,
+        game_match.highest_bid_length,
+        &game_match.trump_suit,
+    ) {
+        (Some(player), Some(length), Some(suit)) => Bid {
+            player,
+            length,
+            suit: suit.clone(),
+        },
+        _ => return Err("No winning bid recorded for this deal".to_string()),
+    };
+
+    let records = GameStateRepository::get_deal_records(conn, &game_match.id).await?;
+    if records.is_empty() {
+        return Err("No deal recorded for this game".to_string());
+    }
+
+    let deal = PlayedDeal {
+        dealer: game_match.dealer_position.unwrap_or(0),
+        bid,
+        bidding: GameStateRepository::get_bidding_actions(conn, &game_match.id).await?,
+        tricks: TrickStateRepository::get_trick_history(conn, &game_match.id).await?,
+        hints_used: GameStateRepository::get_hint_uses(conn, &game_match.id).await?,
+    };
+
+    TrickStateRepository::archive_played_deal(conn, &game_match.id, records.len() - 1, &deal).await
+}
+
+/// Analyse a finished deal
+///
+/// Replays every card against a double-dummy solver and lists the cards that
+/// cost their team points, along with a review of the winning bid.
+#[utoipa::path(
+    get,
+    path = "/games/{game_id}/deals/{deal_number}/analysis",
+    tag = "Game Playing",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("game_id" = String, Path, description = "Game the deal belongs to"),
+        ("deal_number" = u32, Path, description = "Deal to analyse (0 = first deal)")
+    ),
+    responses(
+        (status = 200, description = "Deal analysed", body = GameAnalysisResponse),
+        (status = 404, description = "No finished deal with this number", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Analyse a finished deal",
+    description = "Compares every card played in a finished deal with perfect (double-dummy) play. Each card that lowered its team's best reachable result is listed with its point cost, and the winning bid is judged against the declarer's hand."
+)]
+#[axum::debug_handler]
+pub async fn get_deal_analysis_handler(
+    State(redis_pool): State<RedisPool>,
+    Path((game_id, deal_number)): Path<(String, u32)>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Only deals that were played to the end are archived
+    let deal = match TrickStateRepository::get_played_deal(
+        &mut conn,
+        &game_id,
+        deal_number as usize,
+    )
+    .await
+    {
+        Ok(Some(deal)) => deal,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Deal not found".to_string(),
+                    message: Some(
+                        "Only deals that were played to the end can be analysed".to_string(),
+                    ),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+    drop(conn);
+
+    // 2. Solving every position takes a while - keep it off the async workers
+    let analysis = match tokio::task::spawn_blocking(move || analysis::analyze_deal(&deal)).await {
+        Ok(Ok(analysis)) => analysis,
+        Ok(Err(e)) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to analyse deal: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Analysis task failed: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 3. Shape the report
+    let response = GameAnalysisResponse {
+        game_id,
+        deal_number,
+        trump_team: vec![analysis.trump_team.0 as u8, analysis.trump_team.1 as u8],
+        trump_team_points: analysis.trump_team_points,
+        bid: BidReview {
+            declarer_position: analysis.bid.declarer as u8,
+            length: analysis.bid.length,
+            suit: analysis.bid.suit,
+            trumps_held: analysis.bid.trumps_held,
+            sound: analysis.bid.sound,
+            double_dummy_points: analysis.bid.double_dummy_points,
+            makeable: analysis.bid.makeable,
+        },
+        mistakes: analysis
+            .mistakes
+            .into_iter()
+            .map(|mistake| CardMistake {
+                trick_number: mistake.trick_number,
+                player_position: mistake.player as u8,
+                card: mistake.card.code(),
+                best_card: mistake.best_card.code(),
+                point_cost: mistake.point_cost,
+            })
+            .collect(),
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
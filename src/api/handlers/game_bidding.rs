@@ -1,12 +1,14 @@
+use crate::api::handlers::{bots, game_fairness, game_start, rule_violation};
 use crate::api::schemas::{
     BidDetails, BidRequest, BidResponse, BiddingGameState, ErrorResponse, PassResponse, PlayerInfo,
+    RuleViolationResponse,
 };
-use crate::game::deck::Deck;
-use crate::game::hand::Hand;
+use crate::game::engine::{GameAction, GameEvent, SjavsGame};
 use crate::redis::game_state::repository::GameStateRepository;
 use crate::redis::normal_match::repository::NormalMatchRepository;
-use crate::redis::player::repository::PlayerRepository;
+use crate::redis::player::repository::{seat_of, PlayerRepository};
 use crate::redis::pubsub::broadcasting;
+use crate::redis::trick_state::TrickStateRepository;
 use crate::RedisPool;
 use axum::http::StatusCode;
 use axum::{
@@ -30,8 +32,8 @@ use axum::{
     request_body = BidRequest,
     responses(
         (status = 200, description = "Bid made successfully", body = BidResponse),
-        (status = 400, description = "Invalid bid or game state", body = ErrorResponse),
-        (status = 403, description = "Not your turn to bid", body = ErrorResponse),
+        (status = 400, description = "Bid breaks a rule; the response lists the legal bids", body = RuleViolationResponse),
+        (status = 409, description = "Not your turn to bid", body = RuleViolationResponse),
         (status = 404, description = "Game not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
@@ -123,7 +125,7 @@ pub async fn make_bid_handler(
         }
     };
 
-    let player_position = match players.iter().position(|p| p.user_id == user_id) {
+    let player_position = match seat_of(&players, &user_id) {
         Some(pos) => pos,
         None => {
             return (
@@ -137,56 +139,51 @@ pub async fn make_bid_handler(
         }
     };
 
-    // Validate the player has the required trumps for their bid
-    let hand = match GameStateRepository::get_hand(&mut conn, &game_id, player_position).await {
-        Ok(Some(hand)) => hand,
-        Ok(None) => {
+    // Load the current deal into the rules engine
+    let hands = match GameStateRepository::get_dealt_hands(&mut conn, &game_id).await {
+        Ok(hands) => hands,
+        Err(e) => {
             return (
                 StatusCode::BAD_REQUEST,
                 Json(ErrorResponse {
-                    error: "Hand not found".to_string(),
-                    message: Some("Your hand has not been dealt yet".to_string()),
+                    error: "Hands not found".to_string(),
+                    message: Some(e),
                 }),
             )
                 .into_response();
         }
+    };
+
+    let mut game = match game_match.to_engine(hands, None) {
+        Ok(game) => game,
         Err(e) => {
             return (
-                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_REQUEST,
                 Json(ErrorResponse {
-                    error: format!("Failed to get hand: {}", e),
-                    message: None,
+                    error: "Invalid bid".to_string(),
+                    message: Some(e),
                 }),
             )
                 .into_response();
         }
     };
 
-    // Check if player has enough trumps for their bid
-    let trump_counts = hand.calculate_trump_counts();
-    let player_trump_count = trump_counts.get(&bid_request.suit).copied().unwrap_or(0);
-
-    if player_trump_count < bid_request.length {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "Insufficient trumps".to_string(),
-                message: Some(format!(
-                    "You only have {} {} trumps, but bid {} trumps",
-                    player_trump_count, bid_request.suit, bid_request.length
-                )),
-            }),
-        )
-            .into_response();
-    }
-
     // Make the bid
-    match game_match.make_bid(
-        player_position,
-        bid_request.length,
-        bid_request.suit.clone(),
-    ) {
+    let action = GameAction::Bid {
+        player: player_position,
+        length: bid_request.length,
+        suit: bid_request.suit.clone(),
+    };
+    match game.apply(action.clone()) {
         Ok(_) => {
+            game_match.apply_engine_state(&game);
+
+            if let Err(e) =
+                GameStateRepository::record_bidding_action(&mut conn, &game_id, &action).await
+            {
+                eprintln!("Failed to record bid: {}", e);
+            }
+
             // Update the match in Redis
             if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
                 return (
@@ -214,6 +211,11 @@ pub async fn make_bid_handler(
                 // Don't fail the request if broadcasting fails
             }
 
+            // Let any bots that are next take their turns
+            if !players.iter().any(|p| p.user_id == user_id && p.is_bot()) {
+                bots::spawn_bot_turns(redis_pool.clone(), game_id.clone());
+            }
+
             // Create bid details
             let bid_details = BidDetails {
                 length: bid_request.length,
@@ -252,15 +254,16 @@ pub async fn make_bid_handler(
 
             (StatusCode::OK, Json(response)).into_response()
         }
-        Err(e) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "Invalid bid".to_string(),
-                    message: Some(e),
-                }),
+        Err(violation) => {
+            rule_violation::reject_action(
+                &mut conn,
+                &game_id,
+                &user_id,
+                &game,
+                player_position,
+                violation,
             )
-                .into_response();
+            .await
         }
     }
 }
@@ -278,8 +281,8 @@ pub async fn make_bid_handler(
     ),
     responses(
         (status = 200, description = "Pass recorded successfully", body = PassResponse),
-        (status = 400, description = "Invalid game state", body = ErrorResponse),
-        (status = 403, description = "Not your turn to bid", body = ErrorResponse),
+        (status = 400, description = "Invalid game state", body = RuleViolationResponse),
+        (status = 409, description = "Not your turn to bid", body = RuleViolationResponse),
         (status = 404, description = "Game not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
@@ -370,7 +373,7 @@ pub async fn pass_bid_handler(
         }
     };
 
-    let player_position = match players.iter().position(|p| p.user_id == user_id) {
+    let player_position = match seat_of(&players, &user_id) {
         Some(pos) => pos,
         None => {
             return (
@@ -384,12 +387,59 @@ pub async fn pass_bid_handler(
         }
     };
 
+    // Load the current deal into the rules engine
+    let hands = match GameStateRepository::get_dealt_hands(&mut conn, &game_id).await {
+        Ok(hands) => hands,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Hands not found".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let mut game = match game_match.to_engine(hands, None) {
+        Ok(game) => game,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid pass".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
     // Make the pass
-    match game_match.make_pass(player_position) {
-        Ok((all_passed, bidding_complete)) => {
+    let action = GameAction::Pass {
+        player: player_position,
+    };
+    match game.apply(action.clone()) {
+        Ok(events) => {
+            if let Err(e) =
+                GameStateRepository::record_bidding_action(&mut conn, &game_id, &action).await
+            {
+                eprintln!("Failed to record pass: {}", e);
+            }
+
+            let all_passed = events
+                .iter()
+                .any(|event| matches!(event, GameEvent::AllPassed { .. }));
+            let bidding_complete = events
+                .iter()
+                .any(|event| matches!(event, GameEvent::BiddingComplete { .. }));
+
             if all_passed {
-                // All players passed - need to redeal
-                game_match.reset_for_redeal();
+                // All players passed - reveal the abandoned deal, then the same dealer deals again
+                if let Err(e) = game_fairness::reveal_latest_deal(&mut conn, &game_id).await {
+                    eprintln!("Failed to reveal deal: {}", e);
+                }
 
                 // Clear existing hands
                 if let Err(e) = GameStateRepository::clear_hands(&mut conn, &game_id).await {
@@ -403,39 +453,20 @@ pub async fn pass_bid_handler(
                         .into_response();
                 }
 
-                // Deal new hands
-                let hands = Deck::deal_until_valid();
-                let hand_objects: [Hand; 4] = [
-                    Hand::new(hands[0].clone(), 0),
-                    Hand::new(hands[1].clone(), 1),
-                    Hand::new(hands[2].clone(), 2),
-                    Hand::new(hands[3].clone(), 3),
-                ];
-
-                if let Err(e) =
-                    GameStateRepository::store_hands(&mut conn, &game_id, &hand_objects).await
+                let hands = match game_start::deal_and_store_hands(
+                    &mut conn,
+                    &game_id,
+                    &players,
+                    &game.rules,
+                )
+                .await
                 {
-                    return (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ErrorResponse {
-                            error: format!("Failed to store redealt hands: {}", e),
-                            message: None,
-                        }),
-                    )
-                        .into_response();
-                }
-
-                // Restart bidding
-                game_match.start_bidding();
-            } else if bidding_complete {
-                // Bidding is complete - transition to playing
-                let (_bid_length, trump_suit, trump_declarer) = match game_match.finish_bidding() {
-                    Ok(result) => result,
+                    Ok((hands, _)) => hands,
                     Err(e) => {
                         return (
                             StatusCode::INTERNAL_SERVER_ERROR,
                             Json(ErrorResponse {
-                                error: format!("Failed to complete bidding: {}", e),
+                                error: format!("Failed to redeal: {}", e),
                                 message: None,
                             }),
                         )
@@ -443,24 +474,12 @@ pub async fn pass_bid_handler(
                     }
                 };
 
-                // Initialize trick state for the playing phase
-                let trump_team = (trump_declarer, (trump_declarer + 2) % 4); // Trump declarer + partner opposite
-                let initial_leader = (game_match.dealer_position.unwrap_or(0) + 1) % 4; // Left of dealer
-
-                let trick_state = crate::game::trick::GameTrickState::new(
-                    game_id.clone(),
-                    initial_leader,
-                    trump_suit.clone(),
-                    trump_team,
-                );
-
-                // Store trick state in Redis
-                if let Err(e) = crate::redis::trick_state::TrickStateRepository::store_trick_state(
-                    &mut conn,
-                    &game_id,
-                    &trick_state,
-                )
-                .await
+                game = SjavsGame::new(game_id.clone(), game.dealer, hands)
+                    .with_rules(game.rules.clone());
+            } else if let Some(trick_state) = &game.tricks {
+                // Bidding is complete - store trick state for the playing phase
+                if let Err(e) =
+                    TrickStateRepository::store_trick_state(&mut conn, &game_id, trick_state).await
                 {
                     return (
                         StatusCode::INTERNAL_SERVER_ERROR,
@@ -473,6 +492,8 @@ pub async fn pass_bid_handler(
                 }
             }
 
+            game_match.apply_engine_state(&game);
+
             // Update the match in Redis
             if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
                 return (
@@ -533,6 +554,11 @@ pub async fn pass_bid_handler(
                 }
             }
 
+            // Let any bots that are next take their turns
+            if !players.iter().any(|p| p.user_id == user_id && p.is_bot()) {
+                bots::spawn_bot_turns(redis_pool.clone(), game_id.clone());
+            }
+
             // Create game state
             let game_state = BiddingGameState {
                 id: game_match.id.clone(),
@@ -569,15 +595,16 @@ pub async fn pass_bid_handler(
 
             (StatusCode::OK, Json(response)).into_response()
         }
-        Err(e) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "Invalid pass".to_string(),
-                    message: Some(e),
-                }),
+        Err(violation) => {
+            rule_violation::reject_action(
+                &mut conn,
+                &game_id,
+                &user_id,
+                &game,
+                player_position,
+                violation,
             )
-                .into_response();
+            .await
         }
     }
 }
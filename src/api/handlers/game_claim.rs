@@ -0,0 +1,404 @@
+use crate::api::handlers::{bots, game_scoring};
+use crate::api::schemas::{ClaimPlay, ClaimRequest, ClaimResponse, ErrorResponse};
+use crate::game::claim;
+use crate::game::engine::GameAction;
+use crate::redis::{
+    game_state::repository::GameStateRepository,
+    normal_match::repository::NormalMatchRepository,
+    player::repository::{seat_of, PlayerRepository},
+    pubsub::broadcasting,
+    trick_state::TrickStateRepository,
+};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use deadpool_redis::Pool as RedisPool;
+
+/// Claim the remaining tricks
+///
+/// Checks the claim against every remaining hand. A claim that holds in every
+/// line is played out and the game goes straight to scoring; otherwise it is
+/// rejected with a counter-line.
+#[utoipa::path(
+    post,
+    path = "/game/claim",
+    tag = "Game Playing",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body(content = Option<ClaimRequest>, description = "Optional statement of how the tricks will be taken"),
+    responses(
+        (status = 200, description = "Claim accepted and the game scored", body = ClaimResponse),
+        (status = 400, description = "Game is not in playing phase", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 409, description = "Claim rejected; the response holds a counter-line", body = ClaimResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Claim the remaining tricks",
+    description = "Claim every remaining trick for your team. The claim is checked against all remaining hands with the double-dummy solver: if no defence can take a trick, the tricks and their points are awarded and the game is scored. Otherwise the claim is rejected with a line of play in which the other team takes a trick."
+)]
+#[axum::debug_handler]
+pub async fn claim_tricks_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    payload: Option<Json<ClaimRequest>>,
+) -> Response {
+    let statement = payload.and_then(|Json(request)| request.statement);
+
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get player's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must be in a game to claim tricks".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Validate game is in playing state
+    let mut game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) => game_match,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if !game_match.is_playing() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Game is not in playing phase".to_string(),
+                message: Some(format!("Game status: {}", game_match.status.to_string())),
+            }),
+        )
+            .into_response();
+    }
+
+    // 3. Get player position
+    let players = match PlayerRepository::get_players_in_game(&mut conn, &game_id).await {
+        Ok(players) => players,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get players: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let player_position = match seat_of(&players, &user_id) {
+        Some(pos) => pos,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Player not in game".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 4. Load the rules engine with every hand and the trick in progress
+    let trick_state = match TrickStateRepository::get_trick_state(&mut conn, &game_id).await {
+        Ok(Some(state)) => state,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "No active trick found".to_string(),
+                    message: Some("Trick state not initialized".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get trick state: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let hands = match GameStateRepository::get_dealt_hands(&mut conn, &game_id).await {
+        Ok(hands) => hands,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get hands".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let mut game = match game_match.to_engine(hands, Some(trick_state)) {
+        Ok(game) => game,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to load game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 5. Search every line - this can take a while, so keep it off the async workers
+    let search = game.clone();
+    let check =
+        match tokio::task::spawn_blocking(move || claim::check_claim(&search, player_position))
+            .await
+        {
+            Ok(Ok(check)) => check,
+            Ok(Err(e)) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse {
+                        error: "Cannot claim now".to_string(),
+                        message: Some(e),
+                    }),
+                )
+                    .into_response();
+            }
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("Claim search failed: {}", e),
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    let line: Vec<ClaimPlay> = check
+        .line
+        .iter()
+        .filter_map(|action| match action {
+            GameAction::PlayCard { player, card } => Some(ClaimPlay {
+                player_position: *player as u8,
+                card: card.code(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    // 6. Reject a claim the other team can beat, showing them how
+    if !check.holds() {
+        if let Err(e) = broadcasting::broadcast_claim_rejected(
+            &mut conn,
+            &game_id,
+            player_position as u8,
+            statement.as_deref(),
+            check.tricks_lost,
+        )
+        .await
+        {
+            eprintln!("Failed to broadcast claim rejection: {}", e);
+        }
+
+        let response = ClaimResponse {
+            message: format!(
+                "Claim rejected - the other team can take {} of the {} remaining tricks",
+                check.tricks_lost, check.tricks_remaining
+            ),
+            game_id,
+            player_position: player_position as u8,
+            accepted: false,
+            tricks_claimed: check.tricks_remaining,
+            tricks_lost: check.tricks_lost,
+            line,
+            game_complete: None,
+        };
+
+        return (StatusCode::CONFLICT, Json(response)).into_response();
+    }
+
+    // 7. Play the claim out so every trick and point lands as if played by hand
+    let tricks_before = game
+        .tricks
+        .as_ref()
+        .map(|tricks| tricks.completed_tricks.len())
+        .unwrap_or(0);
+    for action in &check.line {
+        if let Err(e) = game.apply(action.clone()) {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to play out claim: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    let trick_state = match &game.tricks {
+        Some(trick_state) => trick_state,
+        None => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Trick state missing after claim".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 8. Save the hands, tricks and match state
+    for (position, hand) in game.hands.iter().enumerate() {
+        if let Err(e) = GameStateRepository::update_hand(&mut conn, &game_id, position, hand).await
+        {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to update hand: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    for trick in trick_state.completed_tricks.iter().skip(tricks_before) {
+        if let Err(e) =
+            TrickStateRepository::store_completed_trick(&mut conn, &game_id, trick).await
+        {
+            eprintln!("Failed to store completed trick: {}", e);
+        }
+    }
+
+    if let Err(e) = TrickStateRepository::store_trick_state(&mut conn, &game_id, trick_state).await
+    {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("Failed to save trick state: {}", e),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    game_match.apply_engine_state(&game);
+    if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("Failed to update game state: {}", e),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    // 9. Tell the table, then score the game
+    if let Err(e) = broadcasting::broadcast_claim_accepted(
+        &mut conn,
+        &game_id,
+        player_position as u8,
+        statement.as_deref(),
+        check.tricks_remaining,
+        trick_state.points_accumulated.0,
+        trick_state.points_accumulated.1,
+    )
+    .await
+    {
+        eprintln!("Failed to broadcast claim: {}", e);
+    }
+
+    let game_complete = match game_scoring::handle_game_completion(&mut conn, game_id.clone()).await
+    {
+        Ok((response, _, _)) => Some(response),
+        Err(e) => {
+            eprintln!("Failed to complete game after claim: {}", e);
+            None
+        }
+    };
+
+    // 10. Let any bots that are next take their turns
+    if !players.iter().any(|p| p.user_id == user_id && p.is_bot()) {
+        bots::spawn_bot_turns(redis_pool.clone(), game_id.clone());
+    }
+
+    let response = ClaimResponse {
+        message: format!(
+            "Claim accepted - {} remaining tricks awarded",
+            check.tricks_remaining
+        ),
+        game_id,
+        player_position: player_position as u8,
+        accepted: true,
+        tricks_claimed: check.tricks_remaining,
+        tricks_lost: 0,
+        line,
+        game_complete,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,186 @@
+use crate::api::schemas::{DealVerificationResponse, ErrorResponse};
+use crate::game::deck;
+use crate::game::hand::Hand;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::pubsub::broadcasting;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Reveal the seed and deck order of a game's most recent deal
+///
+/// Called once a deal is over (scored or passed out), before the next deal
+/// is recorded, so players can check it against the commitment they were sent.
+pub async fn reveal_latest_deal(
+    conn: &mut deadpool_redis::Connection,
+    game_id: &str,
+) -> Result<(), String> {
+    let records = GameStateRepository::get_deal_records(conn, game_id).await?;
+    let Some(record) = records.last() else {
+        return Err("No deal recorded for this game".to_string());
+    };
+
+    broadcasting::broadcast_deal_revealed(
+        conn,
+        game_id,
+        (records.len() - 1) as u32,
+        &record.commitment,
+        record.seed,
+        &record.salt,
+        &record.deck_order(),
+    )
+    .await
+    .map_err(|e| format!("Failed to broadcast deal reveal: {}", e))
+}
+
+/// Verify a finished deal
+///
+/// Reveals the seed, salt and deck order behind a deal and checks them, and
+/// the hands dealt from them, against the commitment stored when it was dealt.
+/// Deals still being bid on or played stay secret.
+///
+/// Any signed-in user may verify any game's finished deals, not just the
+/// players at that table: a finished deal gives nothing away, and letting
+/// onlookers check it is part of the point.
+#[utoipa::path(
+    get,
+    path = "/games/{game_id}/deals/{deal_number}/verify",
+    tag = "Game Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("game_id" = String, Path, description = "Game the deal belongs to"),
+        ("deal_number" = u32, Path, description = "Deal to verify (0 = first deal)")
+    ),
+    responses(
+        (status = 200, description = "Deal revealed and verified", body = DealVerificationResponse),
+        (status = 403, description = "Deal is still in play", body = ErrorResponse),
+        (status = 404, description = "Deal not found", body = ErrorResponse),
+        (status = 409, description = "Revealed values do not match the published commitment", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Verify a finished deal",
+    description = "Reveals the seed and deck order of a finished deal and checks them against the commitment published before bidding."
+)]
+#[axum::debug_handler]
+pub async fn verify_deal_handler(
+    State(redis_pool): State<RedisPool>,
+    Path((game_id, deal_number)): Path<(String, u32)>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Find the requested deal
+    let records = match GameStateRepository::get_deal_records(&mut conn, &game_id).await {
+        Ok(records) => records,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let Some(record) = records.get(deal_number as usize) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Deal not found".to_string(),
+                message: Some(format!("Game has {} recorded deals", records.len())),
+            }),
+        )
+            .into_response();
+    };
+
+    // 2. The latest deal stays secret while the match is still using it
+    let is_latest = deal_number as usize == records.len() - 1;
+    if is_latest {
+        match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+            Ok(Some(game_match)) if game_match.is_active() => {
+                return (
+                    StatusCode::FORBIDDEN,
+                    Json(ErrorResponse {
+                        error: "Deal is still in play".to_string(),
+                        message: Some("Deals are revealed once the game is over".to_string()),
+                    }),
+                )
+                    .into_response();
+            }
+            Ok(_) => {}
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("Failed to get game: {}", e),
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    // 3. Check the revealed values against the commitment published before bidding
+    let deck_order = record.deck_order();
+    let hands = match deck::verify_deal(
+        &record.commitment,
+        &record.salt,
+        record.seed,
+        record.min_bid,
+        &deck_order,
+    ) {
+        Ok(hands) => hands,
+        Err(e) => {
+            eprintln!(
+                "Deal {} of game {} failed verification: {}",
+                deal_number, game_id, e
+            );
+            return (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    error: "Deal failed verification".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let response = DealVerificationResponse {
+        game_id,
+        deal_number,
+        commitment: record.commitment.clone(),
+        seed: record.seed.to_string(),
+        salt: record.salt.clone(),
+        deck_order,
+        hands: hands
+            .into_iter()
+            .enumerate()
+            .map(|(position, cards)| Hand::new(cards, position).to_codes())
+            .collect(),
+        verified: true,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
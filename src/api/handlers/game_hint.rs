@@ -0,0 +1,275 @@
+use crate::api::schemas::{CardHint, ErrorResponse, HintResponse};
+use crate::game::hint;
+use crate::game::mcts::MctsConfig;
+use crate::redis::{
+    game_state::repository::GameStateRepository,
+    normal_match::repository::NormalMatchRepository,
+    player::repository::{seat_of, PlayerRepository},
+    trick_state::TrickStateRepository,
+};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use deadpool_redis::Pool as RedisPool;
+
+/// Suggest a card to play
+///
+/// Ranks every legal card for the player to move with the same search hard
+/// bots use, seeing only what that player can see. Each hint is counted in
+/// the deal's game record.
+#[utoipa::path(
+    get,
+    path = "/game/hint",
+    tag = "Game Playing",
+    security(
+        ("jwt_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Legal cards ranked, best first", body = HintResponse),
+        (status = 400, description = "Game is not in playing phase or it is not your turn", body = ErrorResponse),
+        (status = 403, description = "Hints are turned off for this match", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Suggest a card to play",
+    description = "Only for the player to move, and only when the host allows hints. Every legal card is searched from the player's point of view and returned best first with a short reason. The hint is only shown to the player asking, but it is counted in the deal's game record (the Hints tag) so rated games can reject it."
+)]
+#[axum::debug_handler]
+pub async fn get_hint_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get player's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must be in a game to ask for a hint".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Validate game is in playing state and allows hints
+    let game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) => game_match,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if !game_match.allow_hints {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: "Hints are turned off".to_string(),
+                message: Some("The host has not allowed hints in this match".to_string()),
+            }),
+        )
+            .into_response();
+    }
+
+    if !game_match.is_playing() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Game is not in playing phase".to_string(),
+                message: Some(format!("Game status: {}", game_match.status.to_string())),
+            }),
+        )
+            .into_response();
+    }
+
+    // 3. Get player position
+    let players = match PlayerRepository::get_players_in_game(&mut conn, &game_id).await {
+        Ok(players) => players,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get players: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let player_position = match seat_of(&players, &user_id) {
+        Some(pos) => pos,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Player not in game".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 4. Load the rules engine with the trick in progress
+    let trick_state = match TrickStateRepository::get_trick_state(&mut conn, &game_id).await {
+        Ok(Some(state)) => state,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "No active trick found".to_string(),
+                    message: Some("Trick state not initialized".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get trick state: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let hands = match GameStateRepository::get_dealt_hands(&mut conn, &game_id).await {
+        Ok(hands) => hands,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get hands".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let game = match game_match.to_engine(hands, Some(trick_state)) {
+        Ok(game) => game,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to load game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 5. Search the player's cards - this can take a while, so keep it off the async workers
+    let hints = match tokio::task::spawn_blocking(move || {
+        hint::suggest_cards(&game, player_position, &MctsConfig::from_env())
+    })
+    .await
+    {
+        Ok(Ok(hints)) => hints,
+        Ok(Err(e)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Cannot give a hint now".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Hint search failed: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 6. Count the hint for the game record
+    let hints_used =
+        match GameStateRepository::record_hint_use(&mut conn, &game_id, player_position).await {
+            Ok(hints_used) => hints_used,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e,
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    let response = HintResponse {
+        message: format!("{} cards ranked", hints.len()),
+        game_id,
+        player_position: player_position as u8,
+        hints: hints
+            .into_iter()
+            .map(|hint| CardHint {
+                card: hint.card.code(),
+                visits: hint.visits,
+                expected_reward: hint.expected_reward,
+                reason: hint.reason,
+            })
+            .collect(),
+        hints_used,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,255 @@
+use crate::api::handlers::normal_match::rule_set_info;
+use crate::api::schemas::{
+    ErrorResponse, GameRecordResponse, GameScoringResult, ImportGameRecordRequest,
+    ImportedGameRecordResponse,
+};
+use crate::game::hand::Hand;
+use crate::game::notation::GameRecord;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::player::repository::PlayerRepository;
+use crate::redis::trick_state::repository::TrickStateRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Export a finished deal as Sjavs Game Notation
+///
+/// Writes the players, dealer, seed, rules, hands, bidding and tricks of a
+/// deal that was played to the end, along with the result it scored.
+#[utoipa::path(
+    get,
+    path = "/games/{game_id}/deals/{deal_number}/record",
+    tag = "Game Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("game_id" = String, Path, description = "Game the deal belongs to"),
+        ("deal_number" = u32, Path, description = "Deal to export (0 = first deal)")
+    ),
+    responses(
+        (status = 200, description = "Deal exported", body = GameRecordResponse),
+        (status = 404, description = "No finished deal with this number", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Export a finished deal",
+    description = "Returns a deal that was played to the end as Sjavs Game Notation (SGN) text, for review, puzzles or bug reports."
+)]
+#[axum::debug_handler]
+pub async fn export_game_record_handler(
+    State(redis_pool): State<RedisPool>,
+    Path((game_id, deal_number)): Path<(String, u32)>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Only deals that were played to the end are archived
+    let deal = match TrickStateRepository::get_played_deal(
+        &mut conn,
+        &game_id,
+        deal_number as usize,
+    )
+    .await
+    {
+        Ok(Some(deal)) => deal,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Deal not found".to_string(),
+                    message: Some(
+                        "Only deals that were played to the end can be exported".to_string(),
+                    ),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Header details: seed, rules and who sat where
+    let seed = match GameStateRepository::get_deal_records(&mut conn, &game_id).await {
+        Ok(records) => records.get(deal_number as usize).map(|record| record.seed),
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let rules = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) => game_match.rules,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let players = PlayerRepository::get_players_in_game(&mut conn, &game_id)
+        .await
+        .unwrap_or_default();
+    let mut seats: [String; 4] = [0, 1, 2, 3].map(|seat| format!("Seat {}", seat));
+    for player in &players {
+        let Some(seat) = player.seat else { continue };
+        let username: Option<String> = redis::cmd("HGET")
+            .arg("usernames")
+            .arg(&player.user_id)
+            .query_async(&mut conn)
+            .await
+            .unwrap_or(None);
+        seats[seat] = username.unwrap_or_else(|| player.user_id.clone());
+    }
+
+    // 3. Write the record, replaying it to make sure it is legal
+    let record =
+        GameRecord::from_played_deal(game_id.clone(), deal_number, seats, seed, rules, &deal);
+    let notation = match record.to_notation() {
+        Ok(notation) => notation,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to write game record: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let response = GameRecordResponse {
+        game_id,
+        deal_number,
+        notation,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Import a game written in Sjavs Game Notation
+///
+/// Parses the record strictly and replays every bid and card through the
+/// rules engine. Nothing is stored; the response describes the checked game.
+#[utoipa::path(
+    post,
+    path = "/games/records/import",
+    tag = "Game Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = ImportGameRecordRequest,
+    responses(
+        (status = 200, description = "Record is a legal game", body = ImportedGameRecordResponse),
+        (status = 400, description = "Record is malformed or breaks the rules", body = ErrorResponse)
+    ),
+    summary = "Import a game record",
+    description = "Validates a Sjavs Game Notation (SGN) record by replaying it through the rules engine. Finished deals must state the result they score."
+)]
+#[axum::debug_handler]
+pub async fn import_game_record_handler(Json(request): Json<ImportGameRecordRequest>) -> Response {
+    let record = match GameRecord::parse(&request.notation) {
+        Ok(record) => record,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid game record".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let result = match record.result() {
+        Ok(result) => result,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid game record".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let response = ImportedGameRecordResponse {
+        game_id: record.game_id.clone(),
+        deal_number: record.deal_number,
+        players: record.players.to_vec(),
+        dealer_position: record.dealer as u8,
+        seed: record.seed.map(|seed| seed.to_string()),
+        rules: rule_set_info(&record.rules),
+        hands: record
+            .hands
+            .iter()
+            .enumerate()
+            .map(|(position, cards)| Hand::new(cards.clone(), position).to_codes())
+            .collect(),
+        bidding_actions: record.bidding.len() as u8,
+        tricks_recorded: record.tricks.len() as u8,
+        complete: result.is_some(),
+        result: result.map(|(scoring, game_result)| GameScoringResult {
+            trump_team_points: scoring.trump_team_points,
+            opponent_team_points: scoring.opponent_team_points,
+            trump_team_tricks: scoring.trump_team_tricks,
+            opponent_team_tricks: scoring.opponent_team_tricks,
+            trump_suit: scoring.trump_suit,
+            result_type: format!("{:?}", game_result.result_type),
+            description: game_result.description,
+            trump_team_score: game_result.trump_team_score,
+            opponent_team_score: game_result.opponent_team_score,
+            individual_vol: scoring.individual_vol,
+        }),
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
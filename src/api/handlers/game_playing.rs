@@ -1,11 +1,18 @@
-use crate::api::handlers::game_scoring;
+use crate::api::handlers::{bots, game_scoring, rule_violation};
 use crate::api::schemas::{
-    CardPlayRequest, CardPlayResponse, ErrorResponse, GameTrickInfo, TrickSummaryResponse,
+    CardPlayRequest, CardPlayResponse, ErrorResponse, GameTrickInfo, RuleViolationResponse,
+    TrickSummaryResponse,
 };
 use crate::game::card::Card;
+use crate::game::engine::{GameAction, GameEvent};
+use crate::game::violation::RuleViolation;
 use crate::redis::{
-    game_state::repository::GameStateRepository, normal_match::repository::NormalMatchRepository,
-    player::repository::PlayerRepository, pubsub::broadcasting, trick_state::TrickStateRepository,
+    game_state::repository::GameStateRepository,
+    normal_match::repository::NormalMatchRepository,
+    player::repository::{seat_of, PlayerRepository},
+    pubsub::broadcasting,
+    takeback::TakebackRepository,
+    trick_state::TrickStateRepository,
 };
 use crate::websocket::events::playing::TrickEvent;
 use axum::{
@@ -31,9 +38,9 @@ use serde_json::json;
     ),
     responses(
         (status = 200, description = "Card played successfully", body = CardPlayResponse),
-        (status = 400, description = "Invalid card play", body = ErrorResponse),
-        (status = 403, description = "Not your turn to play", body = ErrorResponse),
+        (status = 400, description = "Card breaks a rule; the response lists the legal cards", body = RuleViolationResponse),
         (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 409, description = "Not your turn to play, or a takeback of the last card is being voted on", body = RuleViolationResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     summary = "Play a card in the current trick",
@@ -85,7 +92,7 @@ pub async fn play_card_handler(
     };
 
     // 2. Validate game is in playing state
-    let game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+    let mut game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
         Ok(Some(game_match)) => game_match,
         Ok(None) => {
             return (
@@ -135,7 +142,7 @@ pub async fn play_card_handler(
         }
     };
 
-    let player_position = match players.iter().position(|p| p.user_id == user_id) {
+    let player_position = match seat_of(&players, &user_id) {
         Some(pos) => pos,
         None => {
             return (
@@ -150,7 +157,7 @@ pub async fn play_card_handler(
     };
 
     // 4. Get current trick state
-    let mut trick_state = match TrickStateRepository::get_trick_state(&mut conn, &game_id).await {
+    let trick_state = match TrickStateRepository::get_trick_state(&mut conn, &game_id).await {
         Ok(Some(state)) => state,
         Ok(None) => {
             return (
@@ -174,48 +181,77 @@ pub async fn play_card_handler(
         }
     };
 
-    // 5. Validate it's the player's turn
-    if trick_state.current_trick.current_player != player_position {
-        return (
-            StatusCode::CONFLICT,
-            Json(ErrorResponse {
-                error: "Not your turn to play".to_string(),
-                message: Some(format!(
-                    "Current player to play is position {}",
-                    trick_state.current_trick.current_player
-                )),
-            }),
+    // 5. Get all hands and load the rules engine
+    let hands = match GameStateRepository::get_dealt_hands(&mut conn, &game_id).await {
+        Ok(hands) => hands,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Player hand not found".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let mut game = match game_match.to_engine(hands, Some(trick_state)) {
+        Ok(game) => game,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to load game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 6. Validate it's the player's turn
+    if game.current_player() != Some(player_position) {
+        let violation = RuleViolation::NotYourTurn {
+            current_player: game.current_player(),
+        };
+        return rule_violation::reject_action(
+            &mut conn,
+            &game_id,
+            &user_id,
+            &game,
+            player_position,
+            violation,
         )
-            .into_response();
+        .await;
     }
 
-    // 6. Get player's hand and validate card
-    let mut player_hand =
-        match GameStateRepository::get_hand(&mut conn, &game_id, player_position).await {
-            Ok(Some(hand)) => hand,
-            Ok(None) => {
-                return (
-                    StatusCode::BAD_REQUEST,
-                    Json(ErrorResponse {
-                        error: "Player hand not found".to_string(),
-                        message: None,
-                    }),
-                )
-                    .into_response();
-            }
-            Err(e) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("Failed to get player hand: {}", e),
-                        message: None,
-                    }),
-                )
-                    .into_response();
-            }
-        };
+    // The last card stays on the table while the others vote on taking it back
+    match TakebackRepository::get_request(&mut conn, &game_id).await {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    error: "Takeback pending".to_string(),
+                    message: Some("Wait for the vote on taking back the last card".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to check takeback request: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
 
-    // 7. Validate player has the card they want to play
+    // 7. Parse the card the player wants to play
     let card_to_play = match Card::from_code(&card_request.card) {
         Ok(card) => card,
         Err(e) => {
@@ -229,125 +265,109 @@ pub async fn play_card_handler(
                 .into_response();
         }
     };
-    if !player_hand.has_card(&card_to_play) {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "Card not in hand".to_string(),
-                message: Some(format!(
-                    "You don't have the {} in your hand",
-                    card_request.card
-                )),
-            }),
-        )
-            .into_response();
-    }
 
-    // 8. Validate it's a legal play (follow suit rules)
-    let legal_cards = trick_state
-        .current_trick
-        .get_legal_cards(&player_hand.cards);
-    if !legal_cards.contains(&card_to_play) {
-        let lead_suit = match trick_state.current_trick.lead_suit {
-            Some(suit) => suit.to_string(),
-            None => "None".to_string(),
-        };
+    // 8. Play the card (validates hand and follow suit rules)
+    let events = match game.apply(GameAction::PlayCard {
+        player: player_position,
+        card: card_to_play,
+    }) {
+        Ok(events) => events,
+        Err(violation) => {
+            return rule_violation::reject_action(
+                &mut conn,
+                &game_id,
+                &user_id,
+                &game,
+                player_position,
+                violation,
+            )
+            .await;
+        }
+    };
 
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "Illegal card play".to_string(),
-                message: Some(format!(
-                    "Must follow suit ({}). Legal cards: {:?}",
-                    lead_suit,
-                    legal_cards
-                        .iter()
-                        .map(|c| c.to_string())
-                        .collect::<Vec<_>>()
-                )),
-            }),
-        )
-            .into_response();
+    // 9. Check if trick or game is complete
+    let mut trick_complete = false;
+    let mut game_complete = false;
+    let mut trick_winner = None;
+    let mut points_won = 0;
+
+    for event in &events {
+        match event {
+            GameEvent::TrickCompleted { winner, points, .. } => {
+                trick_complete = true;
+                trick_winner = Some(*winner);
+                points_won = *points;
+            }
+            GameEvent::GameComplete { .. } => game_complete = true,
+            _ => {}
+        }
     }
 
-    // 9. Play the card in the trick
-    if let Err(e) = trick_state
-        .current_trick
-        .play_card(player_position, card_to_play.clone())
+    let trick_state = match &game.tricks {
+        Some(trick_state) => trick_state,
+        None => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Trick state missing after card play".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 10. Save updated hand
+    if let Err(e) = GameStateRepository::update_hand(
+        &mut conn,
+        &game_id,
+        player_position,
+        &game.hands[player_position],
+    )
+    .await
     {
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("Failed to play card: {}", e),
+                error: format!("Failed to update hand: {}", e),
                 message: None,
             }),
         )
             .into_response();
     }
 
-    // 10. Remove card from player's hand
-    player_hand.remove_card(&card_to_play);
+    // 11. Store completed trick for history
+    if trick_complete {
+        if let Some(completed_trick) = trick_state.completed_tricks.last() {
+            if let Err(e) =
+                TrickStateRepository::store_completed_trick(&mut conn, &game_id, completed_trick)
+                    .await
+            {
+                eprintln!("Failed to store completed trick: {}", e);
+            }
+        }
+    }
 
-    // 11. Save updated hand
-    if let Err(e) =
-        GameStateRepository::update_hand(&mut conn, &game_id, player_position, &player_hand).await
+    // 12. Save updated trick state
+    if let Err(e) = TrickStateRepository::store_trick_state(&mut conn, &game_id, trick_state).await
     {
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("Failed to update hand: {}", e),
+                error: format!("Failed to save trick state: {}", e),
                 message: None,
             }),
         )
             .into_response();
     }
 
-    // 12. Check if trick is complete
-    let mut trick_complete = false;
-    let mut game_complete = false;
-    let mut trick_winner = None;
-    let mut points_won = 0;
-
-    if trick_state.current_trick.is_complete {
-        // Complete the trick
-        match trick_state.complete_trick() {
-            Ok(result) => {
-                trick_complete = true;
-                trick_winner = Some(result.winner);
-                points_won = result.points;
-                game_complete = result.game_complete;
-
-                // Store completed trick for history
-                if let Err(e) = TrickStateRepository::store_completed_trick(
-                    &mut conn,
-                    &game_id,
-                    &trick_state.completed_tricks.last().unwrap(),
-                )
-                .await
-                {
-                    eprintln!("Failed to store completed trick: {}", e);
-                }
-            }
-            Err(e) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("Failed to complete trick: {}", e),
-                        message: None,
-                    }),
-                )
-                    .into_response();
-            }
-        }
-    }
-
-    // 13. Save updated trick state
-    if let Err(e) = TrickStateRepository::store_trick_state(&mut conn, &game_id, &trick_state).await
-    {
+    // 13. Save updated match state
+    game_match.apply_engine_state(&game);
+    if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("Failed to save trick state: {}", e),
+                error: format!("Failed to update game state: {}", e),
                 message: None,
             }),
         )
@@ -401,7 +421,12 @@ pub async fn play_card_handler(
         }
     }
 
-    // 17. Prepare response
+    // 17. Let any bots that are next take their turns
+    if !players.iter().any(|p| p.user_id == user_id && p.is_bot()) {
+        bots::spawn_bot_turns(redis_pool.clone(), game_id.clone());
+    }
+
+    // 18. Prepare response
     let response = CardPlayResponse {
         message: if trick_complete {
             if game_complete {
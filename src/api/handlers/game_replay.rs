@@ -0,0 +1,339 @@
+use crate::api::schemas::{ErrorResponse, ReplayQuery, ReplayResponse, ReplaySnapshot};
+use crate::game::card::Card;
+use crate::game::engine::{GameAction, GamePhase};
+use crate::game::notation::GameRecord;
+use crate::game::replay::{self, ReplayStep};
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::trick_state::repository::TrickStateRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Replay a deal step by step
+///
+/// Rebuilds the game after every bid, pass and card from the stored bidding
+/// and trick history. Hands are only revealed once all 8 tricks are played.
+#[utoipa::path(
+    get,
+    path = "/games/{game_id}/replay",
+    tag = "Game Playing",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("game_id" = String, Path, description = "Game to replay"),
+        ("deal" = Option<u32>, Query, description = "Deal to replay (0 = first deal); defaults to the latest deal")
+    ),
+    responses(
+        (status = 200, description = "Deal replayed", body = ReplayResponse),
+        (status = 404, description = "Game or deal not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Replay a deal",
+    description = "Returns an ordered list of snapshots of the game after each bid, pass and card. The current deal can be replayed while it is in progress; earlier deals only once they were played to the end. Hands are included only for complete deals."
+)]
+#[axum::debug_handler]
+pub async fn get_replay_handler(
+    State(redis_pool): State<RedisPool>,
+    Path(game_id): Path<String>,
+    Query(query): Query<ReplayQuery>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get the match and its deals
+    let game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) => game_match,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let records = match GameStateRepository::get_deal_records(&mut conn, &game_id).await {
+        Ok(records) => records,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let latest = records.len().saturating_sub(1);
+    let deal_number = query.deal.map(|deal| deal as usize).unwrap_or(latest);
+    let deal_record = match records.get(deal_number) {
+        Some(record) => record,
+        None => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Deal not found".to_string(),
+                    message: Some(format!("Game has {} deals", records.len())),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Finished deals are archived; the current one is rebuilt from live state
+    let played_deal =
+        match TrickStateRepository::get_played_deal(&mut conn, &game_id, deal_number).await {
+            Ok(deal) => deal,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e,
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    let seats = [0, 1, 2, 3].map(|seat| format!("Seat {}", seat));
+    let record = match played_deal {
+        Some(deal) => GameRecord::from_played_deal(
+            game_id.clone(),
+            deal_number as u32,
+            seats,
+            Some(deal_record.seed),
+            game_match.rules.clone(),
+            &deal,
+        ),
+        None if deal_number == latest => {
+            let bidding = match GameStateRepository::get_bidding_actions(&mut conn, &game_id).await
+            {
+                Ok(bidding) => bidding,
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: e,
+                            message: None,
+                        }),
+                    )
+                        .into_response();
+                }
+            };
+            let tricks = match live_tricks(&mut conn, &game_id).await {
+                Ok(tricks) => tricks,
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: e,
+                            message: None,
+                        }),
+                    )
+                        .into_response();
+                }
+            };
+
+            let hints_used = match GameStateRepository::get_hint_uses(&mut conn, &game_id).await {
+                Ok(hints_used) => hints_used,
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: e,
+                            message: None,
+                        }),
+                    )
+                        .into_response();
+                }
+            };
+
+            GameRecord {
+                game_id: game_id.clone(),
+                deal_number: deal_number as u32,
+                players: seats,
+                dealer: game_match.dealer_position.unwrap_or(0),
+                seed: Some(deal_record.seed),
+                rules: game_match.rules.clone(),
+                hands: deal_record.regenerate_hands(),
+                bidding,
+                tricks,
+                hints_used,
+            }
+        }
+        None => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Deal not found".to_string(),
+                    message: Some(
+                        "Only the current deal and deals played to the end can be replayed"
+                            .to_string(),
+                    ),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 3. Step through the deal with the rules engine
+    let steps = match replay::replay_steps(&record) {
+        Ok(steps) => steps,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to replay deal: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let complete = steps
+        .last()
+        .map(|step| step.game.phase == GamePhase::Complete)
+        .unwrap_or(false);
+
+    let response = ReplayResponse {
+        game_id,
+        deal_number: deal_number as u32,
+        dealer_position: record.dealer as u8,
+        complete,
+        hands: complete.then(|| record.hands.iter().map(|hand| codes(hand)).collect()),
+        snapshots: steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| snapshot(index, step, complete))
+            .collect(),
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Cards played so far in the current deal, trick by trick
+async fn live_tricks(
+    conn: &mut deadpool_redis::Connection,
+    game_id: &str,
+) -> Result<Vec<Vec<Card>>, String> {
+    let trick_state = match TrickStateRepository::get_trick_state(conn, game_id).await? {
+        Some(state) => state,
+        None => return Ok(vec![]),
+    };
+
+    // A completed trick is stored before the next one starts, so it can be
+    // both in the history and the current trick
+    let finished = trick_state.current_trick.trick_number.saturating_sub(1) as usize;
+    let history = TrickStateRepository::get_trick_history(conn, game_id).await?;
+    if history.len() < finished {
+        return Err("Trick history is incomplete".to_string());
+    }
+
+    let mut tricks: Vec<Vec<Card>> = history
+        .iter()
+        .take(finished)
+        .map(|trick| trick.cards_played.iter().map(|(_, card)| *card).collect())
+        .collect();
+    if !trick_state.current_trick.cards_played.is_empty() {
+        tricks.push(
+            trick_state
+                .current_trick
+                .cards_played
+                .iter()
+                .map(|(_, card)| *card)
+                .collect(),
+        );
+    }
+
+    Ok(tricks)
+}
+
+fn snapshot(index: usize, step: &ReplayStep, reveal_hands: bool) -> ReplaySnapshot {
+    let game = &step.game;
+    let (action, bid_length, bid_suit, card) = match &step.action {
+        GameAction::Bid { length, suit, .. } => ("bid", Some(*length), Some(suit.clone()), None),
+        GameAction::Pass { .. } => ("pass", None, None, None),
+        GameAction::PlayCard { card, .. } => ("play_card", None, None, Some(card.code())),
+    };
+
+    // Show a finished trick until the next card is played to the table
+    let trick = step
+        .completed_trick
+        .as_ref()
+        .or(game.tricks.as_ref().map(|tricks| &tricks.current_trick));
+    let tricks = game.tricks.as_ref();
+
+    ReplaySnapshot {
+        step: index as u16 + 1,
+        action: action.to_string(),
+        player: step.action.player() as u8,
+        bid_length,
+        bid_suit,
+        card,
+        phase: format!("{:?}", game.phase),
+        current_player: game.current_player().map(|player| player as u8),
+        highest_bid_length: game.highest_bid.as_ref().map(|bid| bid.length),
+        highest_bidder: game.highest_bid.as_ref().map(|bid| bid.player as u8),
+        trump_suit: game.trump_suit().map(str::to_string),
+        trick_number: trick.map(|trick| trick.trick_number),
+        trick_cards: trick
+            .map(|trick| {
+                trick
+                    .cards_played
+                    .iter()
+                    .map(|(_, card)| card.code())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        trick_winner: trick
+            .and_then(|trick| trick.trick_winner)
+            .map(|winner| winner as u8),
+        trump_team_tricks: tricks.map(|tricks| tricks.tricks_won.0).unwrap_or(0),
+        opponent_team_tricks: tricks.map(|tricks| tricks.tricks_won.1).unwrap_or(0),
+        trump_team_points: tricks
+            .map(|tricks| tricks.points_accumulated.0)
+            .unwrap_or(0),
+        opponent_team_points: tricks
+            .map(|tricks| tricks.points_accumulated.1)
+            .unwrap_or(0),
+        hands: reveal_hands.then(|| game.hands.iter().map(|hand| hand.to_codes()).collect()),
+    }
+}
+
+fn codes(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(|card| card.code()).collect()
+}
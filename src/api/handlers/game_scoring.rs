@@ -1,8 +1,13 @@
+use crate::api::handlers::{game_analysis, game_fairness, game_start};
 use crate::api::schemas::{
-    CrossScores, ErrorResponse, GameCompleteResponse, GameScoreInfo, GameScoringResult,
+    CrossScores, CrossWinner, ErrorResponse, GameCompleteResponse, GameScoreInfo, GameScoringResult,
 };
+use crate::game::cross::{CrossState, CrossTeam};
+use crate::game::rules::RuleSet;
 use crate::game::scoring::GameResult;
-use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::cross_state::repository::CrossStateRepository;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::id::{NormalMatch, NormalMatchStatus};
 use crate::redis::normal_match::repository::NormalMatchRepository;
 use crate::redis::player::repository::PlayerRepository;
 use crate::redis::pubsub::broadcasting;
@@ -66,7 +71,7 @@ pub async fn handle_game_completion(
         ));
     }
 
-    let game_result = sjavs_scoring.calculate_game_result();
+    let game_result = sjavs_scoring.calculate_game_result(&game_match.rules);
 
     // Log the game result for debugging
     println!(
@@ -77,16 +82,47 @@ pub async fn handle_game_completion(
         game_result.opponent_team_score
     );
 
-    // TODO: Apply cross/rubber scoring (Step 5)
-    // For now, create placeholder cross scores
-    let cross_scores = CrossScores {
-        trump_team_remaining: 24, // Will be updated in Step 5
-        opponent_team_remaining: 24,
-        trump_team_on_hook: false,
-        opponent_team_on_hook: false,
-        trump_team_crosses: 0,
-        opponent_team_crosses: 0,
-    };
+    let trump_declarer = game_match
+        .trump_declarer
+        .ok_or("No trump declarer recorded for completed game")?;
+
+    // Apply the result to the match's running cross
+    let mut cross_state =
+        CrossStateRepository::get_or_create_cross_state(conn, &game_id, &game_match.rules).await?;
+    let cross_result = cross_state.apply_game_result_for_declarer(&game_result, trump_declarer);
+
+    // Scores as this game left them, before a won cross is reset; the broadcast,
+    // the response and the caller all report this one line
+    let cross_scores = cross_scores_from_state(&cross_state);
+
+    let cross_won = cross_result.cross_won.as_ref().map(|winner| CrossWinner {
+        winning_team: match winner.winning_team {
+            CrossTeam::TrumpTeam => "team_a".to_string(),
+            CrossTeam::OpponentTeam => "team_b".to_string(),
+        },
+        double_victory: winner.double_victory,
+        winning_players: winner
+            .winning_team
+            .positions()
+            .iter()
+            .map(|&pos| pos as u8)
+            .collect(),
+    });
+
+    // A won cross either ends the match or starts the next cross from the top
+    let match_complete = cross_result.cross_won.is_some() && game_match.complete_cross();
+    if cross_result.cross_won.is_some() && !match_complete {
+        cross_state.reset_for_new_cross();
+    }
+
+    CrossStateRepository::store_cross_state(conn, &game_id, &cross_state).await?;
+
+    // Keep the final result around for clients that reconnect later
+    if let Err(e) =
+        store_game_result(conn, &game_id, &game_result, sjavs_scoring.individual_vol).await
+    {
+        eprintln!("Failed to store game result: {}", e);
+    }
 
     // Transition match to completed state
     game_match.status = NormalMatchStatus::Completed;
@@ -100,10 +136,42 @@ pub async fn handle_game_completion(
     }
 
     // Broadcast game completion
-    if let Err(e) = broadcast_game_complete(conn, &game_id, &game_result, &cross_scores).await {
+    if let Err(e) = broadcast_game_complete(
+        conn,
+        &game_id,
+        &game_result,
+        &cross_scores,
+        cross_won.as_ref(),
+        match_complete,
+    )
+    .await
+    {
         eprintln!("Failed to broadcast game completion: {}", e);
     }
 
+    // Let players check the finished deal against its commitment
+    if let Err(e) = game_fairness::reveal_latest_deal(conn, &game_id).await {
+        eprintln!("Failed to reveal deal: {}", e);
+    }
+
+    // Keep the finished deal so it can be analysed once the next one is dealt
+    if let Err(e) = game_analysis::archive_finished_deal(conn, &game_match).await {
+        eprintln!("Failed to archive finished deal: {}", e);
+    }
+
+    // Deal the next game unless the match is over
+    let new_game_ready = if match_complete {
+        false
+    } else {
+        match start_next_game(conn, &mut game_match).await {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("Failed to start next game: {}", e);
+                false
+            }
+        }
+    };
+
     let scoring_result = GameScoringResult {
         trump_team_points: sjavs_scoring.trump_team_points,
         opponent_team_points: sjavs_scoring.opponent_team_points,
@@ -121,22 +189,85 @@ pub async fn handle_game_completion(
         message: "Game completed and scored successfully".to_string(),
         game_id: game_id.clone(),
         scoring: scoring_result,
-        cross_scores: cross_scores,
-        cross_won: None,       // Will be implemented in Step 5
-        new_game_ready: false, // Will be implemented in Step 5
+        cross_scores: cross_scores.clone(),
+        cross_won,
+        new_game_ready,
+        match_complete,
     };
 
-    // Create cross_scores copy for return
-    let cross_scores_copy = CrossScores {
-        trump_team_remaining: 24,
-        opponent_team_remaining: 24,
-        trump_team_on_hook: false,
-        opponent_team_on_hook: false,
-        trump_team_crosses: 0,
-        opponent_team_crosses: 0,
-    };
+    Ok((response, game_result, cross_scores))
+}
+
+/// Rotate the deal and put the match straight back into bidding
+async fn start_next_game(
+    conn: &mut deadpool_redis::Connection,
+    game_match: &mut NormalMatch,
+) -> Result<(), String> {
+    let dealer_position = game_match.start_next_game()?;
+
+    // The finished deal was archived; its tricks and hands must not show up in the new one
+    TrickStateRepository::clear_trick_history(conn, &game_match.id).await?;
+    GameStateRepository::clear_hands(conn, &game_match.id).await?;
+
+    let players = PlayerRepository::get_players_in_game(conn, &game_match.id).await?;
+    game_start::deal_and_store_hands(conn, &game_match.id, &players, &game_match.rules).await?;
+
+    game_match.start_bidding();
+    NormalMatchRepository::update(conn, game_match)
+        .await
+        .map_err(|e| format!("Failed to update match for next game: {}", e))?;
+
+    if let Err(e) = broadcasting::broadcast_next_game(
+        conn,
+        &game_match.id,
+        dealer_position as u8,
+        game_match.current_bidder.unwrap_or(0) as u8,
+        game_match.current_cross,
+    )
+    .await
+    {
+        eprintln!("Failed to broadcast next game: {}", e);
+    }
 
-    Ok((response, game_result, cross_scores_copy))
+    Ok(())
+}
+
+/// Build API cross scores from the stored cross state
+fn cross_scores_from_state(cross_state: &CrossState) -> CrossScores {
+    let summary = cross_state.get_summary();
+
+    CrossScores {
+        team_a_remaining: summary.trump_team_score,
+        team_b_remaining: summary.opponent_team_score,
+        team_a_on_hook: summary.trump_team_on_hook,
+        team_b_on_hook: summary.opponent_team_on_hook,
+        team_a_crosses: summary.trump_team_crosses,
+        team_b_crosses: summary.opponent_team_crosses,
+        next_game_bonus: summary.next_game_bonus,
+    }
+}
+
+/// Store the final result of a game for the completed-phase state sync
+async fn store_game_result(
+    conn: &mut deadpool_redis::Connection,
+    game_id: &str,
+    game_result: &GameResult,
+    individual_vol: bool,
+) -> Result<(), String> {
+    let result_data = serde_json::json!({
+        "result_type": format!("{:?}", game_result.result_type),
+        "description": game_result.description,
+        "trump_team_score": game_result.trump_team_score,
+        "opponent_team_score": game_result.opponent_team_score,
+        "individual_vol": individual_vol
+    });
+
+    redis::cmd("SET")
+        .arg(format!("game_result:{}", game_id))
+        .arg(result_data.to_string())
+        .query_async::<_, ()>(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to store game result: {}", e))
 }
 
 /// Get current game scoring (for ongoing games)
@@ -242,14 +373,27 @@ pub async fn get_current_score_handler(
         game_complete: trick_state.game_complete,
     };
 
-    // Placeholder cross scores for Step 5
-    let cross_scores = CrossScores {
-        trump_team_remaining: 24,
-        opponent_team_remaining: 24,
-        trump_team_on_hook: false,
-        opponent_team_on_hook: false,
-        trump_team_crosses: 0,
-        opponent_team_crosses: 0,
+    // Get the running cross scores for this match
+    let cross_scores = match CrossStateRepository::get_cross_state(&mut conn, &game_id).await {
+        Ok(Some(cross_state)) => cross_scores_from_state(&cross_state),
+        Ok(None) => {
+            // No game finished yet - show the starting scores for the match's rules
+            let rules = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+                Ok(Some(game_match)) => game_match.rules,
+                _ => RuleSet::standard(),
+            };
+            cross_scores_from_state(&CrossState::new(game_id.clone(), &rules))
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get cross state: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
     };
 
     let response = GameScoreInfo {
@@ -267,6 +411,8 @@ async fn broadcast_game_complete(
     game_id: &str,
     game_result: &GameResult,
     cross_scores: &CrossScores,
+    cross_won: Option<&CrossWinner>,
+    match_complete: bool,
 ) -> Result<(), String> {
     let event_data = serde_json::json!({
         "type": "game_complete",
@@ -275,6 +421,8 @@ async fn broadcast_game_complete(
         "result_type": format!("{:?}", game_result.result_type),
         "description": game_result.description,
         "cross_scores": cross_scores,
+        "cross_won": cross_won,
+        "match_complete": match_complete,
         "timestamp": chrono::Utc::now().timestamp()
     });
 
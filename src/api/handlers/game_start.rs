@@ -1,9 +1,18 @@
-use crate::api::schemas::{ErrorResponse, GameStartState, PlayerInfo, StartGameResponse};
-use crate::game::deck::Deck;
+use crate::api::handlers::bots;
+use crate::api::schemas::{
+    BiddingAdviceInfo, ErrorResponse, GameStartState, PlayerInfo, StartGameResponse,
+    SuitEvaluationInfo,
+};
+use crate::game::advisor::{self, BiddingAdvice};
+use crate::game::deck::{DealRecord, Deck};
 use crate::game::hand::Hand;
+use crate::game::rules::RuleSet;
 use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::lobby::LobbyRepository;
+use crate::redis::normal_match::id::NormalMatch;
 use crate::redis::normal_match::repository::NormalMatchRepository;
-use crate::redis::player::repository::PlayerRepository;
+use crate::redis::notification::repository::NotificationRepository;
+use crate::redis::player::repository::{player_in_seat, seat_of, PlayerGameInfo, PlayerRepository};
 use crate::redis::pubsub::broadcasting;
 use crate::RedisPool;
 use axum::http::StatusCode;
@@ -12,13 +21,20 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::Utc;
+use deadpool_redis::Connection;
 use rand::Rng;
+use std::time::Duration;
+
+/// Seconds between everyone getting ready and the game starting by itself
+pub const AUTO_START_SECONDS: u64 = 10;
 
 /// Start a Sjavs game
 ///
 /// Transitions a match from Waiting to Bidding state by dealing cards to all players.
-/// Only the host can start the game, and exactly 4 players must be in the match.
-/// Cards are automatically dealt until at least one player has a valid bidding hand (5+ trumps).
+/// Only the host can start the game, and exactly 4 players must be in the match,
+/// every one of them ready.
+/// Cards are automatically dealt until at least one player holds enough trumps for the match's minimum bid.
 #[utoipa::path(
     post,
     path = "/game/start",
@@ -28,13 +44,13 @@ use rand::Rng;
     ),
     responses(
         (status = 200, description = "Game started successfully", body = StartGameResponse),
-        (status = 400, description = "Invalid game state or insufficient players", body = ErrorResponse),
+        (status = 400, description = "Invalid game state, insufficient players or not everyone ready", body = ErrorResponse),
         (status = 403, description = "Only host can start the game", body = ErrorResponse),
         (status = 404, description = "Game not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     summary = "Start a game",
-    description = "Transitions the match from waiting to bidding state. Automatically deals cards until valid hands exist. Only the host can start the game with exactly 4 players."
+    description = "Transitions the match from waiting to bidding state. Automatically deals cards until valid hands exist. Only the host can start the game with exactly 4 players, and only once every player has marked themselves ready over the websocket (bots are always ready)."
 )]
 #[axum::debug_handler]
 pub async fn start_game_handler(
@@ -81,7 +97,7 @@ pub async fn start_game_handler(
     };
 
     // Get the match details
-    let mut game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+    let game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
         Ok(Some(game_match)) => game_match,
         Ok(None) => {
             return (
@@ -132,140 +148,158 @@ pub async fn start_game_handler(
             .into_response();
     }
 
+    match start_match(&redis_pool, &mut conn, &game_id, game_match, players).await {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err((status, error)) => (status, Json(error)).into_response(),
+    }
+}
+
+/// Deal the first game of a waiting match and open the bidding
+///
+/// Shared by the host starting the game and the ready check's auto-start.
+/// Needs four players at the table, all of them ready.
+pub async fn start_match(
+    redis_pool: &RedisPool,
+    conn: &mut Connection,
+    game_id: &str,
+    mut game_match: NormalMatch,
+    players: Vec<PlayerGameInfo>,
+) -> Result<StartGameResponse, (StatusCode, ErrorResponse)> {
     // Validate that we have exactly 4 players
     if players.len() != 4 {
-        return (
+        return Err((
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
+            ErrorResponse {
                 error: "Invalid player count".to_string(),
                 message: Some(format!(
                     "Need exactly 4 players to start, but have {}",
                     players.len()
                 )),
-            }),
-        )
-            .into_response();
+            },
+        ));
     }
 
     // Check if game can be started (must be in Waiting state)
     if !game_match.can_start() {
-        return (
+        return Err((
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
+            ErrorResponse {
                 error: "Game cannot be started".to_string(),
                 message: Some(format!(
                     "Game is in '{}' state, but must be 'Waiting' to start",
                     game_match.status.to_string()
                 )),
-            }),
-        )
-            .into_response();
+            },
+        ));
     }
 
-    // Choose random dealer position (0-3)
-    let dealer_position = rand::thread_rng().gen_range(0..4);
-
-    // Start dealing phase
-    game_match.start_dealing(dealer_position);
-
-    // Deal cards until we get valid hands (this may take multiple attempts)
-    let dealing_start = std::time::Instant::now();
-    let mut attempts = 0;
-
-    loop {
-        attempts += 1;
-
-        // Safety check to prevent infinite loops
-        if attempts > 1000 {
-            return (
+    // Everyone at the table must be ready
+    let ready_check = match NormalMatchRepository::get_ready_check(conn, game_id).await {
+        Ok(ready_check) => ready_check,
+        Err(e) => {
+            return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: "Unable to deal valid hands".to_string(),
-                    message: Some("Exceeded maximum dealing attempts".to_string()),
-                }),
-            )
-                .into_response();
+                ErrorResponse {
+                    error: e,
+                    message: None,
+                },
+            ));
         }
+    };
+    if !ready_check.waiting_for.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            ErrorResponse {
+                error: "Not everyone is ready".to_string(),
+                message: Some(format!(
+                    "Waiting for {} to get ready",
+                    ready_check.waiting_for.join(", ")
+                )),
+            },
+        ));
+    }
 
-        // Deal cards
-        let hands = Deck::deal_until_valid();
-
-        // Convert to Hand objects with proper player positions
-        let hand_objects: [Hand; 4] = [
-            Hand::new(hands[0].clone(), 0),
-            Hand::new(hands[1].clone(), 1),
-            Hand::new(hands[2].clone(), 2),
-            Hand::new(hands[3].clone(), 3),
-        ];
-
-        // Store hands in Redis
-        if let Err(e) = GameStateRepository::store_hands(&mut conn, &game_id, &hand_objects).await {
-            return (
+    // Seats chosen in the waiting room become positions, so chosen partners sit
+    // opposite each other. Anyone without a seat (matches from before seating)
+    // takes the next free one.
+    let mut seating = match NormalMatchRepository::get_seating(conn, game_id).await {
+        Ok(seating) => seating,
+        Err(e) => {
+            return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("Failed to store hands: {}", e),
+                ErrorResponse {
+                    error: e,
                     message: None,
-                }),
-            )
-                .into_response();
+                },
+            ));
         }
-
-        // Broadcast hand updates to each player via WebSocket
-        for (i, hand) in hand_objects.iter().enumerate() {
-            if i < players.len() {
-                let hand_data = serde_json::json!({
-                    "cards": hand.to_codes(),
-                    "trump_counts": hand.calculate_trump_counts(),
-                    "available_bids": hand.get_available_bids(None),
-                    "best_bid": hand.get_best_bid()
-                });
-
-                if let Err(e) = broadcasting::broadcast_hand_update(
-                    &mut conn,
-                    &game_id,
-                    &players[i].user_id,
-                    &hand_data,
-                )
-                .await
-                {
-                    eprintln!("Failed to broadcast hand update to player {}: {}", i, e);
-                }
-            }
+    };
+    if players
+        .iter()
+        .any(|p| seating.seat_of(&p.user_id).is_none())
+    {
+        for player in &players {
+            seating.seat(&player.user_id);
+        }
+        if let Err(e) = NormalMatchRepository::save_seating(conn, game_id, &seating).await {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse {
+                    error: e,
+                    message: None,
+                },
+            ));
         }
-
-        // Hands are valid by design (deal_until_valid ensures this), so we can break
-        break;
     }
+    let players = seating.order(players, |p| &p.user_id);
 
-    let dealing_duration = dealing_start.elapsed();
-    println!(
-        "Dealt valid hands after {} attempts in {:?}",
-        attempts, dealing_duration
-    );
+    // Choose random dealer position (0-3)
+    let dealer_position = rand::thread_rng().gen_range(0..4);
+
+    // Start dealing phase
+    game_match.start_dealing(dealer_position);
+
+    // Deal cards until we get valid hands (this may take multiple attempts)
+    let (_, deal_record) =
+        match deal_and_store_hands(conn, game_id, &players, &game_match.rules).await {
+            Ok(dealt) => dealt,
+            Err(e) => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ErrorResponse {
+                        error: e,
+                        message: None,
+                    },
+                ));
+            }
+        };
 
     // Transition to bidding state
     game_match.start_bidding();
 
     // Update the match in Redis
-    if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
-        return (
+    if let Err(e) = NormalMatchRepository::update(conn, &game_match).await {
+        return Err((
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
+            ErrorResponse {
                 error: format!("Failed to update game state: {}", e),
                 message: None,
-            }),
-        )
-            .into_response();
+            },
+        ));
     }
 
-    // Store hand analysis for debugging
-    let analysis = Deck::analyze_hands(&[
-        Hand::new(vec![], 0).cards, // We'll get the actual hands from Redis if needed
-        Hand::new(vec![], 1).cards,
-        Hand::new(vec![], 2).cards,
-        Hand::new(vec![], 3).cards,
-    ]);
-    let _ = GameStateRepository::store_hand_analysis(&mut conn, &game_id, &analysis).await;
+    // The ready check is over once the game is under way
+    if let Err(e) = NormalMatchRepository::clear_ready_check(conn, game_id).await {
+        eprintln!("Failed to clear ready check for {}: {}", game_id, e);
+    }
+
+    // A started match leaves the lobby
+    if let Err(e) = LobbyRepository::refresh(conn, game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
+    // Bots may be first to bid
+    bots::spawn_bot_turns(redis_pool.clone(), game_id.to_string());
 
     // Prepare response
     let game_state = GameStartState {
@@ -286,13 +320,194 @@ pub async fn start_game_handler(
 
     let response = StartGameResponse {
         message: "Game started successfully".to_string(),
-        game_id: game_id.clone(),
+        game_id: game_id.to_string(),
         state: game_state,
         hands_dealt: true,
-        dealing_attempts: attempts,
+        dealing_attempts: deal_record.attempts,
+        deal_commitment: deal_record.commitment.clone(),
     };
 
-    (StatusCode::OK, Json(response)).into_response()
+    Ok(response)
+}
+
+/// Start the auto-start countdown if the host turned auto-start on and everyone is ready
+///
+/// Call after anything that may complete the ready check: a player getting
+/// ready or the host turning auto-start on. Publishes `auto_start_countdown`.
+pub async fn check_auto_start(
+    redis_pool: &RedisPool,
+    conn: &mut Connection,
+    game_id: &str,
+) -> Result<(), String> {
+    let game_match = NormalMatchRepository::get_by_id(conn, game_id)
+        .await?
+        .ok_or("Game not found")?;
+    if !game_match.auto_start || !game_match.can_start() {
+        return Ok(());
+    }
+
+    let ready_check = NormalMatchRepository::get_ready_check(conn, game_id).await?;
+    if !ready_check.everyone_ready() {
+        return Ok(());
+    }
+
+    let starts_at = Utc::now().timestamp_millis() + AUTO_START_SECONDS as i64 * 1000;
+    if !NormalMatchRepository::start_countdown(conn, game_id, starts_at, AUTO_START_SECONDS).await?
+    {
+        return Ok(()); // Already counting down
+    }
+    spawn_auto_start(redis_pool.clone(), game_id.to_string(), starts_at);
+
+    NotificationRepository::publish_auto_start_countdown(
+        conn,
+        game_id,
+        starts_at,
+        AUTO_START_SECONDS,
+        ready_check.ready,
+    )
+    .await
+}
+
+/// Stop the auto-start countdown, if one is running, and tell the table why
+///
+/// Call when the ready check falls apart: a player getting unready, leaving
+/// or being kicked, or the host turning auto-start off. Publishes
+/// `auto_start_cancelled`.
+pub async fn cancel_auto_start(
+    conn: &mut Connection,
+    game_id: &str,
+    reason: &str,
+) -> Result<(), String> {
+    if !NormalMatchRepository::clear_countdown(conn, game_id).await? {
+        return Ok(());
+    }
+
+    let players = PlayerRepository::get_players_in_game(conn, game_id).await?;
+    let affected_players = players.into_iter().map(|p| p.user_id).collect();
+    NotificationRepository::publish_auto_start_cancelled(conn, game_id, reason, affected_players)
+        .await
+}
+
+/// Start the game when the countdown runs out, unless it was cancelled first
+fn spawn_auto_start(redis_pool: RedisPool, game_id: String, starts_at: i64) {
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(AUTO_START_SECONDS)).await;
+
+        let mut conn = match redis_pool.get().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                eprintln!("Failed to get Redis connection for auto-start: {}", e);
+                return;
+            }
+        };
+
+        // A cancelled or restarted countdown leaves the game to its successor
+        match NormalMatchRepository::get_countdown(&mut conn, &game_id).await {
+            Ok(Some(countdown)) if countdown == starts_at => {}
+            Ok(_) => return,
+            Err(e) => {
+                eprintln!("Failed to check auto-start countdown: {}", e);
+                return;
+            }
+        }
+
+        // Everything is checked again, as the table may have changed since
+        let started: Result<StartGameResponse, String> = async {
+            let game_match = NormalMatchRepository::get_by_id(&mut conn, &game_id)
+                .await?
+                .ok_or("Game not found")?;
+            let players = PlayerRepository::get_players_in_game(&mut conn, &game_id).await?;
+            start_match(&redis_pool, &mut conn, &game_id, game_match, players)
+                .await
+                .map_err(|(_, error)| error.message.unwrap_or(error.error))
+        }
+        .await;
+
+        match started {
+            Ok(_) => {
+                if let Err(e) = broadcasting::broadcast_game_state_update(&mut conn, &game_id).await
+                {
+                    eprintln!("Failed to broadcast auto-start: {}", e);
+                }
+            }
+            Err(reason) => {
+                if let Err(e) = cancel_auto_start(&mut conn, &game_id, &reason).await {
+                    eprintln!("Failed to cancel auto-start: {}", e);
+                }
+            }
+        }
+    });
+}
+
+/// Deal a new set of valid hands, store them and send each player their cards
+///
+/// A commitment to the deck order is broadcast before the hands, and
+/// `game_fairness::reveal_latest_deal` opens it once the deal is over.
+///
+/// Shared by the initial game start, redeals and the automatic deal between games.
+pub async fn deal_and_store_hands(
+    conn: &mut Connection,
+    game_id: &str,
+    players: &[PlayerGameInfo],
+    rules: &RuleSet,
+) -> Result<([Hand; 4], DealRecord), String> {
+    let (hands, deal_record) = Deck::deal_recorded(rules.min_bid);
+
+    // Convert to Hand objects with proper player positions
+    let hand_objects: [Hand; 4] = [
+        Hand::new(hands[0].clone(), 0),
+        Hand::new(hands[1].clone(), 1),
+        Hand::new(hands[2].clone(), 2),
+        Hand::new(hands[3].clone(), 3),
+    ];
+
+    GameStateRepository::store_hands(conn, game_id, &hand_objects)
+        .await
+        .map_err(|e| format!("Failed to store hands: {}", e))?;
+
+    // Keep the seed so the deal can be reproduced later
+    let deal_number = GameStateRepository::store_deal_record(conn, game_id, &deal_record).await?;
+
+    // Bidding and hint counts start afresh on every deal
+    GameStateRepository::clear_bidding_actions(conn, game_id).await?;
+    GameStateRepository::clear_hint_uses(conn, game_id).await?;
+
+    // Commit to the deck order before anyone sees their cards
+    if let Err(e) = broadcasting::broadcast_deal_commitment(
+        conn,
+        game_id,
+        deal_number as u32,
+        &deal_record.commitment,
+    )
+    .await
+    {
+        eprintln!("Failed to broadcast deal commitment: {}", e);
+    }
+
+    // Broadcast hand updates to each player via WebSocket
+    for (i, hand) in hand_objects.iter().enumerate() {
+        if let Some(player) = player_in_seat(players, i) {
+            let hand_data = serde_json::json!({
+                "cards": hand.to_codes(),
+                "trump_counts": hand.calculate_trump_counts(),
+                "available_bids": hand.get_available_bids(None, rules.min_bid),
+                "best_bid": hand.get_best_bid()
+            });
+
+            if let Err(e) =
+                broadcasting::broadcast_hand_update(conn, game_id, &player.user_id, &hand_data)
+                    .await
+            {
+                eprintln!("Failed to broadcast hand update to player {}: {}", i, e);
+            }
+        }
+    }
+
+    // Store hand analysis for debugging
+    let analysis = Deck::analyze_hands(&hands);
+    let _ = GameStateRepository::store_hand_analysis(conn, game_id, &analysis).await;
+
+    Ok((hand_objects, deal_record))
 }
 
 /// Get player's hand
@@ -313,7 +528,7 @@ pub async fn start_game_handler(
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     summary = "Get player's hand",
-    description = "Returns the current player's hand with cards and bidding options"
+    description = "Returns the current player's hand with cards and bidding options. During bidding, players who enabled hints in their settings also get bidding advice if the match allows hints."
 )]
 #[axum::debug_handler]
 pub async fn get_player_hand_handler(
@@ -414,10 +629,7 @@ pub async fn get_player_hand_handler(
         }
     };
 
-    let player_position = players
-        .iter()
-        .position(|p| p.user_id == user_id)
-        .unwrap_or(0);
+    let player_position = seat_of(&players, &user_id).unwrap_or(0);
 
     // Get the player's hand
     let hand = match GameStateRepository::get_hand(&mut conn, &game_id, player_position).await {
@@ -446,24 +658,64 @@ pub async fn get_player_hand_handler(
 
     // Get available bids (only if in bidding state)
     let available_bids = if game_match.status.to_string() == "Bidding" {
-        hand.get_available_bids(game_match.highest_bid_length)
+        hand.get_available_bids(game_match.highest_bid_length, game_match.rules.min_bid)
     } else {
         vec![]
     };
 
+    // Bidding advice for players who turned hints on
+    let hints_enabled =
+        match PlayerRepository::get_hints_enabled(&mut conn, &game_id, &user_id).await {
+            Ok(enabled) => enabled,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e,
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    let bidding_advice = if hints_enabled
+        && game_match.allow_hints
+        && game_match.status.to_string() == "Bidding"
+    {
+        let advice_hand = hand.clone();
+        let dealer = game_match.dealer_position.unwrap_or(0);
+        let rules = game_match.rules.clone();
+        let current_highest = game_match.highest_bid_length;
+
+        // Playing out the sampled deals takes a moment - keep it off the async workers
+        match tokio::task::spawn_blocking(move || {
+            advice_hand.bidding_advice(dealer, &rules, current_highest, advisor::DEFAULT_SAMPLES)
+        })
+        .await
+        {
+            Ok(advice) => Some(bidding_advice_info(advice)),
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("Bidding advice failed: {}", e),
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        }
+    } else {
+        None
+    };
+
     // Convert to API format
     let trump_counts = hand.calculate_trump_counts();
     let can_bid = !available_bids.is_empty();
 
-    let api_bids: Vec<crate::api::schemas::BidOption> = available_bids
-        .into_iter()
-        .map(|bid| crate::api::schemas::BidOption {
-            length: bid.length,
-            suit: bid.suit,
-            display_text: bid.display_text,
-            is_club_declaration: bid.is_club_declaration,
-        })
-        .collect();
+    let api_bids: Vec<crate::api::schemas::BidOption> =
+        available_bids.into_iter().map(bid_option_info).collect();
 
     let response = crate::api::schemas::PlayerHandResponse {
         message: "Hand retrieved successfully".to_string(),
@@ -473,7 +725,35 @@ pub async fn get_player_hand_handler(
         trump_counts,
         available_bids: api_bids,
         can_bid,
+        bidding_advice,
     };
 
     (StatusCode::OK, Json(response)).into_response()
 }
+
+fn bid_option_info(bid: crate::game::hand::BidOption) -> crate::api::schemas::BidOption {
+    crate::api::schemas::BidOption {
+        length: bid.length,
+        suit: bid.suit,
+        display_text: bid.display_text,
+        is_club_declaration: bid.is_club_declaration,
+    }
+}
+
+fn bidding_advice_info(advice: BiddingAdvice) -> BiddingAdviceInfo {
+    BiddingAdviceInfo {
+        evaluations: advice
+            .evaluations
+            .into_iter()
+            .map(|evaluation| SuitEvaluationInfo {
+                suit: evaluation.suit,
+                trumps_held: evaluation.trumps_held,
+                expected_points: evaluation.expected_points,
+                expected_score: evaluation.expected_score,
+                win_probability: evaluation.win_probability,
+                results: evaluation.results,
+            })
+            .collect(),
+        recommended_bid: advice.recommended_bid.map(bid_option_info),
+    }
+}
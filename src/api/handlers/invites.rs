@@ -0,0 +1,335 @@
+use crate::api::schemas::{
+    CreateInviteRequest, CreateInviteResponse, ErrorResponse, InviteInfo, InviteListResponse,
+    RevokeInviteResponse,
+};
+use crate::redis::invite::{Invite, InviteRepository};
+use crate::redis::player::repository::PlayerRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use deadpool_redis::Connection;
+
+/// Invites last a day unless the host says otherwise
+const DEFAULT_EXPIRY_MINUTES: u32 = 24 * 60;
+
+/// Longest an invite may last (a week)
+const MAX_EXPIRY_MINUTES: u32 = 7 * 24 * 60;
+
+/// Enough uses to fill the other three seats
+const DEFAULT_MAX_USES: u32 = 3;
+
+/// Invite players to the match you host
+///
+/// Returns a signed token that joins the match through `/normal-match/join`,
+/// even when the match isn't listed in the lobby. The token stops working when
+/// it expires, runs out of uses or is revoked.
+#[utoipa::path(
+    post,
+    path = "/normal-match/invites",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body(content = Option<CreateInviteRequest>, description = "Optional expiry and use count"),
+    responses(
+        (status = 201, description = "Invite created", body = CreateInviteResponse),
+        (status = 400, description = "Not in a game, or invalid expiry or use count", body = ErrorResponse),
+        (status = 403, description = "Only the host can invite players", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Create an invite",
+    description = "Host-only. Creates an invite to the host's match that expires after `expires_in_minutes` (default a day, at most a week) and admits up to `max_uses` players (default 3). The token is only returned here; share it with the players you invite."
+)]
+#[axum::debug_handler]
+pub async fn create_invite_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    payload: Option<Json<CreateInviteRequest>>,
+) -> Response {
+    let (expires_in_minutes, max_uses) = match payload {
+        Some(Json(request)) => (request.expires_in_minutes, request.max_uses),
+        None => (None, None),
+    };
+    let expires_in_minutes = expires_in_minutes.unwrap_or(DEFAULT_EXPIRY_MINUTES);
+    let max_uses = max_uses.unwrap_or(DEFAULT_MAX_USES);
+
+    if expires_in_minutes == 0 || expires_in_minutes > MAX_EXPIRY_MINUTES {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Invalid expiry".to_string(),
+                message: Some(format!(
+                    "expires_in_minutes must be between 1 and {}",
+                    MAX_EXPIRY_MINUTES
+                )),
+            }),
+        )
+            .into_response();
+    }
+    if max_uses == 0 {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Invalid use count".to_string(),
+                message: Some("max_uses must be at least 1".to_string()),
+            }),
+        )
+            .into_response();
+    }
+
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Only the host may invite
+    let game_id = match hosted_game(&mut conn, &user_id, "invite players").await {
+        Ok(game_id) => game_id,
+        Err(response) => return response,
+    };
+
+    // 2. Store and sign the invite
+    let expires_at = chrono::Utc::now().timestamp() as u64 + expires_in_minutes as u64 * 60;
+    let (invite, token) =
+        match InviteRepository::create(&mut conn, &game_id, &user_id, expires_at, max_uses).await {
+            Ok(created) => created,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e,
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    (
+        StatusCode::CREATED,
+        Json(CreateInviteResponse {
+            message: "Invite created".to_string(),
+            invite: invite_info(invite),
+            token,
+        }),
+    )
+        .into_response()
+}
+
+/// List invites to the match you host
+#[utoipa::path(
+    get,
+    path = "/normal-match/invites",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Live invites", body = InviteListResponse),
+        (status = 400, description = "Not in a game", body = ErrorResponse),
+        (status = 403, description = "Only the host can see invites", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "List invites",
+    description = "Host-only. Returns every invite to the host's match that hasn't expired or been revoked, with its use count. Tokens are not included."
+)]
+#[axum::debug_handler]
+pub async fn list_invites_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let game_id = match hosted_game(&mut conn, &user_id, "see invites").await {
+        Ok(game_id) => game_id,
+        Err(response) => return response,
+    };
+
+    match InviteRepository::get_for_game(&mut conn, &game_id).await {
+        Ok(invites) => (
+            StatusCode::OK,
+            Json(InviteListResponse {
+                game_id,
+                invites: invites.into_iter().map(invite_info).collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e,
+                message: None,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+/// Revoke an invite to the match you host
+#[utoipa::path(
+    delete,
+    path = "/normal-match/invites/{invite_id}",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    params(
+        ("invite_id" = String, Path, description = "Invite to revoke")
+    ),
+    responses(
+        (status = 200, description = "Invite revoked", body = RevokeInviteResponse),
+        (status = 400, description = "Not in a game", body = ErrorResponse),
+        (status = 403, description = "Only the host can revoke invites", body = ErrorResponse),
+        (status = 404, description = "No such invite to this match", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Revoke an invite",
+    description = "Host-only. The invite's token is refused from now on. Players who already joined with it stay in the match."
+)]
+#[axum::debug_handler]
+pub async fn revoke_invite_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Path(invite_id): Path<String>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let game_id = match hosted_game(&mut conn, &user_id, "revoke invites").await {
+        Ok(game_id) => game_id,
+        Err(response) => return response,
+    };
+
+    match InviteRepository::revoke(&mut conn, &game_id, &invite_id).await {
+        Ok(true) => (
+            StatusCode::OK,
+            Json(RevokeInviteResponse {
+                message: "Invite revoked".to_string(),
+                invite_id,
+            }),
+        )
+            .into_response(),
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Invite not found".to_string(),
+                message: Some(format!("No invite {} to game {}", invite_id, game_id)),
+            }),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e,
+                message: None,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+/// The game `user_id` hosts, or the error response to send instead
+async fn hosted_game(
+    conn: &mut Connection,
+    user_id: &str,
+    action: &str,
+) -> Result<String, Response> {
+    let game_id = match PlayerRepository::get_player_game(conn, user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some(format!("You must host a match to {}", action)),
+                }),
+            )
+                .into_response());
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response());
+        }
+    };
+
+    let players = PlayerRepository::get_players_in_game(conn, &game_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get players: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response()
+        })?;
+
+    if !players
+        .iter()
+        .any(|p| p.user_id == user_id && p.role == "host")
+    {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: format!("Only the host can {}", action),
+                message: None,
+            }),
+        )
+            .into_response());
+    }
+
+    Ok(game_id)
+}
+
+fn invite_info(invite: Invite) -> InviteInfo {
+    InviteInfo {
+        invite_id: invite.invite_id,
+        game_id: invite.game_id,
+        max_uses: invite.max_uses,
+        uses: invite.uses,
+        expires_at: invite.expires_at,
+    }
+}
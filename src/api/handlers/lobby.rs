@@ -0,0 +1,76 @@
+use crate::api::handlers::normal_match::rule_set_info;
+use crate::api::schemas::{ErrorResponse, LobbyMatch, LobbyResponse};
+use crate::redis::lobby::LobbyRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::State,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// List public matches waiting for players
+///
+/// Only matches created with `public` set appear here, and only until they
+/// start. Subscribe to the lobby over the WebSocket to follow changes live.
+#[utoipa::path(
+    get,
+    path = "/lobby",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Public matches waiting for players", body = LobbyResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "List public matches",
+    description = "Returns every public match that has not started yet, oldest first, with its host, seats taken, house rules and number of crosses. Join one with its PIN through /normal-match/join. Send `lobby_subscribe` over the WebSocket to receive `lobby_match_created`, `lobby_match_updated`, `lobby_match_filled`, `lobby_match_started` and `lobby_match_closed` events."
+)]
+#[axum::debug_handler]
+pub async fn get_lobby_handler(State(redis_pool): State<RedisPool>) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let listings = match LobbyRepository::get_listings(&mut conn).await {
+        Ok(listings) => listings,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let matches = listings
+        .into_iter()
+        .map(|listing| LobbyMatch {
+            rules: rule_set_info(&listing.rules),
+            game_id: listing.game_id,
+            pin: listing.pin,
+            host_id: listing.host_id,
+            host_name: listing.host_name,
+            seats_taken: listing.seats_taken,
+            seats: listing.seats,
+            number_of_crosses: listing.number_of_crosses,
+            created_timestamp: listing.created_timestamp,
+        })
+        .collect();
+
+    (StatusCode::OK, Json(LobbyResponse { matches })).into_response()
+}
@@ -1,9 +1,23 @@
+pub mod admin;
+pub mod bots;
 pub mod debug;
+pub mod game_analysis;
 pub mod game_bidding;
+pub mod game_claim;
+pub mod game_fairness;
+pub mod game_hint;
+pub mod game_notation;
 pub mod game_playing;
+pub mod game_replay;
 pub mod game_scoring;
 pub mod game_start;
+pub mod invites;
+pub mod lobby;
+pub mod moderation;
 pub mod normal_match;
 pub mod normal_match_join;
 pub mod normal_match_leave;
 pub mod openapi;
+pub mod player_settings;
+pub mod rule_violation;
+pub mod seating;
@@ -0,0 +1,330 @@
+use crate::api::handlers::game_start;
+use crate::api::schemas::{
+    ErrorResponse, LockRoomRequest, ModerationResponse, TargetPlayerRequest,
+};
+use crate::redis::lobby::LobbyRepository;
+use crate::redis::normal_match::id::{NormalMatch, NormalMatchStatus};
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::notification::repository::NotificationRepository;
+use crate::redis::player::repository::{PlayerGameInfo, PlayerRepository};
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use deadpool_redis::Connection;
+
+/// How long a kicked player has to wait before rejoining the same match
+pub const KICK_COOLDOWN_SECONDS: u64 = 5 * 60;
+
+/// Why a moderation action was refused, as the HTTP status and a reason
+pub type Refusal = (StatusCode, String);
+
+/// Remove a player from the match you host
+#[utoipa::path(
+    post,
+    path = "/normal-match/kick",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = TargetPlayerRequest,
+    responses(
+        (status = 200, description = "Player removed", body = ModerationResponse),
+        (status = 400, description = "Game already started, or kicking yourself", body = ErrorResponse),
+        (status = 403, description = "Only the host can kick players", body = ErrorResponse),
+        (status = 404, description = "Player not in the match", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Kick a player",
+    description = "Host-only, while the match is waiting for players. The player is removed from the match and can't rejoin it for five minutes. Everyone, the kicked player included, gets a `player_kicked` event."
+)]
+#[axum::debug_handler]
+pub async fn kick_player_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<TargetPlayerRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => return refusal_response((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    };
+
+    match kick_player(&mut conn, &user_id, &payload.player_id).await {
+        Ok(game_id) => moderation_response("Player kicked", game_id),
+        Err(refusal) => refusal_response(refusal),
+    }
+}
+
+/// Hand the match you host to another player
+#[utoipa::path(
+    post,
+    path = "/normal-match/host",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = TargetPlayerRequest,
+    responses(
+        (status = 200, description = "Host transferred", body = ModerationResponse),
+        (status = 400, description = "Target is yourself or a bot", body = ErrorResponse),
+        (status = 403, description = "Only the host can transfer the role", body = ErrorResponse),
+        (status = 404, description = "Player not in the match", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Transfer host",
+    description = "Host-only. The target player becomes host and the current host stays in the match as a regular player. Everyone gets a `host_changed` event."
+)]
+#[axum::debug_handler]
+pub async fn transfer_host_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<TargetPlayerRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => return refusal_response((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    };
+
+    match transfer_host(&mut conn, &user_id, &payload.player_id).await {
+        Ok(game_id) => moderation_response("Host transferred", game_id),
+        Err(refusal) => refusal_response(refusal),
+    }
+}
+
+/// Lock or unlock the room you host
+#[utoipa::path(
+    post,
+    path = "/normal-match/lock",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = LockRoomRequest,
+    responses(
+        (status = 200, description = "Room locked or unlocked", body = ModerationResponse),
+        (status = 403, description = "Only the host can lock the room", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Lock the room",
+    description = "Host-only. A locked room refuses joins by PIN and by invite, and is hidden from the lobby. The host can still add bots. Everyone gets a `room_locked` event."
+)]
+#[axum::debug_handler]
+pub async fn lock_room_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<LockRoomRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => return refusal_response((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    };
+
+    match lock_room(&mut conn, &user_id, payload.locked).await {
+        Ok(game_id) if payload.locked => moderation_response("Room locked", game_id),
+        Ok(game_id) => moderation_response("Room unlocked", game_id),
+        Err(refusal) => refusal_response(refusal),
+    }
+}
+
+/// Remove `player_id` from the waiting match `host_id` hosts, returning the game ID
+pub async fn kick_player(
+    conn: &mut Connection,
+    host_id: &str,
+    player_id: &str,
+) -> Result<String, Refusal> {
+    let (game_match, players) = hosted_match(conn, host_id).await?;
+    let game_id = game_match.id;
+
+    if game_match.status != NormalMatchStatus::Waiting {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Players can only be kicked before the game starts".to_string(),
+        ));
+    }
+    if player_id == host_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "You can't kick yourself; leave the match instead".to_string(),
+        ));
+    }
+    if !players.iter().any(|p| p.user_id == player_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Player {} is not in this match", player_id),
+        ));
+    }
+
+    NormalMatchRepository::remove_player(conn, &game_id, player_id)
+        .await
+        .map_err(internal)?;
+    NormalMatchRepository::set_kick_cooldown(conn, &game_id, player_id, KICK_COOLDOWN_SECONDS)
+        .await
+        .map_err(internal)?;
+    if let Err(e) = game_start::cancel_auto_start(conn, &game_id, "A player was kicked").await {
+        eprintln!("Failed to cancel auto-start: {}", e);
+    }
+
+    let affected_players = players.into_iter().map(|p| p.user_id).collect();
+    if let Err(e) = NotificationRepository::publish_player_kicked(
+        conn,
+        &game_id,
+        player_id,
+        KICK_COOLDOWN_SECONDS,
+        affected_players,
+    )
+    .await
+    {
+        eprintln!("Failed to publish player kicked event: {}", e);
+    }
+    if let Err(e) = LobbyRepository::refresh(conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
+    Ok(game_id)
+}
+
+/// Make `player_id` host of the match `host_id` hosts, returning the game ID
+pub async fn transfer_host(
+    conn: &mut Connection,
+    host_id: &str,
+    player_id: &str,
+) -> Result<String, Refusal> {
+    let (game_match, players) = hosted_match(conn, host_id).await?;
+    let game_id = game_match.id;
+
+    let target = match players.iter().find(|p| p.user_id == player_id) {
+        Some(target) => target,
+        None => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                format!("Player {} is not in this match", player_id),
+            ))
+        }
+    };
+    if target.user_id == host_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "You are already the host".to_string(),
+        ));
+    }
+    if target.is_bot() {
+        return Err((StatusCode::BAD_REQUEST, "A bot can't be host".to_string()));
+    }
+
+    NormalMatchRepository::set_role(conn, &game_id, player_id, "host")
+        .await
+        .map_err(internal)?;
+    NormalMatchRepository::set_role(conn, &game_id, host_id, "player")
+        .await
+        .map_err(internal)?;
+
+    let affected_players = players.into_iter().map(|p| p.user_id).collect();
+    if let Err(e) = NotificationRepository::publish_host_changed(
+        conn,
+        &game_id,
+        player_id,
+        affected_players,
+        "Host handed over",
+    )
+    .await
+    {
+        eprintln!("Failed to publish host change event: {}", e);
+    }
+    if let Err(e) = LobbyRepository::refresh(conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
+    Ok(game_id)
+}
+
+/// Lock or unlock the match `host_id` hosts, returning the game ID
+pub async fn lock_room(
+    conn: &mut Connection,
+    host_id: &str,
+    locked: bool,
+) -> Result<String, Refusal> {
+    let (mut game_match, players) = hosted_match(conn, host_id).await?;
+
+    game_match.is_locked = locked;
+    NormalMatchRepository::update(conn, &game_match)
+        .await
+        .map_err(internal)?;
+
+    let game_id = game_match.id;
+    let affected_players = players.into_iter().map(|p| p.user_id).collect();
+    if let Err(e) =
+        NotificationRepository::publish_room_locked(conn, &game_id, locked, affected_players).await
+    {
+        eprintln!("Failed to publish room lock event: {}", e);
+    }
+    if let Err(e) = LobbyRepository::refresh(conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
+    Ok(game_id)
+}
+
+/// The match `user_id` hosts, with its players
+async fn hosted_match(
+    conn: &mut Connection,
+    user_id: &str,
+) -> Result<(NormalMatch, Vec<PlayerGameInfo>), Refusal> {
+    let game_id = match PlayerRepository::get_player_game(conn, user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "You must host a match to moderate it".to_string(),
+            ))
+        }
+        Err(e) => return Err(internal(e)),
+    };
+
+    let players = PlayerRepository::get_players_in_game(conn, &game_id)
+        .await
+        .map_err(internal)?;
+    if !players
+        .iter()
+        .any(|p| p.user_id == user_id && p.role == "host")
+    {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Only the host can moderate the match".to_string(),
+        ));
+    }
+
+    match NormalMatchRepository::get_by_id(conn, &game_id).await {
+        Ok(Some(game_match)) => Ok((game_match, players)),
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Game not found".to_string())),
+        Err(e) => Err(internal(e)),
+    }
+}
+
+fn internal(error: String) -> Refusal {
+    (StatusCode::INTERNAL_SERVER_ERROR, error)
+}
+
+fn moderation_response(message: &str, game_id: String) -> Response {
+    (
+        StatusCode::OK,
+        Json(ModerationResponse {
+            message: message.to_string(),
+            game_id,
+        }),
+    )
+        .into_response()
+}
+
+fn refusal_response((status, error): Refusal) -> Response {
+    (
+        status,
+        Json(ErrorResponse {
+            error,
+            message: None,
+        }),
+    )
+        .into_response()
+}
@@ -1,8 +1,16 @@
-use crate::api::schemas::{CreateMatchResponse, ErrorResponse, MatchState};
+use crate::api::handlers::game_start;
+use crate::api::schemas::{
+    CreateMatchRequest, CreateMatchResponse, ErrorResponse, MatchSettingsRequest,
+    MatchSettingsResponse, MatchState, RuleSetInfo, ScoreValuesInfo,
+};
 use crate::auth::extract_username_from_jwt_token;
+use crate::game::rules::{RuleSet, ScoreValues, PRESETS};
+use crate::redis::lobby::LobbyRepository;
 use crate::redis::normal_match::id::NormalMatch;
 use crate::redis::normal_match::repository::NormalMatchRepository;
 use crate::redis::player::repository::PlayerRepository;
+use crate::redis::pubsub::broadcasting;
+use crate::redis::takeback::TakebackRepository;
 use crate::RedisPool;
 use axum::http::{HeaderMap, StatusCode};
 use axum::{
@@ -17,6 +25,7 @@ use rand::Rng;
 /// Creates a new match with a unique game ID and 4-digit PIN code.
 /// The requesting user becomes the host of the match.
 /// Only one active match per player is allowed.
+/// House rules are picked from a preset or given in full, and stay fixed for the match.
 #[utoipa::path(
     post,
     path = "/normal-match",
@@ -24,20 +33,50 @@ use rand::Rng;
     security(
         ("jwt_auth" = [])
     ),
+    request_body(content = Option<CreateMatchRequest>, description = "Optional house rules"),
     responses(
         (status = 201, description = "Match created successfully", body = CreateMatchResponse),
+        (status = 400, description = "Unknown rule preset or invalid rules", body = ErrorResponse),
         (status = 409, description = "Player already in an active game", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     summary = "Create a new match",
-    description = "Creates a new Sjavs match. The authenticated user becomes the host. Returns a PIN code that other players can use to join."
+    description = "Creates a new Sjavs match. The authenticated user becomes the host. Returns a PIN code that other players can use to join. House rules default to the \"standard\" preset; pass another preset or a full custom rule set to change them. Set `public` to list the match in the lobby."
 )]
 #[axum::debug_handler]
 pub async fn create_match_handler(
     Extension(user_id): Extension<String>,
     State(redis_pool): State<RedisPool>,
     headers: HeaderMap,
+    payload: Option<Json<CreateMatchRequest>>,
 ) -> Response {
+    let allow_takebacks = payload
+        .as_ref()
+        .and_then(|Json(request)| request.allow_takebacks)
+        .unwrap_or(true);
+    let allow_hints = payload
+        .as_ref()
+        .and_then(|Json(request)| request.allow_hints)
+        .unwrap_or(false);
+    let is_public = payload
+        .as_ref()
+        .and_then(|Json(request)| request.public)
+        .unwrap_or(false);
+    let rules = match payload.map(|Json(request)| rules_from_request(request)) {
+        None => RuleSet::standard(),
+        Some(Ok(rules)) => rules,
+        Some(Err(e)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid rules".to_string(),
+                    message: Some(e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
     let mut conn = match redis_pool.get().await {
         Ok(conn) => conn,
         Err(e) => {
@@ -108,11 +147,15 @@ pub async fn create_match_handler(
     let pin_code = rand::thread_rng().gen_range(1000..=9999);
 
     // Create a new NormalMatch instance
-    let normal_match = NormalMatch::new(
+    let mut normal_match = NormalMatch::new(
         game_id.clone(),
         pin_code,
         3, // Default number of crosses - adjust as needed
+        rules,
     );
+    normal_match.allow_takebacks = allow_takebacks;
+    normal_match.allow_hints = allow_hints;
+    normal_match.is_public = is_public;
 
     // Use repository to create the match in Redis
     if let Err(e) = NormalMatchRepository::create(&mut conn, &normal_match, &user_id).await {
@@ -126,6 +169,11 @@ pub async fn create_match_handler(
             .into_response();
     }
 
+    // List public matches in the lobby
+    if let Err(e) = LobbyRepository::refresh(&mut conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
     // Verify the match was created successfully
     match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
         Ok(Some(stored_match)) => {
@@ -145,6 +193,12 @@ pub async fn create_match_handler(
                             current_cross: stored_match.current_cross,
                             created_timestamp: stored_match.created_timestamp,
                             host: user_id,
+                            rules: rule_set_info(&stored_match.rules),
+                            allow_takebacks: stored_match.allow_takebacks,
+                            allow_hints: stored_match.allow_hints,
+                            is_public: stored_match.is_public,
+                            is_locked: stored_match.is_locked,
+                            auto_start: stored_match.auto_start,
                         },
                     };
 
@@ -187,3 +241,250 @@ pub async fn create_match_handler(
         }
     }
 }
+
+/// Change match settings
+///
+/// Lets the host change settings that may differ between tables, such as
+/// whether takebacks and hints are allowed. Turning takebacks off cancels a
+/// pending vote, and turning auto-start off cancels a running countdown.
+#[utoipa::path(
+    post,
+    path = "/normal-match/settings",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = MatchSettingsRequest,
+    responses(
+        (status = 200, description = "Settings updated", body = MatchSettingsResponse),
+        (status = 400, description = "Not in a game", body = ErrorResponse),
+        (status = 403, description = "Only the host can change settings", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Change match settings",
+    description = "Host-only. Updates the settings given in the request and leaves the rest unchanged. Settings can be changed at any point in the match. With `auto_start` on, the game starts by itself a few seconds after all four players are ready."
+)]
+#[axum::debug_handler]
+pub async fn update_match_settings_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<MatchSettingsRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get player's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must host a match to change its settings".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Only the host may change settings
+    let players = match PlayerRepository::get_players_in_game(&mut conn, &game_id).await {
+        Ok(players) => players,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get players: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if !players
+        .iter()
+        .any(|p| p.user_id == user_id && p.role == "host")
+    {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: "Only the host can change match settings".to_string(),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    // 3. Apply the settings that were given
+    let mut game_match = match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) => game_match,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if let Some(allow_takebacks) = payload.allow_takebacks {
+        game_match.allow_takebacks = allow_takebacks;
+    }
+    if let Some(allow_hints) = payload.allow_hints {
+        game_match.allow_hints = allow_hints;
+    }
+    if let Some(auto_start) = payload.auto_start {
+        game_match.auto_start = auto_start;
+    }
+
+    if let Err(e) = NormalMatchRepository::update(&mut conn, &game_match).await {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("Failed to update match: {}", e),
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    // 4. A vote that can no longer pass is cancelled
+    if !game_match.allow_takebacks {
+        if let Ok(Some(request)) = TakebackRepository::get_request(&mut conn, &game_id).await {
+            if let Err(e) = TakebackRepository::clear_request(&mut conn, &game_id).await {
+                eprintln!("Failed to clear takeback request: {}", e);
+            } else if let Err(e) =
+                broadcasting::broadcast_takeback_closed(&mut conn, &game_id, &request, "cancelled")
+                    .await
+            {
+                eprintln!("Failed to broadcast takeback cancellation: {}", e);
+            }
+        }
+    }
+
+    // 5. Count down straight away if everyone is already ready
+    let auto_start = if game_match.auto_start {
+        game_start::check_auto_start(&redis_pool, &mut conn, &game_id).await
+    } else {
+        game_start::cancel_auto_start(&mut conn, &game_id, "The host turned auto-start off").await
+    };
+    if let Err(e) = auto_start {
+        eprintln!("Failed to update auto-start: {}", e);
+    }
+
+    if let Err(e) = broadcasting::broadcast_game_state_update(&mut conn, &game_id).await {
+        eprintln!("Failed to broadcast settings update: {}", e);
+    }
+
+    let response = MatchSettingsResponse {
+        message: "Match settings updated".to_string(),
+        game_id,
+        allow_takebacks: game_match.allow_takebacks,
+        allow_hints: game_match.allow_hints,
+        auto_start: game_match.auto_start,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Pick the rules a new match asked for: custom rules win over a preset
+fn rules_from_request(request: CreateMatchRequest) -> Result<RuleSet, String> {
+    let rules = match (request.rules, request.rule_preset) {
+        (Some(custom), _) => RuleSet {
+            name: "custom".to_string(),
+            min_bid: custom.min_bid,
+            high_win_points: custom.high_win_points,
+            win_points: custom.win_points,
+            avoid_double_points: custom.avoid_double_points,
+            scores: score_values(custom.scores),
+            clubs_scores: score_values(custom.clubs_scores),
+            opponent_vol: custom.opponent_vol,
+            tie_bonus: custom.tie_bonus,
+            cross_start: custom.cross_start,
+        },
+        (None, Some(preset)) => RuleSet::preset(&preset).ok_or_else(|| {
+            format!(
+                "Unknown rule preset '{}'. Choose one of: {}",
+                preset,
+                PRESETS.join(", ")
+            )
+        })?,
+        (None, None) => RuleSet::standard(),
+    };
+
+    rules.validate()?;
+    Ok(rules)
+}
+
+fn score_values(info: ScoreValuesInfo) -> ScoreValues {
+    ScoreValues {
+        high_win: info.high_win,
+        win: info.win,
+        loss: info.loss,
+        double_loss: info.double_loss,
+        vol: info.vol,
+        individual_vol: info.individual_vol,
+    }
+}
+
+/// Rule set in API format
+pub fn rule_set_info(rules: &RuleSet) -> RuleSetInfo {
+    let score_info = |values: &ScoreValues| ScoreValuesInfo {
+        high_win: values.high_win,
+        win: values.win,
+        loss: values.loss,
+        double_loss: values.double_loss,
+        vol: values.vol,
+        individual_vol: values.individual_vol,
+    };
+
+    RuleSetInfo {
+        name: rules.name.clone(),
+        min_bid: rules.min_bid,
+        high_win_points: rules.high_win_points,
+        win_points: rules.win_points,
+        avoid_double_points: rules.avoid_double_points,
+        scores: score_info(&rules.scores),
+        clubs_scores: score_info(&rules.clubs_scores),
+        opponent_vol: rules.opponent_vol,
+        tie_bonus: rules.tie_bonus,
+        cross_start: rules.cross_start,
+    }
+}
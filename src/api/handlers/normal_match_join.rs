@@ -1,5 +1,8 @@
+use crate::api::handlers::normal_match;
 use crate::api::schemas::{JoinMatchRequest, JoinMatchResponse, ErrorResponse};
 use crate::auth::extract_username_from_jwt_token;
+use crate::redis::invite::{InviteRepository, InviteToken};
+use crate::redis::lobby::LobbyRepository;
 use crate::redis::normal_match::id::NormalMatchStatus;
 use crate::redis::normal_match::repository::NormalMatchRepository;
 use crate::redis::notification::repository::NotificationRepository;
@@ -16,12 +19,16 @@ use serde_json::json;
 
 #[derive(Deserialize)]
 pub struct JoinRequest {
-    pub pin_code: String,
+    #[serde(default)]
+    pub pin_code: Option<String>,
+    #[serde(default)]
+    pub invite_token: Option<String>,
 }
 
-/// Join an existing match using a PIN code
+/// Join an existing match using a PIN code or invite
 /// 
-/// Join an existing Sjavs match using a 4-digit PIN code. The authenticated user
+/// Join an existing Sjavs match using a 4-digit PIN code or an invite token from
+/// the host. Invites work for private matches too. The authenticated user
 /// must not already be in an active game.
 #[utoipa::path(
     post,
@@ -36,7 +43,12 @@ pub struct JoinRequest {
         ),
         (
             status = 400, 
-            description = "Invalid PIN code",
+            description = "Invalid PIN code or invite token",
+            body = ErrorResponse
+        ),
+        (
+            status = 403, 
+            description = "Room locked, or kicked from this match recently",
             body = ErrorResponse
         ),
         (
@@ -44,6 +56,11 @@ pub struct JoinRequest {
             description = "Player already in an active game or game not joinable",
             body = ErrorResponse
         ),
+        (
+            status = 410, 
+            description = "Invite expired, revoked or used up",
+            body = ErrorResponse
+        ),
         (
             status = 500, 
             description = "Internal server error",
@@ -115,8 +132,59 @@ pub async fn join_match_handler(
         _ => {} // Continue if player is not in a game
     }
 
-    // Look up game ID by PIN code using repository
-    let game_id = match NormalMatchRepository::get_id_by_pin(&mut conn, &payload.pin_code).await {
+    // Check the invite's signature and expiry; its use is only counted once the join goes through
+    let invite = match &payload.invite_token {
+        Some(token) => {
+            let secret = match InviteRepository::signing_key(&mut conn).await {
+                Ok(secret) => secret,
+                Err(e) => {
+                    return (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({"error": e})))
+                        .into_response();
+                }
+            };
+            match InviteToken::verify(token, &secret) {
+                Ok(invite) if invite.is_expired(chrono::Utc::now().timestamp() as u64) => {
+                    return (
+                        StatusCode::GONE,
+                        Json(json!({
+                            "error": "Invite expired",
+                            "message": "Ask the host for a new invite."
+                        })),
+                    )
+                        .into_response();
+                }
+                Ok(invite) => Some(invite),
+                Err(e) => {
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        Json(json!({
+                            "error": "Invalid invite",
+                            "message": e
+                        })),
+                    )
+                        .into_response();
+                }
+            }
+        }
+        None => None,
+    };
+
+    // Look up game ID by invite or PIN code using repository
+    let pin_lookup = match (&invite, &payload.pin_code) {
+        (Some(invite), _) => Ok(Some(invite.game_id.clone())),
+        (None, Some(pin_code)) => NormalMatchRepository::get_id_by_pin(&mut conn, pin_code).await,
+        (None, None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "error": "Missing pin",
+                    "message": "Provide a pin_code or an invite_token."
+                })),
+            )
+                .into_response();
+        }
+    };
+    let game_id = match pin_lookup {
         Ok(Some(id)) => id,
         Ok(None) => {
             return (
@@ -175,9 +243,65 @@ pub async fn join_match_handler(
             .into_response();
     }
 
+    // The host may have locked the room or kicked this player
+    if game_match.is_locked {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "error": "Room locked",
+                "message": "The host has locked the room to new players."
+            })),
+        )
+            .into_response();
+    }
+
+    match NormalMatchRepository::get_kick_cooldown(&mut conn, &game_id, &user_id).await {
+        Ok(None) => {}
+        Ok(Some(seconds)) => {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(json!({
+                    "error": "Kicked from this match",
+                    "message": format!("You can rejoin in {} seconds.", seconds)
+                })),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({"error": e}))).into_response();
+        }
+    }
+
+    // Use up one of the invite's joins, now that every check has passed
+    if let Some(invite) = &invite {
+        match InviteRepository::redeem(&mut conn, invite).await {
+            Ok(true) => {}
+            Ok(false) => {
+                return (
+                    StatusCode::GONE,
+                    Json(json!({
+                        "error": "Invite no longer valid",
+                        "message": "The invite was revoked or has been used up."
+                    })),
+                )
+                    .into_response();
+            }
+            Err(e) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({"error": e})))
+                    .into_response();
+            }
+        }
+    }
+
     // Add player to the game using repository
     if let Err(e) = NormalMatchRepository::add_player(&mut conn, &game_id, &user_id, "player").await
     {
+        // The join failed, so it shouldn't count against the invite
+        if let Some(invite) = &invite {
+            if let Err(e) = InviteRepository::release(&mut conn, invite).await {
+                eprintln!("Failed to give back invite use for {}: {}", game_id, e);
+            }
+        }
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({
@@ -187,6 +311,11 @@ pub async fn join_match_handler(
             .into_response();
     }
 
+    // Update the seat count shown in the lobby
+    if let Err(e) = LobbyRepository::refresh(&mut conn, &game_id).await {
+        eprintln!("Failed to update lobby for {}: {}", game_id, e);
+    }
+
     // Get the redis key and players key
     let redis_key = format!("normal_match:{}", game_id);
     let players_key = format!("{}:players", redis_key);
@@ -243,7 +372,8 @@ pub async fn join_match_handler(
                 "number_of_crosses": game_match.number_of_crosses,
                 "current_cross": game_match.current_cross,
                 "created_timestamp": game_match.created_timestamp,
-                "host": host_id.unwrap_or_default()
+                "host": host_id.unwrap_or_default(),
+                "rules": normal_match::rule_set_info(&game_match.rules)
             }
         })),
     )
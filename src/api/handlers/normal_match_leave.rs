@@ -1,4 +1,6 @@
+use crate::api::handlers::game_start;
 use crate::api::schemas::{LeaveMatchResponse, ErrorResponse};
+use crate::redis::lobby::LobbyRepository;
 use crate::redis::normal_match::repository::NormalMatchRepository;
 use crate::redis::notification::repository::NotificationRepository;
 use crate::redis::player::repository::PlayerRepository;
@@ -15,7 +17,8 @@ use std::collections::HashMap;
 /// Leave the current match
 /// 
 /// Leave the match the authenticated user is currently in. If the user is the host,
-/// the entire game will be terminated and all other players will be notified.
+/// the next human player in seat order takes over; when no human is left the entire
+/// game is terminated and all other players are notified.
 #[utoipa::path(
     delete,
     path = "/normal-match/leave",
@@ -71,15 +74,45 @@ pub async fn leave_match_handler(
     if let Some(game_id) = player_game {
         // Try to remove player using the repository (handles new format)
         match NormalMatchRepository::remove_player(&mut conn, &game_id, &user_id).await {
-            Ok((game_deleted, affected_players)) => {
-                // If host left (game deleted) and there were other players,
+            Ok(removal) => {
+                let game_deleted = removal.game_deleted;
+                let affected_players = removal.affected_players;
+
+                // Free the seat in the lobby, or drop the match if it was deleted
+                if let Err(e) = LobbyRepository::refresh(&mut conn, &game_id).await {
+                    eprintln!("Failed to update lobby for {}: {}", game_id, e);
+                }
+
+                // A player short, the table can no longer start by itself
+                if let Err(e) =
+                    game_start::cancel_auto_start(&mut conn, &game_id, "A player left").await
+                {
+                    eprintln!("Failed to cancel auto-start: {}", e);
+                }
+
+                // If host left and someone took over, tell the table who hosts now
+                if let Some(new_host) = &removal.new_host {
+                    if let Err(e) = NotificationRepository::publish_host_changed(
+                        &mut conn,
+                        &game_id,
+                        new_host,
+                        affected_players.clone(),
+                        "Host left",
+                    )
+                    .await
+                    {
+                        eprintln!("Failed to publish host change event: {}", e);
+                    }
+                }
+
+                // If nobody could take over as host (game deleted) and there were other players,
                 // publish a message to Redis for WebSocket handlers to pick up
                 if game_deleted && affected_players.len() > 1 {
                     if let Err(e) = NotificationRepository::publish_game_terminated(
                         &mut conn,
                         &game_id,
                         affected_players.clone(),
-                        "Game terminated because host left",
+                        "Game terminated because no human was left to host it",
                     )
                     .await
                     {
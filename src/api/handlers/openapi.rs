@@ -47,8 +47,20 @@ impl Modify for SecurityAddon {
     paths(
         // Match management endpoints
         crate::api::handlers::normal_match::create_match_handler,
+        crate::api::handlers::normal_match::update_match_settings_handler,
         crate::api::handlers::normal_match_join::join_match_handler,
         crate::api::handlers::normal_match_leave::leave_match_handler,
+        crate::api::handlers::bots::add_bot_handler,
+        crate::api::handlers::moderation::kick_player_handler,
+        crate::api::handlers::moderation::transfer_host_handler,
+        crate::api::handlers::moderation::lock_room_handler,
+        crate::api::handlers::seating::get_seating_handler,
+        crate::api::handlers::seating::change_seat_handler,
+        crate::api::handlers::invites::create_invite_handler,
+        crate::api::handlers::invites::list_invites_handler,
+        crate::api::handlers::invites::revoke_invite_handler,
+        crate::api::handlers::lobby::get_lobby_handler,
+        crate::api::handlers::player_settings::update_player_settings_handler,
         // Game management endpoints
         crate::api::handlers::game_start::start_game_handler,
         crate::api::handlers::game_start::get_player_hand_handler,
@@ -56,7 +68,16 @@ impl Modify for SecurityAddon {
         crate::api::handlers::game_bidding::pass_bid_handler,
         crate::api::handlers::game_playing::play_card_handler,
         crate::api::handlers::game_playing::get_trick_info_handler,
+        crate::api::handlers::game_claim::claim_tricks_handler,
+        crate::api::handlers::game_hint::get_hint_handler,
         crate::api::handlers::game_scoring::get_current_score_handler,
+        crate::api::handlers::game_fairness::verify_deal_handler,
+        crate::api::handlers::game_analysis::get_deal_analysis_handler,
+        crate::api::handlers::game_replay::get_replay_handler,
+        crate::api::handlers::game_notation::export_game_record_handler,
+        crate::api::handlers::game_notation::import_game_record_handler,
+        // Admin endpoints
+        crate::api::handlers::admin::get_game_deals_handler,
         // Debug endpoints
         crate::api::handlers::debug::flush_redis_handler,
         // Documentation endpoints
@@ -66,11 +87,34 @@ impl Modify for SecurityAddon {
         // Match management schemas
         CreateMatchResponse,
         MatchState,
+        CreateMatchRequest,
+        MatchSettingsRequest,
+        MatchSettingsResponse,
+        LobbyResponse,
+        LobbyMatch,
+        PlayerSettingsRequest,
+        PlayerSettingsResponse,
+        RuleSetInfo,
+        ScoreValuesInfo,
         JoinMatchRequest,
         JoinMatchResponse,
+        TargetPlayerRequest,
+        LockRoomRequest,
+        ModerationResponse,
+        SeatChangeRequest,
+        SeatInfo,
+        SeatingResponse,
+        CreateInviteRequest,
+        CreateInviteResponse,
+        InviteInfo,
+        InviteListResponse,
+        RevokeInviteResponse,
         PlayerInfo,
         LeaveMatchResponse,
+        AddBotRequest,
+        AddBotResponse,
         ErrorResponse,
+        RuleViolationResponse,
         DebugResponse,
         // Game management schemas
         StartGameResponse,
@@ -83,16 +127,36 @@ impl Modify for SecurityAddon {
         PassResponse,
         BiddingCompleteResponse,
         BidOption,
+        BiddingAdviceInfo,
+        SuitEvaluationInfo,
         // Card playing schemas
                     CardPlayRequest,
             CardPlayResponse,
             GameTrickInfo,
             TrickSummaryResponse,
+            ClaimRequest,
+            ClaimPlay,
+            ClaimResponse,
+            HintResponse,
+            CardHint,
             GameCompleteResponse,
             GameScoringResult,
             CrossScores,
             CrossWinner,
             GameScoreInfo,
+            DealVerificationResponse,
+            GameAnalysisResponse,
+            BidReview,
+            CardMistake,
+            ReplayQuery,
+            ReplaySnapshot,
+            ReplayResponse,
+            GameRecordResponse,
+            ImportGameRecordRequest,
+            ImportedGameRecordResponse,
+        // Admin schemas
+        DealInfo,
+        GameDealsResponse,
         // WebSocket schemas
         GameMessage,
         JoinEventData,
@@ -104,6 +168,8 @@ impl Modify for SecurityAddon {
         (name = "Match Management", description = "Endpoints for creating, joining, and leaving matches"),
         (name = "Game Management", description = "Endpoints for starting games, dealing cards, and managing bidding"),
         (name = "Game Playing", description = "Endpoints for playing cards and trick-taking"),
+        (name = "Player", description = "Endpoints for personal settings"),
+        (name = "Admin", description = "Administrative tools for inspecting games"),
         (name = "Debug", description = "Debug utilities for development"),
         (name = "Documentation", description = "API documentation endpoints")
     ),
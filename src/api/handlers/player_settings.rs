@@ -0,0 +1,116 @@
+use crate::api::schemas::{ErrorResponse, PlayerSettingsRequest, PlayerSettingsResponse};
+use crate::redis::player::repository::PlayerRepository;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+/// Change personal settings
+///
+/// Settings apply to the player's current match and are dropped with it. With
+/// hints enabled, `GET /game/hand` includes bidding advice.
+#[utoipa::path(
+    post,
+    path = "/player/settings",
+    tag = "Player",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = PlayerSettingsRequest,
+    responses(
+        (status = 200, description = "Settings updated", body = PlayerSettingsResponse),
+        (status = 400, description = "Not in a game", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Change personal settings",
+    description = "Updates your settings for the match you are in with the values given in the request and leaves the rest unchanged. Returns the settings now in effect."
+)]
+#[axum::debug_handler]
+pub async fn update_player_settings_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<PlayerSettingsRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get player's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must be in a game to change your settings".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Apply the settings that were given
+    if let Some(enabled) = payload.hints_enabled {
+        if let Err(e) =
+            PlayerRepository::set_hints_enabled(&mut conn, &game_id, &user_id, enabled).await
+        {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    // 3. Return the settings now in effect
+    let hints_enabled =
+        match PlayerRepository::get_hints_enabled(&mut conn, &game_id, &user_id).await {
+            Ok(enabled) => enabled,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e,
+                        message: None,
+                    }),
+                )
+                    .into_response();
+            }
+        };
+
+    (
+        StatusCode::OK,
+        Json(PlayerSettingsResponse {
+            message: "Settings updated".to_string(),
+            hints_enabled,
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,73 @@
+use crate::api::schemas::{BidOption, RuleViolationResponse};
+use crate::game::engine::{GamePhase, SjavsGame};
+use crate::game::violation::RuleViolation;
+use crate::redis::pubsub::broadcasting;
+use axum::http::StatusCode;
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use deadpool_redis::Connection;
+
+/// Reject an illegal action, showing the player what they may do instead
+///
+/// The same explanation goes to the player's websocket as a `rule_violation`
+/// event, so clients that act over REST and listen over websocket both get it.
+pub async fn reject_action(
+    conn: &mut Connection,
+    game_id: &str,
+    user_id: &str,
+    game: &SjavsGame,
+    player: usize,
+    violation: RuleViolation,
+) -> Response {
+    let status = match violation {
+        RuleViolation::NotYourTurn { .. } => StatusCode::CONFLICT,
+        RuleViolation::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        _ => StatusCode::BAD_REQUEST,
+    };
+
+    let response = explain(game, player, &violation);
+
+    match serde_json::to_value(&response) {
+        Ok(data) => {
+            if let Err(e) =
+                broadcasting::broadcast_rule_violation(conn, game_id, user_id, &data).await
+            {
+                eprintln!("Failed to send rule violation: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Failed to serialize rule violation: {}", e),
+    }
+
+    (status, Json(response)).into_response()
+}
+
+/// Describe a broken rule along with the player's legal alternatives
+pub fn explain(
+    game: &SjavsGame,
+    player: usize,
+    violation: &RuleViolation,
+) -> RuleViolationResponse {
+    RuleViolationResponse {
+        error: violation.code(),
+        message: violation.to_string(),
+        violation: serde_json::to_value(violation).unwrap_or_default(),
+        legal_cards: game
+            .legal_cards(player)
+            .iter()
+            .map(|card| card.code())
+            .collect(),
+        legal_bids: game
+            .available_bids(player)
+            .into_iter()
+            .map(|bid| BidOption {
+                length: bid.length,
+                suit: bid.suit,
+                display_text: bid.display_text,
+                is_club_declaration: bid.is_club_declaration,
+            })
+            .collect(),
+        can_pass: game.phase == GamePhase::Bidding && game.current_bidder == Some(player),
+    }
+}
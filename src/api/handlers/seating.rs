@@ -0,0 +1,282 @@
+use crate::api::schemas::{ErrorResponse, SeatChangeRequest, SeatInfo, SeatingResponse};
+use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::normal_match::seating::Seating;
+use crate::redis::player::repository::PlayerRepository;
+use crate::redis::pubsub::broadcasting;
+use crate::RedisPool;
+use axum::http::StatusCode;
+use axum::{
+    extract::{Extension, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use deadpool_redis::Connection;
+
+/// See who sits where
+#[utoipa::path(
+    get,
+    path = "/normal-match/seats",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Current seating", body = SeatingResponse),
+        (status = 400, description = "Not in a game", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Get seating",
+    description = "Returns the four seats of the player's match and who sits in each. Seat numbers are the positions players take when the game starts."
+)]
+#[axum::debug_handler]
+pub async fn get_seating_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    match NormalMatchRepository::get_seating(&mut conn, &game_id).await {
+        Ok(seating) => seating_response(&mut conn, "Current seating", game_id, &seating).await,
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e,
+                message: None,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+/// Sit, swap seats or choose a partner before the game starts
+///
+/// Seats become positions when the host starts the game, so partners chosen
+/// here sit opposite each other and play together.
+#[utoipa::path(
+    post,
+    path = "/normal-match/seats",
+    tag = "Match Management",
+    security(
+        ("jwt_auth" = [])
+    ),
+    request_body = SeatChangeRequest,
+    responses(
+        (status = 200, description = "Seats changed", body = SeatingResponse),
+        (status = 400, description = "Not in a waiting game, unknown action or seat not available", body = ErrorResponse),
+        (status = 404, description = "Game not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    summary = "Change seats",
+    description = "Only while the match is waiting for players. \"sit\" moves you to an empty seat, \"swap\" trades seats with whoever sits in `seat`, and \"partner\" moves `player_id` opposite you, trading places with whoever sat there. Everyone in the match gets a `seating_updated` event."
+)]
+#[axum::debug_handler]
+pub async fn change_seat_handler(
+    Extension(user_id): Extension<String>,
+    State(redis_pool): State<RedisPool>,
+    Json(payload): Json<SeatChangeRequest>,
+) -> Response {
+    let mut conn = match redis_pool.get().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get Redis connection from pool: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 1. Get player's current game
+    let game_id = match PlayerRepository::get_player_game(&mut conn, &user_id).await {
+        Ok(Some(game_id)) => game_id,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Not in a game".to_string(),
+                    message: Some("You must be in a match to change seats".to_string()),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get player game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // 2. Seats are fixed once the game starts
+    match NormalMatchRepository::get_by_id(&mut conn, &game_id).await {
+        Ok(Some(game_match)) if game_match.status == NormalMatchStatus::Waiting => {}
+        Ok(Some(game_match)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Game already started".to_string(),
+                    message: Some(format!(
+                        "Seats can only change while waiting, but the game is {}",
+                        game_match.status.to_string()
+                    )),
+                }),
+            )
+                .into_response();
+        }
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Game not found".to_string(),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to get game: {}", e),
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    // 3. Apply the change
+    let mut seating = match NormalMatchRepository::get_seating(&mut conn, &game_id).await {
+        Ok(seating) => seating,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e,
+                    message: None,
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let seat = payload.seat.map(usize::from);
+    let changed = match (payload.action.as_str(), seat, payload.player_id.as_deref()) {
+        ("sit", Some(seat), _) => seating.sit(&user_id, seat),
+        ("swap", Some(seat), _) => seating.swap(&user_id, seat),
+        ("partner", _, Some(partner_id)) => seating.choose_partner(&user_id, partner_id),
+        ("sit" | "swap", None, _) => Err("A seat is required".to_string()),
+        ("partner", _, None) => Err("A player_id is required".to_string()),
+        (action, _, _) => Err(format!(
+            "Unknown action '{}'; use sit, swap or partner",
+            action
+        )),
+    };
+    if let Err(e) = changed {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Cannot change seats".to_string(),
+                message: Some(e),
+            }),
+        )
+            .into_response();
+    }
+
+    if let Err(e) = NormalMatchRepository::save_seating(&mut conn, &game_id, &seating).await {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e,
+                message: None,
+            }),
+        )
+            .into_response();
+    }
+
+    // 4. Tell the table
+    if let Err(e) = broadcasting::broadcast_seating_updated(&mut conn, &game_id, &seating).await {
+        eprintln!("Failed to broadcast seating update: {}", e);
+    }
+
+    seating_response(&mut conn, "Seats changed", game_id, &seating).await
+}
+
+async fn seating_response(
+    conn: &mut Connection,
+    message: &str,
+    game_id: String,
+    seating: &Seating,
+) -> Response {
+    let mut seats = Vec::new();
+    for (seat, user_id) in seating.seats().iter().enumerate() {
+        let username = match user_id {
+            Some(user_id) => redis::cmd("HGET")
+                .arg("usernames")
+                .arg(user_id)
+                .query_async::<_, Option<String>>(&mut **conn)
+                .await
+                .unwrap_or(None),
+            None => None,
+        };
+        seats.push(SeatInfo {
+            seat: seat as u8,
+            user_id: user_id.clone(),
+            username,
+        });
+    }
+
+    (
+        StatusCode::OK,
+        Json(SeatingResponse {
+            message: message.to_string(),
+            game_id,
+            seats,
+        }),
+    )
+        .into_response()
+}
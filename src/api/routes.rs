@@ -1,10 +1,11 @@
 use crate::api::handlers::{
-    debug, game_bidding, game_playing, game_scoring, game_start, normal_match, normal_match_join,
-    normal_match_leave, openapi,
+    admin, bots, debug, game_analysis, game_bidding, game_claim, game_fairness, game_hint,
+    game_notation, game_playing, game_replay, game_scoring, game_start, invites, lobby, moderation,
+    normal_match, normal_match_join, normal_match_leave, openapi, player_settings, seating,
 };
 use crate::RedisPool;
 use axum::{
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
 };
 
@@ -21,6 +22,10 @@ pub fn create_protected_router(redis_pool: RedisPool) -> Router {
     Router::new()
         // Match management endpoints
         .route("/normal-match", post(normal_match::create_match_handler))
+        .route(
+            "/normal-match/settings",
+            post(normal_match::update_match_settings_handler),
+        )
         .route(
             "/normal-match/join",
             post(normal_match_join::join_match_handler),
@@ -29,6 +34,31 @@ pub fn create_protected_router(redis_pool: RedisPool) -> Router {
             "/normal-match/leave",
             post(normal_match_leave::leave_match_handler),
         )
+        .route("/normal-match/bots", post(bots::add_bot_handler))
+        .route("/normal-match/kick", post(moderation::kick_player_handler))
+        .route(
+            "/normal-match/host",
+            post(moderation::transfer_host_handler),
+        )
+        .route("/normal-match/lock", post(moderation::lock_room_handler))
+        .route(
+            "/normal-match/seats",
+            post(seating::change_seat_handler).get(seating::get_seating_handler),
+        )
+        .route(
+            "/normal-match/invites",
+            post(invites::create_invite_handler).get(invites::list_invites_handler),
+        )
+        .route(
+            "/normal-match/invites/:invite_id",
+            delete(invites::revoke_invite_handler),
+        )
+        .route("/lobby", get(lobby::get_lobby_handler))
+        // Player endpoints
+        .route(
+            "/player/settings",
+            post(player_settings::update_player_settings_handler),
+        )
         // Game management endpoints
         .route("/game/start", post(game_start::start_game_handler))
         .route("/game/hand", get(game_start::get_player_hand_handler))
@@ -36,7 +66,34 @@ pub fn create_protected_router(redis_pool: RedisPool) -> Router {
         .route("/game/pass", post(game_bidding::pass_bid_handler))
         .route("/game/play-card", post(game_playing::play_card_handler))
         .route("/game/trick", get(game_playing::get_trick_info_handler))
+        .route("/game/claim", post(game_claim::claim_tricks_handler))
+        .route("/game/hint", get(game_hint::get_hint_handler))
         .route("/game/score", get(game_scoring::get_current_score_handler))
+        .route(
+            "/games/:game_id/deals/:deal_number/verify",
+            get(game_fairness::verify_deal_handler),
+        )
+        .route(
+            "/games/:game_id/deals/:deal_number/analysis",
+            get(game_analysis::get_deal_analysis_handler),
+        )
+        .route(
+            "/games/:game_id/replay",
+            get(game_replay::get_replay_handler),
+        )
+        .route(
+            "/games/:game_id/deals/:deal_number/record",
+            get(game_notation::export_game_record_handler),
+        )
+        .route(
+            "/games/records/import",
+            post(game_notation::import_game_record_handler),
+        )
+        // Admin endpoints
+        .route(
+            "/admin/games/:game_id/deals",
+            get(admin::get_game_deals_handler),
+        )
         // Debug endpoints
         .route("/debug/flush", post(debug::flush_redis_handler))
         .with_state(redis_pool)
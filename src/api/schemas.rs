@@ -31,13 +31,244 @@ pub struct MatchState {
     pub created_timestamp: u64,
     /// Host player ID
     pub host: String,
+    /// House rules the match is played with
+    pub rules: RuleSetInfo,
+    /// Whether players may ask to take back their last card
+    pub allow_takebacks: bool,
+    /// Whether players may ask for hints and bidding advice
+    pub allow_hints: bool,
+    /// Whether the match is listed in the public lobby
+    pub is_public: bool,
+    /// Whether the host has closed the room to new players
+    pub is_locked: bool,
+    /// Whether the game starts by itself once everyone is ready
+    pub auto_start: bool,
+}
+
+/// Request to create a match
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateMatchRequest {
+    /// Rule preset: "standard" (default), "quick" or "no_club_bonus"
+    pub rule_preset: Option<String>,
+    /// Custom house rules, used instead of the preset when given
+    pub rules: Option<RuleSetInfo>,
+    /// Let players take back their last card with everyone's consent (default true)
+    pub allow_takebacks: Option<bool>,
+    /// Let players ask for hints and bidding advice (default false)
+    pub allow_hints: Option<bool>,
+    /// List the match in the public lobby (default false)
+    pub public: Option<bool>,
+}
+
+/// Match settings the host can change; omitted fields are left as they are
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MatchSettingsRequest {
+    /// Let players take back their last card with everyone's consent
+    pub allow_takebacks: Option<bool>,
+    /// Let players ask for hints and bidding advice
+    pub allow_hints: Option<bool>,
+    /// Start the game by itself once all four players are ready
+    pub auto_start: Option<bool>,
+}
+
+/// Match settings after an update
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct MatchSettingsResponse {
+    pub message: String,
+    pub game_id: String,
+    /// Whether players may ask to take back their last card
+    pub allow_takebacks: bool,
+    /// Whether players may ask for hints and bidding advice
+    pub allow_hints: bool,
+    /// Whether the game starts by itself once everyone is ready
+    pub auto_start: bool,
+}
+
+/// Public matches waiting for players
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LobbyResponse {
+    /// Oldest first
+    pub matches: Vec<LobbyMatch>,
+}
+
+/// A public match as listed in the lobby
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LobbyMatch {
+    pub game_id: String,
+    /// PIN to join the match with
+    pub pin: u32,
+    pub host_id: String,
+    pub host_name: String,
+    /// Seats taken, bots included
+    pub seats_taken: u8,
+    /// Seats at the table
+    pub seats: u8,
+    /// House rules the match is played with
+    pub rules: RuleSetInfo,
+    /// Number of crosses (games) in this rubber match
+    pub number_of_crosses: u32,
+    /// Timestamp when the match was created
+    pub created_timestamp: u64,
+}
+
+/// Personal settings; omitted fields are left as they are
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PlayerSettingsRequest {
+    /// Show bidding advice with your hand
+    pub hints_enabled: Option<bool>,
+}
+
+/// Personal settings after an update
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PlayerSettingsResponse {
+    pub message: String,
+    /// Whether bidding advice is shown with your hand
+    pub hints_enabled: bool,
+}
+
+/// Cross points awarded for each kind of game result
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ScoreValuesInfo {
+    /// Trump team reached the high win threshold
+    pub high_win: u8,
+    /// Trump team reached the win threshold
+    pub win: u8,
+    /// Trump team lost but avoided the double loss
+    pub loss: u8,
+    /// Trump team lost below the double loss threshold
+    pub double_loss: u8,
+    /// Trump team won all tricks
+    pub vol: u8,
+    /// A single trump team player won all tricks
+    pub individual_vol: u8,
+}
+
+/// House rules for bidding, scoring and the cross
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RuleSetInfo {
+    /// Preset the rules came from, or "custom"
+    pub name: String,
+    /// Fewest trumps a player may bid (4-8)
+    pub min_bid: u8,
+    /// Card points for the trump team's high win (standard 90)
+    pub high_win_points: u8,
+    /// Card points the trump team needs to win (standard 61)
+    pub win_points: u8,
+    /// Card points the trump team needs to avoid the double loss (standard 31)
+    pub avoid_double_points: u8,
+    /// Scores when any suit but clubs is trump
+    pub scores: ScoreValuesInfo,
+    /// Scores when clubs is trump
+    pub clubs_scores: ScoreValuesInfo,
+    /// Score for the opponents winning all tricks
+    pub opponent_vol: u8,
+    /// Extra points the next game is worth after a 60-60 tie (standard 2)
+    pub tie_bonus: u8,
+    /// Points each team starts a cross with (standard 24)
+    pub cross_start: u8,
 }
 
 /// Request to join a match
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct JoinMatchRequest {
     /// 4-digit PIN code of the match to join
-    pub pin: u32,
+    pub pin: Option<u32>,
+    /// Invite token from the host, used instead of the PIN
+    pub invite_token: Option<String>,
+}
+
+/// Request to invite players to the match you host
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateInviteRequest {
+    /// Minutes until the invite lapses (default 1440, at most 10080)
+    pub expires_in_minutes: Option<u32>,
+    /// Players who may join with it (default 3)
+    pub max_uses: Option<u32>,
+}
+
+/// An invite to a match
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct InviteInfo {
+    pub invite_id: String,
+    pub game_id: String,
+    pub max_uses: u32,
+    /// Players who have joined with it so far
+    pub uses: u32,
+    /// Unix time (seconds) when the invite lapses
+    pub expires_at: u64,
+}
+
+/// A new invite and the token to share
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateInviteResponse {
+    pub message: String,
+    pub invite: InviteInfo,
+    /// Signed token to send to the players you invite; shown only once
+    pub token: String,
+}
+
+/// Live invites to the match you host
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct InviteListResponse {
+    pub game_id: String,
+    /// Soonest to expire first
+    pub invites: Vec<InviteInfo>,
+}
+
+/// Confirmation that an invite was revoked
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RevokeInviteResponse {
+    pub message: String,
+    pub invite_id: String,
+}
+
+/// Change seats in the waiting room
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SeatChangeRequest {
+    /// "sit" (move to an empty seat), "swap" (trade seats with whoever sits there)
+    /// or "partner" (seat `player_id` opposite you)
+    pub action: String,
+    /// Seat (0-3) for "sit" and "swap"
+    pub seat: Option<u8>,
+    /// Player to partner with for "partner"
+    pub player_id: Option<String>,
+}
+
+/// One seat at the table
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SeatInfo {
+    /// Seat number, which becomes the player's position when the game starts
+    pub seat: u8,
+    /// Player in the seat, if any
+    pub user_id: Option<String>,
+    pub username: Option<String>,
+}
+
+/// Who sits where; partners sit opposite each other (0 & 2, 1 & 3)
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SeatingResponse {
+    pub message: String,
+    pub game_id: String,
+    pub seats: Vec<SeatInfo>,
+}
+
+/// A player the host acts on
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TargetPlayerRequest {
+    pub player_id: String,
+}
+
+/// Open or close the room to new players
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LockRoomRequest {
+    pub locked: bool,
+}
+
+/// Result of a host moderation action
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ModerationResponse {
+    pub message: String,
+    pub game_id: String,
 }
 
 /// Response when successfully joining a match
@@ -62,12 +293,37 @@ pub struct PlayerInfo {
     pub role: String,
 }
 
+/// Request to add a bot to a match
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AddBotRequest {
+    /// "normal" (rule-based, default), "hard" (Monte Carlo search) or
+    /// "external:<name>" for an engine configured on the server
+    pub difficulty: Option<String>,
+}
+
+/// Response when a bot is added to a match
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AddBotResponse {
+    /// Success message
+    pub message: String,
+    /// The game ID
+    pub game_id: String,
+    /// User ID of the new bot seat
+    pub bot_id: String,
+    /// Display name of the bot
+    pub bot_name: String,
+    /// How strongly the bot plays
+    pub difficulty: String,
+    /// Everyone now in the match, bots included
+    pub players: Vec<PlayerInfo>,
+}
+
 /// Response when successfully leaving a match
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct LeaveMatchResponse {
     /// Success message
     pub message: String,
-    /// Whether the entire game was deleted (true if host left)
+    /// Whether the entire game was deleted (true when no human was left to host it)
     pub game_deleted: bool,
     /// List of players affected by the leave action
     pub affected_players: Vec<String>,
@@ -83,6 +339,24 @@ pub struct ErrorResponse {
     pub message: Option<String>,
 }
 
+/// A move the rules don't allow, with what the player may do instead
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RuleViolationResponse {
+    /// Code of the broken rule, e.g. "must_follow_suit"
+    pub error: String,
+    /// The broken rule in English
+    pub message: String,
+    /// The rule code with its details, e.g. {"code": "bid_too_low", "min": 6}
+    #[schema(value_type = Object)]
+    pub violation: serde_json::Value,
+    /// Cards the player may play right now (as card codes like "AS", "QC")
+    pub legal_cards: Vec<String>,
+    /// Bids the player may make right now
+    pub legal_bids: Vec<BidOption>,
+    /// Whether the player may pass right now
+    pub can_pass: bool,
+}
+
 /// Success response for debug operations
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct DebugResponse {
@@ -168,6 +442,8 @@ pub struct StartGameResponse {
     pub hands_dealt: bool,
     /// Number of dealing attempts required to get valid hands
     pub dealing_attempts: u32,
+    /// SHA-256 commitment to the deal, revealed once the game is over
+    pub deal_commitment: String,
 }
 
 /// Game state information after starting
@@ -208,6 +484,34 @@ pub struct PlayerHandResponse {
     pub available_bids: Vec<BidOption>,
     /// Whether this player can make a bid
     pub can_bid: bool,
+    /// Estimated outcome of each trump suit, during bidding for players with hints enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidding_advice: Option<BiddingAdviceInfo>,
+}
+
+/// Bidding advice from playing the hand out over sampled deals
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BiddingAdviceInfo {
+    /// Every suit the hand could declare, best expected score first
+    pub evaluations: Vec<SuitEvaluationInfo>,
+    /// Bid to make, or null to pass
+    pub recommended_bid: Option<BidOption>,
+}
+
+/// How a contract in one trump suit is likely to go
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SuitEvaluationInfo {
+    pub suit: String,
+    /// Trumps held in this suit
+    pub trumps_held: u8,
+    /// Average card points the trump team takes (0-120)
+    pub expected_points: f64,
+    /// Average game score for the trump team, minus the opponents' score
+    pub expected_score: f64,
+    /// Chance the trump team wins, vol included (0.0 - 1.0)
+    pub win_probability: f64,
+    /// Chance of each result, e.g. "TrumpTeamWin" or "Vol"
+    pub results: std::collections::BTreeMap<String, f64>,
 }
 
 /// A bid option available to a player
@@ -406,8 +710,10 @@ pub struct GameCompleteResponse {
     /// Whether a cross (rubber) was won
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cross_won: Option<CrossWinner>,
-    /// Whether to start a new game
+    /// Whether the next game has been dealt
     pub new_game_ready: bool,
+    /// Whether all crosses of the match have been played
+    pub match_complete: bool,
 }
 
 /// Final scoring results for a completed game
@@ -436,26 +742,31 @@ pub struct GameScoringResult {
 }
 
 /// Cross/Rubber scoring state
-#[derive(Serialize, Deserialize, ToSchema)]
+///
+/// Partnerships are fixed for the match: team A is seats 0 and 2, team B is
+/// seats 1 and 3, whichever of them declared trumps.
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 pub struct CrossScores {
-    /// Trump team's remaining points (starts at 24, counts down)
-    pub trump_team_remaining: i8,
-    /// Opponent team's remaining points (starts at 24, counts down)
-    pub opponent_team_remaining: i8,
-    /// Whether trump team is "on the hook" (6 points remaining)
-    pub trump_team_on_hook: bool,
-    /// Whether opponent team is "on the hook" (6 points remaining)
-    pub opponent_team_on_hook: bool,
-    /// Crosses won by trump team
-    pub trump_team_crosses: u8,
-    /// Crosses won by opponent team
-    pub opponent_team_crosses: u8,
+    /// Team A's remaining points (starts at 24, counts down)
+    pub team_a_remaining: i8,
+    /// Team B's remaining points (starts at 24, counts down)
+    pub team_b_remaining: i8,
+    /// Whether team A is "on the hook" (6 points remaining)
+    pub team_a_on_hook: bool,
+    /// Whether team B is "on the hook" (6 points remaining)
+    pub team_b_on_hook: bool,
+    /// Crosses won by team A
+    pub team_a_crosses: u8,
+    /// Crosses won by team B
+    pub team_b_crosses: u8,
+    /// Extra points for the winner of the next game (from 60-60 ties)
+    pub next_game_bonus: u8,
 }
 
 /// Information about cross/rubber winner
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct CrossWinner {
-    /// Team that won the cross ("trump_team" or "opponents")
+    /// Team that won the cross ("team_a" for seats 0 & 2, "team_b" for seats 1 & 3)
     pub winning_team: String,
     /// Whether it was a double victory (opponent still at 24)
     pub double_victory: bool,
@@ -473,3 +784,281 @@ pub struct GameScoreInfo {
     /// Cross/rubber scores
     pub cross_scores: CrossScores,
 }
+
+/// A recorded deal and the hands regenerated from its seed
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DealInfo {
+    /// Position of this deal in the game's history (0 = first deal)
+    pub deal_number: u32,
+    /// Shuffle seed, as a string to avoid precision loss in JSON clients
+    pub seed: String,
+    /// Number of shuffles needed before a valid hand came up
+    pub attempts: u32,
+    /// Card codes for each seat, regenerated from the seed
+    pub hands: Vec<Vec<String>>,
+}
+
+/// Response listing every deal of a game
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GameDealsResponse {
+    /// The game ID
+    pub game_id: String,
+    /// Deals in the order they happened
+    pub deals: Vec<DealInfo>,
+}
+
+/// A finished deal revealed and checked against its commitment
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DealVerificationResponse {
+    /// The game ID
+    pub game_id: String,
+    /// Position of this deal in the game's history (0 = first deal)
+    pub deal_number: u32,
+    /// SHA-256 commitment that was broadcast before bidding
+    pub commitment: String,
+    /// Shuffle seed, as a string to avoid precision loss in JSON clients
+    pub seed: String,
+    /// Hex salt mixed into the commitment
+    pub salt: String,
+    /// Card codes in deck order; card `i` goes to seat `i % 4`
+    pub deck_order: Vec<String>,
+    /// Card codes for each seat, dealt from the deck order
+    pub hands: Vec<Vec<String>>,
+    /// Whether the revealed values match the commitment and the seed; a deal
+    /// that fails is answered with 409 instead
+    pub verified: bool,
+}
+
+/// A card that lowered its team's best reachable outcome
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CardMistake {
+    /// Trick the card was played in (1-8)
+    pub trick_number: u8,
+    /// Position of the player who played it (0-3)
+    pub player_position: u8,
+    /// Card code that was played
+    pub card: String,
+    /// Card code that would have kept the best outcome
+    pub best_card: String,
+    /// Card points the choice gave away under perfect play
+    pub point_cost: u8,
+}
+
+/// How the winning bid held up
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BidReview {
+    /// Position of the trump declarer (0-3)
+    pub declarer_position: u8,
+    /// Trump count bid
+    pub length: u8,
+    /// Trump suit
+    pub suit: String,
+    /// Trumps the declarer actually held
+    pub trumps_held: u8,
+    /// Whether the hand meets the bidding guideline (6+ trumps, or 5 with two top trumps)
+    pub sound: bool,
+    /// Card points the trump team could force with perfect play
+    pub double_dummy_points: u8,
+    /// Whether perfect play would have won the game (61+ points)
+    pub makeable: bool,
+}
+
+/// Post-game review of a finished deal against perfect play
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GameAnalysisResponse {
+    /// The game ID
+    pub game_id: String,
+    /// Position of this deal in the game's history (0 = first deal)
+    pub deal_number: u32,
+    /// Positions of the trump declarer and partner
+    pub trump_team: Vec<u8>,
+    /// Card points the trump team actually took
+    pub trump_team_points: u8,
+    /// Review of the winning bid
+    pub bid: BidReview,
+    /// Costly cards in play order
+    pub mistakes: Vec<CardMistake>,
+}
+
+/// A finished deal written in Sjavs Game Notation
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct GameRecordResponse {
+    /// The game ID
+    pub game_id: String,
+    /// Position of this deal in the game's history (0 = first deal)
+    pub deal_number: u32,
+    /// The deal as SGN text
+    pub notation: String,
+}
+
+/// Request to import a game written in Sjavs Game Notation
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ImportGameRecordRequest {
+    /// SGN text of one deal
+    pub notation: String,
+}
+
+/// Summary of an imported game after every action was checked
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ImportedGameRecordResponse {
+    /// Game ID from the record
+    pub game_id: String,
+    /// Deal number from the record
+    pub deal_number: u32,
+    /// Player names by seat
+    pub players: Vec<String>,
+    /// Position of the dealer (0-3)
+    pub dealer_position: u8,
+    /// Seed the hands were dealt from, if recorded (string to keep full precision)
+    pub seed: Option<String>,
+    /// House rules the deal was played with
+    pub rules: RuleSetInfo,
+    /// Hands as dealt, by seat
+    pub hands: Vec<Vec<String>>,
+    /// Number of bids and passes
+    pub bidding_actions: u8,
+    /// Number of tricks recorded, including an unfinished last trick
+    pub tricks_recorded: u8,
+    /// Whether all 8 tricks were played
+    pub complete: bool,
+    /// Final scoring, for finished deals
+    pub result: Option<GameScoringResult>,
+}
+
+/// Which deal to replay
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReplayQuery {
+    /// Deal to replay (0 = first deal); defaults to the latest deal
+    pub deal: Option<u32>,
+}
+
+/// The game as it stood right after one action
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReplaySnapshot {
+    /// Position of this action in the deal (1 = first bid)
+    pub step: u16,
+    /// Kind of action ("bid", "pass" or "play_card")
+    pub action: String,
+    /// Player who took the action (0-3)
+    pub player: u8,
+    /// Number of trumps bid (bids only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_length: Option<u8>,
+    /// Trump suit bid (bids only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_suit: Option<String>,
+    /// Card played (card plays only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<String>,
+    /// Game phase after the action ("Bidding", "Playing", "Complete" or "Redeal")
+    pub phase: String,
+    /// Player to act next, if any
+    pub current_player: Option<u8>,
+    /// Highest bid length so far
+    pub highest_bid_length: Option<u8>,
+    /// Player holding the highest bid
+    pub highest_bidder: Option<u8>,
+    /// Trump suit once bidding is complete
+    pub trump_suit: Option<String>,
+    /// Trick the cards on the table belong to (1-8)
+    pub trick_number: Option<u8>,
+    /// Cards on the table in play order, starting with the leader
+    pub trick_cards: Vec<String>,
+    /// Winner of the trick, once its fourth card is played
+    pub trick_winner: Option<u8>,
+    /// Tricks won by trump team
+    pub trump_team_tricks: u8,
+    /// Tricks won by opponent team
+    pub opponent_team_tricks: u8,
+    /// Points accumulated by trump team
+    pub trump_team_points: u8,
+    /// Points accumulated by opponent team
+    pub opponent_team_points: u8,
+    /// Cards each seat still holds (only once the deal is complete)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hands: Option<Vec<Vec<String>>>,
+}
+
+/// Every step of a deal, for scrubbing through it like a video
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReplayResponse {
+    /// The game ID
+    pub game_id: String,
+    /// Position of this deal in the game's history (0 = first deal)
+    pub deal_number: u32,
+    /// Position of the dealer (0-3)
+    pub dealer_position: u8,
+    /// Whether all 8 tricks were played
+    pub complete: bool,
+    /// Hands as dealt, by seat (only once the deal is complete)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hands: Option<Vec<Vec<String>>>,
+    /// State after each bid, pass and card, in order
+    pub snapshots: Vec<ReplaySnapshot>,
+}
+
+/// Request to claim the remaining tricks for your team
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ClaimRequest {
+    /// How the claimer intends to take the tricks, shown to the table
+    pub statement: Option<String>,
+}
+
+/// One card of a claim's line of play
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ClaimPlay {
+    /// Player who plays the card (0-3)
+    pub player_position: u8,
+    /// Card played
+    pub card: String,
+}
+
+/// Outcome of a claim
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ClaimResponse {
+    /// Success or rejection message
+    pub message: String,
+    /// The game ID
+    pub game_id: String,
+    /// Player who claimed (0-3)
+    pub player_position: u8,
+    /// Whether the claim held in every line
+    pub accepted: bool,
+    /// Tricks that were left to play, including the one in progress
+    pub tricks_claimed: u8,
+    /// Tricks the other team can take against best play (0 when accepted)
+    pub tricks_lost: u8,
+    /// Cards played out for an accepted claim, or a counter-line in which
+    /// the other team takes a trick
+    pub line: Vec<ClaimPlay>,
+    /// Scoring of the finished game (accepted claims only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_complete: Option<GameCompleteResponse>,
+}
+
+/// Cards ranked for the player to move
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct HintResponse {
+    pub message: String,
+    /// The game ID
+    pub game_id: String,
+    /// Player the hint is for (0-3)
+    pub player_position: u8,
+    /// Every legal card, best first
+    pub hints: Vec<CardHint>,
+    /// Hints this player has asked for during the deal, this one included
+    pub hints_used: u32,
+}
+
+/// A legal card and why it is worth playing
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CardHint {
+    /// Card code like "AS" or "QC"
+    pub card: String,
+    /// Times the search chose this card
+    pub visits: u32,
+    /// Average playout reward for the player's team (0.0 - 1.0)
+    pub expected_reward: f64,
+    /// Short reason, e.g. "Wins the trick for now, taking 10 points"
+    pub reason: String,
+}
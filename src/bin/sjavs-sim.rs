@@ -0,0 +1,232 @@
+//! Headless bot-vs-bot simulation
+//!
+//! Plays complete games in-process with no Redis and prints how often each
+//! result occurs per trump suit and bid length. Handy for checking rule
+//! variants and bot strength before shipping them.
+//!
+//! With `--export` every decision point is also written as JSONL, one
+//! `DecisionRecord` per line, for training and comparing models offline.
+//!
+//! ```text
+//! cargo run --release --bin sjavs-sim -- --games 10000 --seed 42 --seats normal,hard,normal,hard
+//! cargo run --release --bin sjavs-sim -- --games 1000 --export decisions.jsonl
+//! ```
+
+use sjavs_backend::bots;
+use sjavs_backend::game::bot::BotDifficulty;
+use sjavs_backend::game::dataset::DecisionRecord;
+use sjavs_backend::game::rules::RuleSet;
+use sjavs_backend::game::simulation::{self, ContractStats, SimulationConfig, SimulationReport};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
+use std::time::Instant;
+
+const USAGE: &str = "Usage: sjavs-sim [--games N] [--seed N] [--seats BOT,BOT,BOT,BOT] \
+[--rules PRESET] [--mcts-iterations N] [--export FILE]
+
+  --games N             complete games to play (default 1000)
+  --seed N              seed for deals and searches (default 0)
+  --seats LIST          bot per seat: normal, hard or external:<name> from
+                        SJAVS_EXTERNAL_BOTS; seats 0/2 and 1/3 are partners
+                        (default normal,normal,normal,normal)
+  --rules PRESET        standard, quick or no_club_bonus (default standard)
+  --mcts-iterations N   search iterations per move for hard bots (default 200)
+  --export FILE         write every decision point to FILE as JSONL";
+
+/// Parsed command line
+struct Options {
+    config: SimulationConfig,
+    export: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let Options { config, export } = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut writer = match export.as_deref().map(File::create).transpose() {
+        Ok(file) => file.map(BufWriter::new),
+        Err(e) => {
+            eprintln!("Failed to create export file: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let started = Instant::now();
+    let result = simulation::run_with(&config, |game| match writer.as_mut() {
+        Some(writer) => write_decisions(writer, &game.decisions),
+        None => Ok(()),
+    })
+    .and_then(|report| match writer.as_mut() {
+        Some(writer) => writer
+            .flush()
+            .map(|_| report)
+            .map_err(|e| format!("Failed to write export: {}", e)),
+        None => Ok(report),
+    });
+
+    match result {
+        Ok(report) => {
+            print_report(&config, &report);
+            if let Some(path) = &export {
+                println!("\nDecisions written to {}", path);
+            }
+            println!("\nFinished in {:.1}s", started.elapsed().as_secs_f64());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Simulation failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Build the config from command line flags, or `None` when help was asked for
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut config = SimulationConfig {
+        engines: Some(bots::choose_action),
+        ..SimulationConfig::default()
+    };
+    let mut export = None;
+
+    while let Some(flag) = args.next() {
+        if flag == "-h" || flag == "--help" {
+            return Ok(None);
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--games" => config.games = parse_number(&flag, &value)?,
+            "--seed" => config.seed = parse_number(&flag, &value)?,
+            "--mcts-iterations" => config.mcts_iterations = parse_number(&flag, &value)?,
+            "--export" => {
+                config.record_decisions = true;
+                export = Some(value);
+            }
+            "--rules" => {
+                config.rules = RuleSet::preset(&value)
+                    .ok_or_else(|| format!("Unknown rules preset '{}'", value))?
+            }
+            "--seats" => {
+                let seats = value
+                    .split(',')
+                    .map(|name| {
+                        BotDifficulty::parse(name.trim())
+                            .filter(bots::is_available)
+                            .ok_or_else(|| format!("Unknown bot '{}'", name))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                config.seats = seats
+                    .try_into()
+                    .map_err(|_| "--seats needs exactly four bots".to_string())?;
+            }
+            _ => return Err(format!("Unknown flag {}", flag)),
+        }
+    }
+
+    Ok(Some(Options { config, export }))
+}
+
+fn write_decisions(writer: &mut impl Write, decisions: &[DecisionRecord]) -> Result<(), String> {
+    for record in decisions {
+        serde_json::to_writer(&mut *writer, record)
+            .map_err(|e| format!("Failed to serialize decision: {}", e))?;
+        writeln!(writer).map_err(|e| format!("Failed to write export: {}", e))?;
+    }
+    Ok(())
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn print_report(config: &SimulationConfig, report: &SimulationReport) {
+    let seats: Vec<String> = config.seats.iter().map(|bot| bot.to_string()).collect();
+    println!(
+        "{} games, seed {}, rules '{}', seats [{}]",
+        report.overall.games,
+        config.seed,
+        config.rules.name,
+        seats.join(", ")
+    );
+    println!("Redeals after everybody passed: {}", report.redeals);
+
+    println!("\nResults");
+    for (result, count) in &report.overall.results {
+        println!(
+            "  {:<18} {:>7} {:>6.1}%",
+            result,
+            count,
+            percent(*count, report.overall.games)
+        );
+    }
+
+    println!("\nBy trump suit");
+    print_header("suit");
+    for (suit, stats) in &report.by_suit {
+        print_row(suit, stats);
+    }
+
+    println!("\nBy contract");
+    print_header("contract");
+    for ((suit, length), stats) in &report.by_contract {
+        print_row(&format!("{} {}", length, suit), stats);
+    }
+
+    println!("\nPartnerships");
+    for (team, label) in ["seats 0/2", "seats 1/3"].iter().enumerate() {
+        println!(
+            "  {:<10} games won {:>7}  crosses won {:>5}",
+            label, report.team_games_won[team], report.team_crosses_won[team]
+        );
+    }
+    println!(
+        "\nCrosses finished: {}, average length {:.2} games",
+        report.cross_lengths.len(),
+        report.average_cross_length()
+    );
+}
+
+fn print_header(label: &str) {
+    println!(
+        "  {:<12} {:>7} {:>9} {:>11}  results",
+        label, "games", "win rate", "avg points"
+    );
+}
+
+fn print_row(label: &str, stats: &ContractStats) {
+    let results: Vec<String> = stats
+        .results
+        .iter()
+        .map(|(result, count)| format!("{} {}", result, count))
+        .collect();
+    println!(
+        "  {:<12} {:>7} {:>8.1}% {:>11.1}  {}",
+        label,
+        stats.games,
+        stats.win_rate() * 100.0,
+        stats.average_points(),
+        results.join(", ")
+    );
+}
+
+fn percent(count: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
@@ -0,0 +1,330 @@
+//! Running external bot engines as child processes
+//!
+//! The engines speak the line protocol in `game::external`. Each seat keeps
+//! its own process between moves. An engine that answers too late, exits or
+//! moves illegally loses that turn to the built-in bot and is restarted for its
+//! next one.
+//!
+//! Only engines the operator lists in `SJAVS_EXTERNAL_BOTS` are run, as
+//! `name=command args;name=command args`. Hosts pick one by name.
+
+use crate::game::bot::BotDifficulty;
+use crate::game::engine::{GameAction, SjavsGame};
+use crate::game::external::{go_line, parse_move, position_lines};
+use crate::game::mcts;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time an engine gets for the handshake and for each move, unless configured
+const DEFAULT_MOVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Engines unused for this long are shut down (their game was probably abandoned)
+const IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Engine commands by name, from `SJAVS_EXTERNAL_BOTS`
+static ENGINES: Lazy<HashMap<String, String>> = Lazy::new(|| {
+    std::env::var("SJAVS_EXTERNAL_BOTS")
+        .map(|value| parse_engines(&value))
+        .unwrap_or_default()
+});
+
+/// Running engines by game and seat
+static RUNNING: Lazy<Mutex<HashMap<(String, usize), RunningEngine>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct RunningEngine {
+    engine: String,
+    bot: ExternalBot,
+    last_used: Instant,
+}
+
+/// Whether the operator configured an engine with this name
+pub fn is_configured(engine: &str) -> bool {
+    ENGINES.contains_key(engine)
+}
+
+/// Whether a bot of this difficulty can be seated: built-in ones always, engines when configured
+pub fn is_available(difficulty: &BotDifficulty) -> bool {
+    match difficulty {
+        BotDifficulty::External(engine) => is_configured(engine),
+        _ => true,
+    }
+}
+
+/// Move time from `SJAVS_EXTERNAL_BOT_TIMEOUT_MS`, or the default
+pub fn move_timeout() -> Duration {
+    std::env::var("SJAVS_EXTERNAL_BOT_TIMEOUT_MS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_MOVE_TIMEOUT)
+}
+
+/// Ask the named engine playing `player` for its action
+///
+/// Matches `game::external::EngineRunner`. A process that fails is dropped,
+/// so the next call starts a fresh one.
+pub fn choose_action(
+    engine: &str,
+    game: &SjavsGame,
+    player: usize,
+    bidding: &[GameAction],
+) -> Result<GameAction, String> {
+    let command = ENGINES
+        .get(engine)
+        .ok_or_else(|| format!("No external bot named '{}' is configured", engine))?;
+    let key = (game.game_id.clone(), player);
+
+    // Take the process out so a slow engine doesn't block other tables
+    let running = {
+        let mut running = RUNNING
+            .lock()
+            .map_err(|_| "External bot registry poisoned")?;
+        running.retain(|_, entry| entry.last_used.elapsed() < IDLE_TIMEOUT);
+        running.remove(&key)
+    };
+
+    let timeout = move_timeout();
+    let mut bot = match running {
+        Some(entry) if entry.engine == engine => entry.bot,
+        _ => ExternalBot::spawn(command, timeout)?,
+    };
+    let action = bot.choose_action(game, player, bidding, timeout)?;
+
+    if let Ok(mut running) = RUNNING.lock() {
+        running.insert(
+            key,
+            RunningEngine {
+                engine: engine.to_string(),
+                bot,
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    Ok(action)
+}
+
+/// `name=command args;name=command args`, skipping malformed entries
+fn parse_engines(value: &str) -> HashMap<String, String> {
+    value
+        .split(';')
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(name, command)| (name.trim().to_string(), command.trim().to_string()))
+        .filter(|(name, command)| !name.is_empty() && !command.is_empty())
+        .collect()
+}
+
+/// A running engine process
+pub struct ExternalBot {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines from the engine's stdout, read on a separate thread so reads can time out
+    lines: Receiver<String>,
+    /// Name the engine reported with `id name`
+    pub name: Option<String>,
+}
+
+impl ExternalBot {
+    /// Start `command` (split on whitespace) and wait for it to answer the handshake
+    pub fn spawn(command: &str, timeout: Duration) -> Result<Self, String> {
+        let mut parts = command.split_whitespace();
+        let program = parts.next().ok_or("External bot command is empty")?;
+        let mut child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| format!("Failed to start external bot '{}': {}", program, e))?;
+
+        let stdin = child.stdin.take().ok_or("External bot has no stdin")?;
+        let stdout = child.stdout.take().ok_or("External bot has no stdout")?;
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut bot = Self {
+            child,
+            stdin,
+            lines,
+            name: None,
+        };
+
+        bot.send("sjavs")?;
+        let deadline = Instant::now() + timeout;
+        loop {
+            let line = bot.read_line(deadline)?;
+            if line.trim() == "sjavsok" {
+                return Ok(bot);
+            }
+            if let Some(name) = line.trim().strip_prefix("id name ") {
+                bot.name = Some(name.trim().to_string());
+            }
+        }
+    }
+
+    /// Describe the position to the engine and wait for a legal move
+    pub fn choose_action(
+        &mut self,
+        game: &SjavsGame,
+        player: usize,
+        bidding: &[GameAction],
+        timeout: Duration,
+    ) -> Result<GameAction, String> {
+        if game.current_player() != Some(player) {
+            return Err(format!("It is not seat {}'s turn", player));
+        }
+
+        let legal = mcts::legal_actions(game);
+        for line in position_lines(game, player, bidding) {
+            self.send(&line)?;
+        }
+        self.send(&go_line(game.phase, &legal))?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let line = self.read_line(deadline)?;
+            let Some(reply) = line.trim().strip_prefix("move ") else {
+                continue; // info and anything unknown is ignored
+            };
+
+            return match parse_move(reply.trim(), player) {
+                Ok(action) if legal.contains(&action) => Ok(action),
+                _ => {
+                    let _ = self.send(&format!("illegal {}", reply.trim()));
+                    Err(format!(
+                        "External bot played illegal move '{}'",
+                        reply.trim()
+                    ))
+                }
+            };
+        }
+    }
+
+    fn send(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.stdin, "{}", line)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|e| format!("Failed to write to external bot: {}", e))
+    }
+
+    fn read_line(&self, deadline: Instant) -> Result<String, String> {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        self.lines.recv_timeout(remaining).map_err(|e| match e {
+            RecvTimeoutError::Timeout => "External bot did not answer in time".to_string(),
+            RecvTimeoutError::Disconnected => "External bot exited".to_string(),
+        })
+    }
+}
+
+impl Drop for ExternalBot {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::deck::Deck;
+    use crate::game::engine::GamePhase;
+    use crate::game::hand::Hand;
+
+    /// Passes when bidding and plays the first legal card
+    const FIRST_LEGAL_BOT: &str = r#"
+while read -r command rest; do
+  case "$command" in
+    sjavs) echo "id name First Legal"; echo "sjavsok" ;;
+    go) set -- $rest; if [ "$1" = bid ]; then echo "move pass"; else echo "info picking"; echo "move $2"; fi ;;
+    quit) exit 0 ;;
+  esac
+done
+"#;
+
+    /// Completes the handshake, then never moves
+    const SILENT_BOT: &str = r#"
+while read -r command rest; do
+  [ "$command" = sjavs ] && echo "sjavsok"
+done
+"#;
+
+    /// Start a shell script engine, deleting the script once it is running
+    fn spawn_script(body: &str) -> ExternalBot {
+        let path =
+            std::env::temp_dir().join(format!("sjavs_bot_{}.sh", uuid::Uuid::new_v4().simple()));
+        std::fs::write(&path, body).unwrap();
+        let bot = ExternalBot::spawn(&format!("sh {}", path.display()), Duration::from_secs(5));
+        std::fs::remove_file(&path).unwrap();
+        bot.expect("bot should start")
+    }
+
+    fn seeded_game(seed: u64) -> SjavsGame {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        SjavsGame::new("external_game".to_string(), 3, hands)
+    }
+
+    #[test]
+    fn test_engine_config() {
+        let engines = parse_engines("greedy=/opt/greedy --fast; py = python3 bot.py;broken");
+        assert_eq!(engines.len(), 2);
+        assert_eq!(engines["greedy"], "/opt/greedy --fast");
+        assert_eq!(engines["py"], "python3 bot.py");
+    }
+
+    #[test]
+    fn test_external_bot_plays_a_full_game() {
+        let mut bot = spawn_script(FIRST_LEGAL_BOT);
+        assert_eq!(bot.name.as_deref(), Some("First Legal"));
+
+        for seed in 0..10 {
+            let mut game = seeded_game(seed);
+            let mut bidding = Vec::new();
+
+            while let Some(player) = game.current_player() {
+                let action = if player == 1 {
+                    bot.choose_action(&game, player, &bidding, Duration::from_secs(5))
+                        .expect("bot should move legally")
+                } else {
+                    RuleBot::choose_action(&game, player).unwrap()
+                };
+                if game.phase == GamePhase::Bidding {
+                    bidding.push(action.clone());
+                }
+                game.apply(action).unwrap();
+            }
+
+            assert!(matches!(
+                game.phase,
+                GamePhase::Complete | GamePhase::Redeal
+            ));
+        }
+    }
+
+    #[test]
+    fn test_silent_bot_times_out() {
+        let mut bot = spawn_script(SILENT_BOT);
+        let game = seeded_game(0);
+        let player = game.current_player().unwrap();
+
+        let started = Instant::now();
+        let result = bot.choose_action(&game, player, &[], Duration::from_millis(200));
+        assert!(result.unwrap_err().contains("did not answer"));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
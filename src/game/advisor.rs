@@ -0,0 +1,264 @@
+use crate::game::bot::RuleBot;
+use crate::game::deck::Deck;
+use crate::game::engine::{Bid, GameAction, GameEvent, GamePhase, SjavsGame};
+use crate::game::hand::{BidOption, Hand};
+use crate::game::rules::RuleSet;
+use crate::game::scoring::{GameResult, SjavsResult};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::BTreeMap;
+
+/// Deals sampled per trump suit unless the caller asks for more or fewer
+pub const DEFAULT_SAMPLES: u32 = 200;
+
+/// How a contract in one trump suit is likely to go
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuitEvaluation {
+    pub suit: String,
+    /// Trumps the hand holds in this suit
+    pub trumps_held: u8,
+    /// Average card points the trump team takes (0-120)
+    pub expected_points: f64,
+    /// Average game score for the trump team, minus the opponents' score
+    pub expected_score: f64,
+    /// Share of deals the trump team wins, vol included (0.0 - 1.0)
+    pub win_probability: f64,
+    /// Share of deals ending in each `SjavsResult`, by name
+    pub results: BTreeMap<String, f64>,
+}
+
+/// What a hand should bid, with the numbers behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct BiddingAdvice {
+    /// Every suit the hand could declare, best expected score first
+    pub evaluations: Vec<SuitEvaluation>,
+    /// The bid to make, or `None` to pass
+    pub recommended_bid: Option<BidOption>,
+}
+
+impl BiddingAdvice {
+    /// The advice as an action for `player`
+    pub fn action(&self, player: usize) -> GameAction {
+        match &self.recommended_bid {
+            Some(bid) => GameAction::Bid {
+                player,
+                length: bid.length,
+                suit: bid.suit.clone(),
+            },
+            None => GameAction::Pass { player },
+        }
+    }
+}
+
+/// Estimates how well a hand plays as declarer in each trump suit
+///
+/// Deals the unseen cards at random many times and plays each deal out with
+/// `RuleBot` in every seat, the hand declaring its full trump length. Cheap
+/// enough to run on request, and the same seed always gives the same advice.
+pub struct BiddingAdvisor;
+
+impl BiddingAdvisor {
+    /// Advice for `hand` with `dealer` to deal, choosing among `available` bids
+    ///
+    /// Only suits the hand could open in (at least `rules.min_bid` trumps)
+    /// are evaluated. The recommended bid is the cheapest available one in
+    /// the best suit, as long as that suit is expected to score.
+    pub fn advise(
+        hand: &Hand,
+        dealer: usize,
+        rules: &RuleSet,
+        available: &[BidOption],
+        samples: u32,
+        seed: u64,
+    ) -> BiddingAdvice {
+        let trump_counts = hand.calculate_trump_counts();
+        let mut evaluations: Vec<SuitEvaluation> = ["hearts", "diamonds", "clubs", "spades"]
+            .into_iter()
+            .filter(|suit| trump_counts[*suit] >= rules.min_bid)
+            .map(|suit| {
+                let bid = Bid {
+                    player: hand.player_position,
+                    length: trump_counts[suit],
+                    suit: suit.to_string(),
+                };
+                Self::evaluate(hand, dealer, rules, bid, samples, seed)
+            })
+            .collect();
+        evaluations.sort_by(|a, b| b.expected_score.total_cmp(&a.expected_score));
+
+        let recommended_bid = evaluations
+            .iter()
+            .filter(|evaluation| evaluation.expected_score > 0.0)
+            .find_map(|evaluation| {
+                available
+                    .iter()
+                    .find(|option| option.suit == evaluation.suit)
+                    .cloned()
+            });
+
+        BiddingAdvice {
+            evaluations,
+            recommended_bid,
+        }
+    }
+
+    /// Advice for the player to bid in `game`, for bots
+    ///
+    /// Passes rather than outbid partner, like `RuleBot`.
+    pub fn advise_game(game: &SjavsGame, player: usize, samples: u32, seed: u64) -> BiddingAdvice {
+        let partner = (player + 2) % 4;
+        let available = if game.highest_bid.as_ref().map(|bid| bid.player) == Some(partner) {
+            vec![]
+        } else {
+            game.available_bids(player)
+        };
+
+        Self::advise(
+            &game.hands[player],
+            game.dealer,
+            &game.rules,
+            &available,
+            samples,
+            seed,
+        )
+    }
+
+    /// Play `bid` out over `samples` random deals of the unseen cards
+    pub fn evaluate(
+        hand: &Hand,
+        dealer: usize,
+        rules: &RuleSet,
+        bid: Bid,
+        samples: u32,
+        seed: u64,
+    ) -> SuitEvaluation {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut points = 0u32;
+        let mut score = 0i32;
+        let mut wins = 0u32;
+        let mut results: BTreeMap<String, f64> = BTreeMap::new();
+        let mut played = 0u32;
+
+        for _ in 0..samples {
+            let hands = Deck::deal_around(&hand.cards, bid.player, &mut rng);
+            let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+            let game = SjavsGame::new("advisor".to_string(), dealer, hands)
+                .with_rules(rules.clone())
+                .with_contract(bid.clone());
+
+            let Some((trump_team_points, result)) = play_out(game) else {
+                continue;
+            };
+            played += 1;
+            points += trump_team_points as u32;
+            score += result.trump_team_score as i32 - result.opponent_team_score as i32;
+            if matches!(
+                result.result_type,
+                SjavsResult::TrumpTeamWin | SjavsResult::Vol | SjavsResult::IndividualVol
+            ) {
+                wins += 1;
+            }
+            *results
+                .entry(format!("{:?}", result.result_type))
+                .or_default() += 1.0;
+        }
+
+        let share = |count: f64| {
+            if played == 0 {
+                0.0
+            } else {
+                count / played as f64
+            }
+        };
+        SuitEvaluation {
+            suit: bid.suit,
+            trumps_held: bid.length,
+            expected_points: share(points as f64),
+            expected_score: share(score as f64),
+            win_probability: share(wins as f64),
+            results: results
+                .into_iter()
+                .map(|(result, count)| (result, share(count)))
+                .collect(),
+        }
+    }
+}
+
+/// Finish a game with `RuleBot` in every seat
+fn play_out(mut game: SjavsGame) -> Option<(u8, GameResult)> {
+    while let Some(player) = game.current_player() {
+        let action = RuleBot::choose_action(&game, player)?;
+        for event in game.apply(action).ok()? {
+            if let GameEvent::GameComplete { scoring, result } = event {
+                return Some((scoring.trump_team_points, result));
+            }
+        }
+    }
+    debug_assert_ne!(game.phase, GamePhase::Playing);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card::Card;
+
+    fn hand(codes: &[&str], seat: usize) -> Hand {
+        Hand::new(
+            codes
+                .iter()
+                .map(|code| Card::from_code(code).unwrap())
+                .collect(),
+            seat,
+        )
+    }
+
+    #[test]
+    fn test_strong_hand_is_advised_to_bid() {
+        // All six permanent trumps plus the ace and ten of hearts
+        let strong = hand(&["QC", "QS", "JC", "JS", "JH", "JD", "AH", "10H"], 1);
+        let advice = strong.bidding_advice(0, &RuleSet::standard(), None, 40);
+
+        assert_eq!(advice.evaluations.len(), 4);
+        let hearts = advice
+            .evaluations
+            .iter()
+            .find(|evaluation| evaluation.suit == "hearts")
+            .unwrap();
+        assert_eq!(hearts.trumps_held, 8);
+        assert!(hearts.expected_points > 90.0);
+        assert!(hearts.win_probability > 0.9);
+
+        let shares: f64 = hearts.results.values().sum();
+        assert!((shares - 1.0).abs() < 1e-9);
+
+        let bid = advice.recommended_bid.clone().expect("should bid");
+        assert_eq!(bid.length, 5); // Cheapest legal bid in the best suit
+        assert!(matches!(
+            advice.action(1),
+            GameAction::Bid { player: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_weak_hand_is_advised_to_pass() {
+        let weak = hand(&["7H", "8H", "9D", "7D", "8S", "9S", "7C", "8C"], 2);
+        let advice = weak.bidding_advice(0, &RuleSet::standard(), None, 20);
+
+        assert!(advice.evaluations.is_empty());
+        assert_eq!(advice.action(2), GameAction::Pass { player: 2 });
+    }
+
+    #[test]
+    fn test_advice_is_reproducible() {
+        let cards = ["QC", "JS", "JH", "AH", "KH", "9H", "AS", "7D"];
+        let first = hand(&cards, 0).bidding_advice(3, &RuleSet::standard(), None, 30);
+        let second = hand(&cards, 0).bidding_advice(3, &RuleSet::standard(), None, 30);
+        assert_eq!(first, second);
+
+        // Outbidding a higher bid is only advised when the hand can afford it
+        let outbid = hand(&cards, 0).bidding_advice(3, &RuleSet::standard(), Some(8), 30);
+        assert_eq!(outbid.evaluations, first.evaluations);
+        assert_eq!(outbid.recommended_bid, None);
+    }
+}
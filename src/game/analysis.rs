@@ -0,0 +1,222 @@
+use crate::game::bot;
+use crate::game::card::{Card, Suit};
+use crate::game::solver::{DoubleDummySolver, Objective};
+use crate::game::trick::{PlayedDeal, TrickState};
+
+/// Card points the trump team needs to win a game
+const WINNING_POINTS: u8 = 61;
+
+/// A card that lowered the best outcome its player's team could still reach
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mistake {
+    pub trick_number: u8,
+    pub player: usize,
+    pub card: Card,
+    /// A card that would have kept the best outcome
+    pub best_card: Card,
+    /// Card points the choice gave away under perfect play
+    pub point_cost: u8,
+}
+
+/// Whether the winning bid was a good one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidAssessment {
+    pub declarer: usize,
+    pub length: u8,
+    pub suit: String,
+    /// Trumps the declarer actually held
+    pub trumps_held: u8,
+    /// The declarer's hand meets the usual bidding guideline
+    /// (6+ trumps, or 5 with two of the four top trumps)
+    pub sound: bool,
+    /// Card points the trump team could force with perfect play from the first lead
+    pub double_dummy_points: u8,
+    /// Perfect play would have won the game for the trump team
+    pub makeable: bool,
+}
+
+/// Review of a finished deal against perfect play
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameAnalysis {
+    pub trump_team: (usize, usize),
+    /// Card points the trump team actually took
+    pub trump_team_points: u8,
+    pub bid: BidAssessment,
+    /// Costly cards in play order
+    pub mistakes: Vec<Mistake>,
+}
+
+/// Replay a finished deal card by card against the double-dummy solver
+///
+/// Before each card the solver values every legal alternative. A card is a
+/// mistake when another card would have left the player's team with more
+/// points under perfect play from there on.
+pub fn analyze_deal(deal: &PlayedDeal) -> Result<GameAnalysis, String> {
+    let Some(first_trick) = deal.tricks.first() else {
+        return Err("Deal has no recorded tricks".to_string());
+    };
+    if deal.tricks.len() != 8 || deal.tricks.iter().any(|trick| !trick.is_complete) {
+        return Err("Deal was not played to the end".to_string());
+    }
+
+    let trump_team = deal.trump_team();
+    let trump = Suit::from(deal.bid.suit.as_str());
+    let hands = deal.dealt_hands();
+    let leader = first_trick.cards_played[0].0;
+    let opening = TrickState::new(
+        first_trick.game_id.clone(),
+        1,
+        leader,
+        deal.bid.suit.clone(),
+    );
+
+    let mut solver = DoubleDummySolver::new(&hands, trump, &opening, trump_team)?;
+    let mut mistakes = Vec::new();
+    let mut double_dummy_points = None;
+
+    for trick in &deal.tricks {
+        for &(player, card) in &trick.cards_played {
+            let values = solver.evaluate_moves(Objective::Points);
+            let on_trump_team = player == trump_team.0 || player == trump_team.1;
+
+            // Best alternative from the player's side of the table
+            let (best_card, best_value) = *values
+                .iter()
+                .max_by_key(|(_, value)| {
+                    if on_trump_team {
+                        *value as i16
+                    } else {
+                        -(*value as i16)
+                    }
+                })
+                .ok_or("No legal cards to evaluate")?;
+            double_dummy_points.get_or_insert(best_value);
+
+            let chosen_value = values
+                .iter()
+                .find(|(legal, _)| *legal == card)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| {
+                    format!(
+                        "{} was not a legal card in trick {}",
+                        card.code(),
+                        trick.trick_number
+                    )
+                })?;
+
+            let point_cost = best_value.abs_diff(chosen_value);
+            if point_cost > 0 {
+                mistakes.push(Mistake {
+                    trick_number: trick.trick_number,
+                    player,
+                    card,
+                    best_card,
+                    point_cost,
+                });
+            }
+
+            solver.advance(card)?;
+        }
+    }
+
+    let trump_team_points = deal
+        .tricks
+        .iter()
+        .filter(|trick| {
+            trick
+                .trick_winner
+                .is_some_and(|winner| winner == trump_team.0 || winner == trump_team.1)
+        })
+        .map(|trick| trick.calculate_points())
+        .sum();
+
+    let declarer_hand = &hands[deal.bid.player];
+    let double_dummy_points = double_dummy_points.unwrap_or(0);
+    let bid = BidAssessment {
+        declarer: deal.bid.player,
+        length: deal.bid.length,
+        suit: deal.bid.suit.clone(),
+        trumps_held: declarer_hand
+            .iter()
+            .filter(|card| card.is_trump(trump))
+            .count() as u8,
+        sound: bot::is_sound_bid(declarer_hand, &deal.bid.suit),
+        double_dummy_points,
+        makeable: double_dummy_points >= WINNING_POINTS,
+    };
+
+    Ok(GameAnalysis {
+        trump_team,
+        trump_team_points,
+        bid,
+        mistakes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::deck::Deck;
+    use crate::game::engine::{GamePhase, SjavsGame};
+    use crate::game::hand::Hand;
+
+    /// Play a seeded deal out with rule bots and record it
+    fn played_deal(seed: u64) -> Option<PlayedDeal> {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        let mut game = SjavsGame::new("analysis".to_string(), 3, hands);
+
+        while let Some(player) = game.current_player() {
+            let action = RuleBot::choose_action(&game, player)?;
+            game.apply(action).ok()?;
+        }
+        if game.phase != GamePhase::Complete {
+            return None;
+        }
+
+        let tricks = game.tricks?.completed_tricks;
+        Some(PlayedDeal {
+            dealer: 3,
+            bid: game.highest_bid?,
+            bidding: vec![],
+            tricks,
+            hints_used: [0; 4],
+        })
+    }
+
+    #[test]
+    fn test_mistakes_account_for_the_result() {
+        let deal = (0..20)
+            .find_map(played_deal)
+            .expect("a seed gets played out");
+        let analysis = analyze_deal(&deal).unwrap();
+        let trump_team = analysis.trump_team;
+
+        // Every point lost to a mistake moves the result away from perfect play
+        let mut expected = analysis.bid.double_dummy_points as i16;
+        for mistake in &analysis.mistakes {
+            assert!(mistake.point_cost > 0);
+            assert_ne!(mistake.card, mistake.best_card);
+            if mistake.player == trump_team.0 || mistake.player == trump_team.1 {
+                expected -= mistake.point_cost as i16;
+            } else {
+                expected += mistake.point_cost as i16;
+            }
+        }
+        assert_eq!(analysis.trump_team_points as i16, expected);
+        assert_eq!(
+            analysis.bid.makeable,
+            analysis.bid.double_dummy_points >= WINNING_POINTS
+        );
+    }
+
+    #[test]
+    fn test_unfinished_deal_is_rejected() {
+        let mut deal = (0..20)
+            .find_map(played_deal)
+            .expect("a seed gets played out");
+        deal.tricks.pop();
+        assert!(analyze_deal(&deal).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+use crate::game::advisor::{BiddingAdvisor, DEFAULT_SAMPLES};
+use crate::game::card::{Card, Rank, Suit};
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+use crate::game::external::EngineRunner;
+use crate::game::mcts::{MctsBot, MctsConfig};
+use crate::game::trick::GameTrickState;
+use std::fmt;
+
+/// How strongly a bot seat plays
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum BotDifficulty {
+    /// Rule-based heuristics, instant
+    #[default]
+    Normal,
+    /// Bids with the sampling advisor and plays with Monte Carlo tree search,
+    /// using up to the configured search budget per card
+    Hard,
+    /// An engine from `SJAVS_EXTERNAL_BOTS`, by name, written as `external:<name>`
+    External(String),
+}
+
+impl BotDifficulty {
+    /// Parse `normal`, `hard` or `external:<name>`
+    ///
+    /// Whether an engine of that name is configured is for the caller to check.
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(engine) = value.strip_prefix("external:") {
+            return (!engine.is_empty()).then(|| BotDifficulty::External(engine.to_string()));
+        }
+
+        match value.to_lowercase().as_str() {
+            "normal" => Some(BotDifficulty::Normal),
+            "hard" => Some(BotDifficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// Pick the action for `player` at this difficulty
+    ///
+    /// `bidding` is the deal's bids and passes so far, which external engines
+    /// are told about. `engines` runs them; when it is missing or an engine
+    /// fails, the rule-based bot takes the turn.
+    pub fn choose_action(
+        &self,
+        game: &SjavsGame,
+        player: usize,
+        bidding: &[GameAction],
+        engines: Option<EngineRunner>,
+    ) -> Option<GameAction> {
+        match self {
+            BotDifficulty::Normal => RuleBot::choose_action(game, player),
+            BotDifficulty::Hard if game.phase == GamePhase::Bidding => {
+                if game.current_player() != Some(player) {
+                    return None;
+                }
+                let seed = game.hands[player].card_set().bits() as u64;
+                Some(
+                    BiddingAdvisor::advise_game(game, player, DEFAULT_SAMPLES, seed).action(player),
+                )
+            }
+            BotDifficulty::Hard => MctsBot::choose_action(game, player, &MctsConfig::from_env()),
+            BotDifficulty::External(engine) => {
+                let action = engines
+                    .ok_or_else(|| "No engine runner was given".to_string())
+                    .and_then(|run| run(engine, game, player, bidding));
+                match action {
+                    Ok(action) => Some(action),
+                    Err(e) => {
+                        eprintln!(
+                            "External bot '{}' failed, the built-in bot takes its turn: {}",
+                            engine, e
+                        );
+                        RuleBot::choose_action(game, player)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for BotDifficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BotDifficulty::Normal => write!(f, "normal"),
+            BotDifficulty::Hard => write!(f, "hard"),
+            BotDifficulty::External(engine) => write!(f, "external:{}", engine),
+        }
+    }
+}
+
+/// Rule-based computer player
+///
+/// Bids only with a sound trump holding and plays with simple card-play
+/// heuristics: draw trumps as declarer, win tricks as cheaply as possible,
+/// smear points onto tricks partner is winning and throw away low cards
+/// otherwise. It never looks at other players' hands.
+pub struct RuleBot;
+
+impl RuleBot {
+    /// Pick the action for `player`, or `None` if it isn't their turn
+    pub fn choose_action(game: &SjavsGame, player: usize) -> Option<GameAction> {
+        if game.current_player() != Some(player) {
+            return None;
+        }
+
+        match game.phase {
+            GamePhase::Bidding => Some(Self::choose_bid(game, player)),
+            GamePhase::Playing => {
+                Self::choose_card(game, player).map(|card| GameAction::PlayCard { player, card })
+            }
+            GamePhase::Redeal | GamePhase::Complete => None,
+        }
+    }
+
+    /// Bid the cheapest sound option, or pass
+    pub fn choose_bid(game: &SjavsGame, player: usize) -> GameAction {
+        // Never outbid partner
+        let partner = (player + 2) % 4;
+        if game.highest_bid.as_ref().map(|bid| bid.player) == Some(partner) {
+            return GameAction::Pass { player };
+        }
+
+        let hand = &game.hands[player].cards;
+        game.available_bids(player)
+            .into_iter()
+            .find(|option| is_sound_bid(hand, &option.suit))
+            .map(|option| GameAction::Bid {
+                player,
+                length: option.length,
+                suit: option.suit,
+            })
+            .unwrap_or(GameAction::Pass { player })
+    }
+
+    /// Pick a legal card to play
+    pub fn choose_card(game: &SjavsGame, player: usize) -> Option<Card> {
+        let legal = game.legal_cards(player);
+        if legal.len() <= 1 {
+            return legal.first().copied();
+        }
+
+        let tricks = game.tricks.as_ref()?;
+        let trump = Suit::from(tricks.current_trick.trump_suit.as_str());
+        let trick = &tricks.current_trick;
+
+        let Some(lead_suit) = trick.lead_suit else {
+            return Some(choose_lead(
+                tricks,
+                &game.hands[player].cards,
+                &legal,
+                player,
+                trump,
+            ));
+        };
+
+        // Find who is currently winning the trick
+        let (winner, winning_card) =
+            trick.cards_played[1..]
+                .iter()
+                .fold(trick.cards_played[0], |best, &(seat, card)| {
+                    if card.beats(&best.1, trump, lead_suit) {
+                        (seat, card)
+                    } else {
+                        best
+                    }
+                });
+
+        let partner_winning = winner == (player + 2) % 4;
+        let last_to_play = trick.cards_played.len() == 3;
+
+        if partner_winning && (last_to_play || strength(&winning_card, trump) >= TOP_TRUMP) {
+            // Partner has the trick - give them as many points as possible
+            return legal
+                .iter()
+                .max_by_key(|card| {
+                    (
+                        !card.is_trump(trump),
+                        card.point_value(),
+                        u8::MAX - strength(card, trump),
+                    )
+                })
+                .copied();
+        }
+
+        if !partner_winning {
+            // Win as cheaply as possible
+            let cheapest_winner = legal
+                .iter()
+                .filter(|card| card.beats(&winning_card, trump, lead_suit))
+                .min_by_key(|card| strength(card, trump))
+                .copied();
+            if cheapest_winner.is_some() {
+                return cheapest_winner;
+            }
+        }
+
+        Some(cheapest_discard(&legal, trump))
+    }
+}
+
+/// Trump order at and above which a card is one of the four top trumps (Q♣, Q♠, J♣, J♠)
+const TOP_TRUMP: u8 = 37;
+
+/// Card strength on one scale: every trump ranks above every plain card
+fn strength(card: &Card, trump: Suit) -> u8 {
+    card.trump_order(trump)
+        .map(|order| order + 20)
+        .unwrap_or_else(|| card.non_trump_order())
+}
+
+/// Bid a suit with 6+ trumps, or with 5 when two of them are top trumps
+pub(crate) fn is_sound_bid(hand: &[Card], suit: &str) -> bool {
+    let trump = Suit::from(suit);
+    let trumps: Vec<&Card> = hand.iter().filter(|card| card.is_trump(trump)).collect();
+    let top_trumps = trumps
+        .iter()
+        .filter(|card| strength(card, trump) >= TOP_TRUMP)
+        .count();
+
+    trumps.len() >= 6 || (trumps.len() == 5 && top_trumps >= 2)
+}
+
+/// Lowest-value card, keeping trumps where possible
+fn cheapest_discard(legal: &[Card], trump: Suit) -> Card {
+    *legal
+        .iter()
+        .min_by_key(|card| {
+            (
+                card.is_trump(trump),
+                card.point_value(),
+                strength(card, trump),
+            )
+        })
+        .expect("legal cards are never empty here")
+}
+
+/// Choose the opening card of a trick
+fn choose_lead(
+    tricks: &GameTrickState,
+    hand: &[Card],
+    legal: &[Card],
+    player: usize,
+    trump: Suit,
+) -> Card {
+    let on_trump_team = tricks.trump_team.0 == player || tricks.trump_team.1 == player;
+
+    // Declaring side draws trumps while it holds the best one still out
+    if on_trump_team {
+        let played: Vec<Card> = tricks
+            .completed_tricks
+            .iter()
+            .flat_map(|trick| trick.cards_played.iter().map(|(_, card)| *card))
+            .collect();
+        let best_unseen = all_trumps(trump)
+            .into_iter()
+            .filter(|card| !played.contains(card) && !hand.contains(card))
+            .map(|card| strength(&card, trump))
+            .max();
+        let best_held = legal
+            .iter()
+            .filter(|card| card.is_trump(trump))
+            .max_by_key(|card| strength(card, trump));
+
+        if let (Some(best_held), Some(best_unseen)) = (best_held, best_unseen) {
+            if strength(best_held, trump) > best_unseen {
+                return *best_held;
+            }
+        }
+    }
+
+    // Cash a plain ace, otherwise lead low
+    legal
+        .iter()
+        .find(|card| card.rank == Rank::Ace && !card.is_trump(trump))
+        .copied()
+        .unwrap_or_else(|| cheapest_discard(legal, trump))
+}
+
+/// Every card that is trump when `trump` is the trump suit
+fn all_trumps(trump: Suit) -> Vec<Card> {
+    let mut cards = Vec::new();
+    for suit in [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades] {
+        for rank in [
+            Rank::Seven,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Ten,
+            Rank::Jack,
+            Rank::Queen,
+            Rank::King,
+            Rank::Ace,
+        ] {
+            let card = Card::new(suit, rank);
+            if card.is_trump(trump) {
+                cards.push(card);
+            }
+        }
+    }
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::Deck;
+    use crate::game::engine::GameEvent;
+    use crate::game::hand::Hand;
+
+    fn seeded_game(seed: u64) -> SjavsGame {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        SjavsGame::new("bot_game".to_string(), 3, hands)
+    }
+
+    #[test]
+    fn test_bots_play_full_games_legally() {
+        for seed in 0..20 {
+            let mut game = seeded_game(seed);
+
+            while let Some(player) = game.current_player() {
+                let action = RuleBot::choose_action(&game, player).expect("bot should act");
+                game.apply(action).expect("bot action should be legal");
+            }
+
+            // Either everyone passed or the game was played out
+            match game.phase {
+                GamePhase::Complete => assert!(game.hands.iter().all(|h| h.cards.is_empty())),
+                GamePhase::Redeal => assert!(game.highest_bid.is_none()),
+                phase => panic!("unexpected phase {:?}", phase),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bot_does_not_outbid_partner() {
+        // Unshuffled deal gives seat 0 all the top trumps
+        let hands = Deck::new().deal().unwrap();
+        let mut hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        hands[2] = hands[0].clone();
+        let mut game = SjavsGame::new("bot_game".to_string(), 3, hands);
+
+        let events = game
+            .apply(GameAction::Bid {
+                player: 0,
+                length: 5,
+                suit: "hearts".to_string(),
+            })
+            .unwrap();
+        assert!(matches!(events[0], GameEvent::BidMade { .. }));
+        game.apply(GameAction::Pass { player: 1 }).unwrap();
+
+        assert_eq!(
+            RuleBot::choose_bid(&game, 2),
+            GameAction::Pass { player: 2 }
+        );
+    }
+
+    #[test]
+    fn test_sound_bid_threshold() {
+        let hand = vec![
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        assert!(is_sound_bid(&hand, "hearts")); // 5 trumps, two on top
+
+        let weak = vec![
+            Card::new(Suit::Diamonds, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        assert!(!is_sound_bid(&weak, "hearts")); // 5 trumps, none on top
+    }
+}
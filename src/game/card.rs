@@ -1,5 +1,21 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub};
+
+/// Suits in card index order
+const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+
+/// Ranks in card index order, lowest first
+const RANKS: [Rank; 8] = [
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
 
 /// Card suits in Sjavs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -89,6 +105,16 @@ impl Card {
         Self { suit, rank }
     }
 
+    /// Position of the card in a `CardSet`: suit * 8 + rank, with 7 = 0 and A = 7
+    pub fn index(&self) -> usize {
+        self.suit as usize * 8 + (self.rank as usize - Rank::Seven as usize)
+    }
+
+    /// The card at a `CardSet` position (0-31)
+    pub fn from_index(index: usize) -> Self {
+        Self::new(SUITS[index / 8], RANKS[index % 8])
+    }
+
     /// Get card code for storage/transmission (e.g., "AS", "QC", "10H")
     pub fn code(&self) -> String {
         format!("{}{}", self.rank, self.suit)
@@ -100,8 +126,8 @@ impl Card {
             return Err("Card code too short".to_string());
         }
 
-        let (rank_str, suit_str) = if code.starts_with("10") {
-            ("10", &code[2..])
+        let (rank_str, suit_str) = if let Some(suit_str) = code.strip_prefix("10") {
+            ("10", suit_str)
         } else {
             (&code[..1], &code[1..])
         };
@@ -230,6 +256,208 @@ impl fmt::Display for Card {
     }
 }
 
+/// A set of cards packed into 32 bits, one bit per card at `Card::index`
+///
+/// Copyable and allocation free, for bots, solvers and simulations that
+/// look at many hands. Converts to and from `Vec<Card>` without loss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u32);
+
+/// Every permanent trump: both black queens and all four jacks
+const PERMANENT_TRUMPS: u32 = 1 << (2 * 8 + 5) // Queen of clubs
+    | 1 << (3 * 8 + 5) // Queen of spades
+    | 1 << (2 * 8 + 4) // Jack of clubs
+    | 1 << (3 * 8 + 4) // Jack of spades
+    | 1 << 4 // Jack of hearts
+    | 1 << (8 + 4); // Jack of diamonds
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL: CardSet = CardSet(u32::MAX);
+
+    pub fn from_bits(bits: u32) -> Self {
+        CardSet(bits)
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn from_cards(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+
+    /// All eight cards of a suit
+    pub fn suit(suit: Suit) -> Self {
+        CardSet(0xff << (suit as u32 * 8))
+    }
+
+    /// The six permanent trumps
+    pub fn permanent_trumps() -> Self {
+        CardSet(PERMANENT_TRUMPS)
+    }
+
+    /// Every trump when `trump_suit` is trumps: its suit plus the permanent trumps
+    pub fn trumps(trump_suit: Suit) -> Self {
+        CardSet(PERMANENT_TRUMPS) | CardSet::suit(trump_suit)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, card: Card) -> bool {
+        self.0 & 1 << card.index() != 0
+    }
+
+    /// Add a card, returning false if it was already in the set
+    pub fn insert(&mut self, card: Card) -> bool {
+        let added = !self.contains(card);
+        self.0 |= 1 << card.index();
+        added
+    }
+
+    /// Take a card out, returning false if it wasn't in the set
+    pub fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !(1 << card.index());
+        removed
+    }
+
+    /// The cards of this set in the given suit
+    pub fn of_suit(self, suit: Suit) -> Self {
+        self & CardSet::suit(suit)
+    }
+
+    /// How many trumps this set holds when `trump_suit` is trumps
+    pub fn trump_count(self, trump_suit: Suit) -> u8 {
+        (self & CardSet::trumps(trump_suit)).len() as u8
+    }
+
+    /// Trump counts for hearts, diamonds, clubs and spades, in that order
+    pub fn trump_counts(self) -> [u8; 4] {
+        SUITS.map(|suit| self.trump_count(suit))
+    }
+
+    /// Cards that may be played from this hand, same rule as `TrickState::get_legal_cards`
+    pub fn legal_plays(self, lead_suit: Option<Suit>) -> Self {
+        match lead_suit.map(|suit| self.of_suit(suit)) {
+            Some(following) if !following.is_empty() => following,
+            _ => self,
+        }
+    }
+
+    /// Total point value of the cards
+    pub fn points(self) -> u8 {
+        self.iter().map(|card| card.point_value()).sum()
+    }
+
+    /// Cards in index order: hearts, diamonds, clubs, spades, each 7 up to ace
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, other: CardSet) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, other: CardSet) {
+        self.0 &= other.0;
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        CardSet(!self.0)
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> Self {
+        CardSet(1 << card.index())
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        CardSet(
+            cards
+                .into_iter()
+                .fold(0, |bits, card| bits | 1 << card.index()),
+        )
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the cards of a `CardSet`, lowest index first
+pub struct CardSetIter(u32);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(Card::from_index(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +543,92 @@ mod tests {
         assert_eq!(Card::new(Suit::Hearts, Rank::Seven).point_value(), 0);
     }
 
+    #[test]
+    fn test_card_index_round_trip() {
+        let indices: Vec<usize> = (0..32).map(|i| Card::from_index(i).index()).collect();
+        assert_eq!(indices, (0..32).collect::<Vec<usize>>());
+        assert_eq!(Card::new(Suit::Hearts, Rank::Seven).index(), 0);
+        assert_eq!(Card::new(Suit::Spades, Rank::Ace).index(), 31);
+    }
+
+    #[test]
+    fn test_card_set_round_trip() {
+        let cards = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ten),
+            Card::new(Suit::Clubs, Rank::Queen),
+        ];
+        let set = CardSet::from_cards(&cards);
+        assert_eq!(set.len(), 3);
+        assert!(cards.iter().all(|&card| set.contains(card)));
+
+        let mut back = set.to_vec();
+        let mut expected = cards.clone();
+        back.sort_by_key(Card::index);
+        expected.sort_by_key(Card::index);
+        assert_eq!(back, expected);
+
+        let all: CardSet = (0..32).map(Card::from_index).collect();
+        assert_eq!(all, CardSet::FULL);
+    }
+
+    #[test]
+    fn test_card_set_operations() {
+        let mut set = CardSet::EMPTY;
+        let ace = Card::new(Suit::Hearts, Rank::Ace);
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+
+        let hearts = CardSet::suit(Suit::Hearts);
+        assert_eq!(hearts.len(), 8);
+        assert_eq!(set & hearts, set);
+        assert_eq!((hearts - set).len(), 7);
+        assert_eq!((hearts | CardSet::suit(Suit::Spades)).len(), 16);
+        assert_eq!((!hearts).len(), 24);
+
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_card_set_trumps() {
+        assert_eq!(CardSet::permanent_trumps().len(), 6);
+        assert!(CardSet::permanent_trumps()
+            .iter()
+            .all(|card| card.is_permanent_trump()));
+
+        for suit in [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades] {
+            let trumps = CardSet::trumps(suit);
+            for card in CardSet::FULL {
+                assert_eq!(trumps.contains(card), card.is_trump(suit));
+            }
+        }
+        // Hearts and diamonds keep their own jack: 8 + 5 other permanent trumps
+        assert_eq!(CardSet::trumps(Suit::Hearts).len(), 13);
+        // Clubs and spades own a jack and a queen: 8 + 4
+        assert_eq!(CardSet::trumps(Suit::Clubs).len(), 12);
+    }
+
+    #[test]
+    fn test_card_set_legal_plays() {
+        let hand = CardSet::from_cards(&[
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Ace),
+        ]);
+
+        assert_eq!(hand.legal_plays(None), hand);
+        assert_eq!(hand.legal_plays(Some(Suit::Hearts)).len(), 2);
+        assert_eq!(
+            hand.legal_plays(Some(Suit::Spades)).to_vec(),
+            vec![Card::new(Suit::Spades, Rank::Ace)]
+        );
+        // Can't follow: anything goes
+        assert_eq!(hand.legal_plays(Some(Suit::Clubs)), hand);
+        assert_eq!(hand.points(), 13);
+    }
+
     #[test]
     fn test_suit_from_string() {
         assert_eq!(Suit::from("hearts"), Suit::Hearts);
@@ -0,0 +1,174 @@
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+use crate::game::solver::{DoubleDummySolver, Objective};
+
+/// Outcome of checking a claim for the rest of the tricks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimCheck {
+    /// Tricks left to play, including the one in progress
+    pub tricks_remaining: u8,
+    /// Tricks the other team can take however well the claiming team plays
+    pub tricks_lost: u8,
+    /// Best play by both teams from here: it plays the claim out when the
+    /// claim holds, and shows how the other team takes a trick when it fails
+    pub line: Vec<GameAction>,
+}
+
+impl ClaimCheck {
+    /// Whether the claiming team wins every remaining trick in every line
+    pub fn holds(&self) -> bool {
+        self.tricks_lost == 0
+    }
+}
+
+/// Check a player's claim that their team takes all remaining tricks
+///
+/// Every remaining hand is searched with the double-dummy solver, so a claim
+/// only holds if no defence can win a single trick against it.
+pub fn check_claim(game: &SjavsGame, player: usize) -> Result<ClaimCheck, String> {
+    if game.phase != GamePhase::Playing {
+        return Err("Game is not in playing phase".to_string());
+    }
+    if player > 3 {
+        return Err("Invalid player position".to_string());
+    }
+
+    let tricks = game.tricks.as_ref().ok_or("Trick state not initialized")?;
+    let tricks_remaining = 8 - tricks.completed_tricks.len() as u8;
+    let claimer_on_trump_team = player == tricks.trump_team.0 || player == tricks.trump_team.1;
+
+    let mut solver = DoubleDummySolver::from_game(game)?;
+    let mut game = game.clone();
+    let mut line = Vec::new();
+    let mut claimer_tricks = 0;
+
+    while let Some(seat) = game.current_player() {
+        // The trump team plays for the most tricks and the opponents for the fewest
+        let on_trump_team = {
+            let trump_team = game
+                .tricks
+                .as_ref()
+                .ok_or("Trick state not initialized")?
+                .trump_team;
+            seat == trump_team.0 || seat == trump_team.1
+        };
+        let values = solver.evaluate_moves(Objective::Tricks);
+        let best = values
+            .iter()
+            .map(|(_, value)| *value)
+            .reduce(|a, b| if on_trump_team { a.max(b) } else { a.min(b) })
+            .ok_or("No legal card to play")?;
+        let card = values
+            .iter()
+            .find(|(_, value)| *value == best)
+            .map(|(card, _)| *card)
+            .ok_or("No legal card to play")?;
+
+        // The value of the first position is what perfect play makes of the claim
+        if line.is_empty() {
+            claimer_tricks = if claimer_on_trump_team {
+                best
+            } else {
+                tricks_remaining - best
+            };
+        }
+
+        solver.advance(card)?;
+        let action = GameAction::PlayCard { player: seat, card };
+        game.apply(action.clone())?;
+        line.push(action);
+    }
+
+    Ok(ClaimCheck {
+        tricks_remaining,
+        tricks_lost: tricks_remaining - claimer_tricks,
+        line,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::deck::Deck;
+    use crate::game::hand::Hand;
+
+    /// A seeded deal played by rule bots until `tricks_left` tricks remain
+    fn endgame(seed: u64, tricks_left: usize) -> Option<SjavsGame> {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let mut game = SjavsGame::new(
+            "claim".to_string(),
+            0,
+            [0, 1, 2, 3].map(|seat| Hand::new(hands[seat].clone(), seat)),
+        );
+
+        while let Some(player) = game.current_player() {
+            if game.phase == GamePhase::Playing && game.hands[player].cards.len() == tricks_left {
+                let trick = &game.tricks.as_ref()?.current_trick;
+                if trick.cards_played.is_empty() {
+                    return Some(game);
+                }
+            }
+            let action = RuleBot::choose_action(&game, player)?;
+            game.apply(action).ok()?;
+        }
+        None
+    }
+
+    #[test]
+    fn test_last_trick_claim() {
+        let game = (0..20)
+            .find_map(|seed| endgame(seed, 1))
+            .expect("a seed reaches the last trick");
+
+        // Every card is forced, so exactly one team can claim the last trick
+        let checks: Vec<ClaimCheck> = (0..4)
+            .map(|seat| check_claim(&game, seat).unwrap())
+            .collect();
+        assert_eq!(checks.iter().filter(|check| check.holds()).count(), 2);
+        for (seat, check) in checks.iter().enumerate() {
+            assert_eq!(check.tricks_remaining, 1);
+            assert_eq!(check.line.len(), 4);
+            assert_eq!(check.holds(), checks[(seat + 2) % 4].holds());
+        }
+    }
+
+    #[test]
+    fn test_holding_claim_plays_out_for_the_claimer() {
+        let (game, seat, check) = (0..20)
+            .filter_map(|seed| endgame(seed, 3))
+            .find_map(|game| {
+                (0..4).find_map(|seat| {
+                    let check = check_claim(&game, seat).unwrap();
+                    check.holds().then(|| (game.clone(), seat, check))
+                })
+            })
+            .expect("some endgame has a holding claim");
+
+        let tricks_before = game.tricks.as_ref().unwrap().tricks_won;
+        let mut played = game.clone();
+        for action in &check.line {
+            played.apply(action.clone()).unwrap();
+        }
+        assert_eq!(played.phase, GamePhase::Complete);
+
+        let tricks = played.tricks.as_ref().unwrap();
+        let claimer_on_trump_team = seat == tricks.trump_team.0 || seat == tricks.trump_team.1;
+        let gained = if claimer_on_trump_team {
+            tricks.tricks_won.0 - tricks_before.0
+        } else {
+            tricks.tricks_won.1 - tricks_before.1
+        };
+        assert_eq!(gained, 3);
+    }
+
+    #[test]
+    fn test_claim_outside_play_is_rejected() {
+        let hands = Deck::deal_until_valid_seeded(1, 5).0;
+        let game = SjavsGame::new(
+            "claim".to_string(),
+            0,
+            [0, 1, 2, 3].map(|seat| Hand::new(hands[seat].clone(), seat)),
+        );
+        assert!(check_claim(&game, 1).is_err());
+    }
+}
@@ -1,12 +1,17 @@
+use crate::game::rules::RuleSet;
 use crate::game::scoring::GameResult;
 use serde::{Deserialize, Serialize};
 
 /// Cross state tracking for a match
+///
+/// Partnerships stay fixed for the whole match, so the `trump_team_*` columns
+/// belong to seats 0 and 2 and the `opponent_team_*` columns to seats 1 and 3.
+/// Use `apply_game_result_for_declarer` to credit a game to the right columns.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossState {
-    /// Current score for trump team (starts at 24, counts down)
+    /// Current score for trump team (starts at `starting_score`, counts down)
     pub trump_team_score: i8,
-    /// Current score for opponent team (starts at 24, counts down)
+    /// Current score for opponent team (starts at `starting_score`, counts down)
     pub opponent_team_score: i8,
     /// Number of crosses won by trump team
     pub trump_team_crosses: u8,
@@ -18,48 +23,69 @@ pub struct CrossState {
     pub match_id: String,
     /// Whether the cross is complete
     pub cross_complete: bool,
+    /// Points each team starts a cross with
+    #[serde(default = "default_starting_score")]
+    pub starting_score: i8,
+    /// Points a 60-60 tie adds to the next game
+    #[serde(default = "default_tie_bonus")]
+    pub tie_bonus: u8,
+}
+
+/// Cross states stored before rule sets existed used the standard rules
+fn default_starting_score() -> i8 {
+    RuleSet::standard().cross_start as i8
+}
+
+fn default_tie_bonus() -> u8 {
+    RuleSet::standard().tie_bonus
 }
 
 impl CrossState {
-    /// Create new cross state for a match
-    pub fn new(match_id: String) -> Self {
+    /// Create new cross state for a match played with the given rules
+    pub fn new(match_id: String, rules: &RuleSet) -> Self {
+        let starting_score = rules.cross_start as i8;
         Self {
-            trump_team_score: 24,
-            opponent_team_score: 24,
+            trump_team_score: starting_score,
+            opponent_team_score: starting_score,
             trump_team_crosses: 0,
             opponent_team_crosses: 0,
             next_game_bonus: 0,
             match_id,
             cross_complete: false,
+            starting_score,
+            tie_bonus: rules.tie_bonus,
         }
     }
 
     /// Apply game result to cross scores
     pub fn apply_game_result(&mut self, game_result: &GameResult) -> CrossResult {
-        // Apply bonus points if any
-        let trump_team_points = game_result.trump_team_score + self.next_game_bonus;
-        let opponent_team_points = game_result.opponent_team_score;
-
-        // Reset bonus after use
-        let bonus_applied = self.next_game_bonus;
-        self.next_game_bonus = 0;
-
         // Handle tie scenario (both teams get 60 points)
         if game_result.trump_team_score == 0 && game_result.opponent_team_score == 0 {
-            // This was a tie - add 2 to next game bonus
-            self.next_game_bonus = 2;
+            // This was a tie - the next game is worth more (bonuses stack)
+            self.next_game_bonus += self.tie_bonus;
             return CrossResult {
                 trump_team_old_score: self.trump_team_score,
                 opponent_team_old_score: self.opponent_team_score,
                 trump_team_new_score: self.trump_team_score,
                 opponent_team_new_score: self.opponent_team_score,
                 cross_won: None,
-                bonus_applied,
+                bonus_applied: 0,
                 next_game_bonus: self.next_game_bonus,
                 cross_complete: false,
             };
         }
 
+        // Any bonus from earlier ties goes to whichever team won this game
+        let bonus_applied = self.next_game_bonus;
+        self.next_game_bonus = 0;
+
+        let (trump_team_points, opponent_team_points) =
+            if game_result.trump_team_score >= game_result.opponent_team_score {
+                (game_result.trump_team_score + bonus_applied, 0)
+            } else {
+                (0, game_result.opponent_team_score + bonus_applied)
+            };
+
         let old_trump_score = self.trump_team_score;
         let old_opponent_score = self.opponent_team_score;
 
@@ -82,12 +108,35 @@ impl CrossState {
         }
     }
 
+    /// Apply a game result from the perspective of the declaring partnership
+    ///
+    /// `GameResult` is expressed as trump team vs opponents, which changes from
+    /// game to game. When the declarer sits in seat 1 or 3 the scores are
+    /// swapped so they land in the fixed cross columns.
+    pub fn apply_game_result_for_declarer(
+        &mut self,
+        game_result: &GameResult,
+        trump_declarer: usize,
+    ) -> CrossResult {
+        if CrossTeam::for_position(trump_declarer) == CrossTeam::TrumpTeam {
+            return self.apply_game_result(game_result);
+        }
+
+        let swapped = GameResult {
+            trump_team_score: game_result.opponent_team_score,
+            opponent_team_score: game_result.trump_team_score,
+            result_type: game_result.result_type.clone(),
+            description: game_result.description.clone(),
+        };
+        self.apply_game_result(&swapped)
+    }
+
     /// Check if a cross has been completed
     fn check_cross_completion(&mut self) -> Option<CrossWinner> {
         // Check if trump team won
         if self.trump_team_score <= 0 {
             // Check for double victory
-            let double_victory = self.opponent_team_score == 24;
+            let double_victory = self.opponent_team_score == self.starting_score;
 
             self.trump_team_crosses += 1;
             self.cross_complete = true;
@@ -103,7 +152,7 @@ impl CrossState {
         // Check if opponent team won
         if self.opponent_team_score <= 0 {
             // Check for double victory
-            let double_victory = self.trump_team_score == 24;
+            let double_victory = self.trump_team_score == self.starting_score;
 
             self.opponent_team_crosses += 1;
             self.cross_complete = true;
@@ -150,12 +199,10 @@ impl CrossState {
         Ok(())
     }
 
-    /// Reset for completely new cross
+    /// Reset the scores for the next cross of a match, keeping the crosses already won
     pub fn reset_for_new_cross(&mut self) {
-        self.trump_team_score = 24;
-        self.opponent_team_score = 24;
-        self.trump_team_crosses = 0;
-        self.opponent_team_crosses = 0;
+        self.trump_team_score = self.starting_score;
+        self.opponent_team_score = self.starting_score;
         self.next_game_bonus = 0;
         self.cross_complete = false;
     }
@@ -187,7 +234,7 @@ pub struct CrossResult {
 pub struct CrossWinner {
     /// Which team won the cross
     pub winning_team: CrossTeam,
-    /// Whether it was a double victory (opponent still at 24)
+    /// Whether it was a double victory (opponent still at the starting score)
     pub double_victory: bool,
     /// Final scores when cross was won
     pub final_score: (i8, i8), // (trump_team, opponent_team)
@@ -202,6 +249,25 @@ pub enum CrossTeam {
     OpponentTeam,
 }
 
+impl CrossTeam {
+    /// Cross column for a seat (seats 0/2 and 1/3 are partners)
+    pub fn for_position(position: usize) -> Self {
+        if position % 2 == 1 {
+            CrossTeam::OpponentTeam
+        } else {
+            CrossTeam::TrumpTeam
+        }
+    }
+
+    /// Seats that play in this cross column
+    pub fn positions(&self) -> [usize; 2] {
+        match self {
+            CrossTeam::TrumpTeam => [0, 2],
+            CrossTeam::OpponentTeam => [1, 3],
+        }
+    }
+}
+
 /// Summary of cross state for API responses
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossSummary {
@@ -226,11 +292,12 @@ pub struct CrossSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::rules::RuleSet;
     use crate::game::scoring::{GameResult, SjavsResult};
 
     #[test]
     fn test_new_cross_state() {
-        let cross = CrossState::new("test_match".to_string());
+        let cross = CrossState::new("test_match".to_string(), &RuleSet::standard());
         assert_eq!(cross.trump_team_score, 24);
         assert_eq!(cross.opponent_team_score, 24);
         assert_eq!(cross.trump_team_crosses, 0);
@@ -240,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_apply_normal_win() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
 
         let game_result = GameResult {
             trump_team_score: 4,
@@ -257,7 +324,7 @@ mod tests {
 
     #[test]
     fn test_cross_completion() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
         cross.trump_team_score = 4; // Close to winning
 
         let game_result = GameResult {
@@ -276,7 +343,7 @@ mod tests {
 
     #[test]
     fn test_double_victory() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
         cross.trump_team_score = 4;
         // opponent_team_score stays at 24
 
@@ -294,7 +361,7 @@ mod tests {
 
     #[test]
     fn test_tie_bonus() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
 
         // First game: tie
         let tie_result = GameResult {
@@ -322,9 +389,71 @@ mod tests {
         assert_eq!(cross.next_game_bonus, 0); // Reset after use
     }
 
+    #[test]
+    fn test_tie_bonus_goes_to_next_winner() {
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
+        cross.next_game_bonus = 2;
+
+        let opponent_win = GameResult {
+            trump_team_score: 0,
+            opponent_team_score: 4,
+            result_type: SjavsResult::OpponentWin,
+            description: "Opponents won".to_string(),
+        };
+
+        let result = cross.apply_game_result(&opponent_win);
+        assert_eq!(cross.trump_team_score, 24);
+        assert_eq!(cross.opponent_team_score, 18); // 24 - (4 + 2 bonus)
+        assert_eq!(result.bonus_applied, 2);
+    }
+
+    #[test]
+    fn test_declarer_in_odd_seat_scores_opponent_column() {
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
+
+        let game_result = GameResult {
+            trump_team_score: 4,
+            opponent_team_score: 0,
+            result_type: SjavsResult::TrumpTeamWin,
+            description: "Trump team won".to_string(),
+        };
+
+        // Seat 3 declared and won - credited to seats 1 & 3
+        cross.apply_game_result_for_declarer(&game_result, 3);
+        assert_eq!(cross.trump_team_score, 24);
+        assert_eq!(cross.opponent_team_score, 20);
+
+        // Seat 2 declared and won - credited to seats 0 & 2
+        cross.apply_game_result_for_declarer(&game_result, 2);
+        assert_eq!(cross.trump_team_score, 20);
+        assert_eq!(cross.opponent_team_score, 20);
+    }
+
+    #[test]
+    fn test_new_cross_keeps_tally() {
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
+        cross.trump_team_score = 2;
+
+        let game_result = GameResult {
+            trump_team_score: 4,
+            opponent_team_score: 0,
+            result_type: SjavsResult::TrumpTeamWin,
+            description: "Trump team won".to_string(),
+        };
+        cross.apply_game_result(&game_result);
+        assert!(cross.cross_complete);
+
+        cross.reset_for_new_cross();
+        assert_eq!(cross.trump_team_score, 24);
+        assert_eq!(cross.opponent_team_score, 24);
+        assert_eq!(cross.trump_team_crosses, 1);
+        assert!(!cross.cross_complete);
+        assert!(cross.start_new_game().is_ok());
+    }
+
     #[test]
     fn test_on_the_hook() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
         cross.trump_team_score = 6;
         cross.opponent_team_score = 6;
 
@@ -335,7 +464,7 @@ mod tests {
 
     #[test]
     fn test_vol_scenario() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
         cross.trump_team_score = 8; // Close to winning
 
         // Vol in clubs (16 points)
@@ -354,7 +483,7 @@ mod tests {
 
     #[test]
     fn test_opponent_vol() {
-        let mut cross = CrossState::new("test".to_string());
+        let mut cross = CrossState::new("test".to_string(), &RuleSet::standard());
         cross.opponent_team_score = 10; // Close to winning
 
         // Opponents win all tricks
@@ -374,4 +503,35 @@ mod tests {
         );
         assert!(cross.cross_complete);
     }
+
+    #[test]
+    fn test_rule_set_start_and_tie_bonus() {
+        let mut rules = RuleSet::preset("quick").unwrap();
+        rules.tie_bonus = 4;
+        let mut cross = CrossState::new("test".to_string(), &rules);
+        assert_eq!(cross.trump_team_score, 12);
+
+        let tie_result = GameResult {
+            trump_team_score: 0,
+            opponent_team_score: 0,
+            result_type: SjavsResult::Tie,
+            description: "Tie game".to_string(),
+        };
+        cross.apply_game_result(&tie_result);
+        assert_eq!(cross.next_game_bonus, 4);
+
+        let win_result = GameResult {
+            trump_team_score: 8,
+            opponent_team_score: 0,
+            result_type: SjavsResult::TrumpTeamWin,
+            description: "Trump team won".to_string(),
+        };
+        let result = cross.apply_game_result(&win_result);
+        let winner = result.cross_won.unwrap();
+        assert!(winner.double_victory); // Opponents still at 12
+
+        cross.reset_for_new_cross();
+        assert_eq!(cross.trump_team_score, 12);
+        assert_eq!(cross.opponent_team_score, 12);
+    }
 }
@@ -0,0 +1,165 @@
+use crate::game::cross::CrossState;
+use crate::game::engine::{Bid, GameAction, GamePhase, SjavsGame};
+use crate::game::hand::Hand;
+use crate::game::mcts;
+use crate::game::scoring::{GameResult, SjavsScoring};
+use crate::game::trick::GameTrickState;
+use serde::{Deserialize, Serialize};
+
+/// Version of the `DecisionRecord` layout
+///
+/// Bump whenever a field is added, removed or changes meaning, so analysts
+/// can tell exports apart.
+pub const DATASET_SCHEMA_VERSION: u32 = 1;
+
+/// One decision taken by a seat, with everything that seat could see
+///
+/// Written one per line as JSONL. Cards, actions, tricks and results use the
+/// engine's own serialization, so a record reads back into the same types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub schema_version: u32,
+    /// Game number within the run
+    pub game: u32,
+    /// Deal number within the game; everybody passed on the deals before it
+    pub deal: u32,
+    /// Decision number within the deal, from 0
+    pub decision: u32,
+    pub phase: GamePhase,
+    pub seat: usize,
+    pub observation: Observation,
+    /// Every action the seat was allowed to take
+    pub legal_actions: Vec<GameAction>,
+    /// The action the seat took
+    pub action: GameAction,
+    /// How the deal ended, or `None` when everybody passed and it was thrown in
+    pub outcome: Option<Outcome>,
+}
+
+/// The game as seen from one seat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Observation {
+    pub dealer: usize,
+    /// The seat's own cards; other hands stay hidden
+    pub hand: Hand,
+    /// Bids and passes so far, in order
+    pub bidding: Vec<GameAction>,
+    pub highest_bid: Option<Bid>,
+    /// Tricks so far: the current trick, completed tricks, points and tricks won
+    pub tricks: Option<GameTrickState>,
+    pub cross: CrossScores,
+}
+
+/// Cross scores from the point of view of the acting seat's partnership
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossScores {
+    /// Points the seat's team still needs to win the cross
+    pub team: i8,
+    /// Points the other team still needs
+    pub opponents: i8,
+    /// Bonus the next game is worth after earlier ties
+    pub next_game_bonus: u8,
+}
+
+impl CrossScores {
+    fn for_seat(cross: &CrossState, seat: usize) -> Self {
+        // Seats 0/2 use the trump team column and seats 1/3 the opponent column
+        let (team, opponents) = if seat % 2 == 1 {
+            (cross.opponent_team_score, cross.trump_team_score)
+        } else {
+            (cross.trump_team_score, cross.opponent_team_score)
+        };
+        Self {
+            team,
+            opponents,
+            next_game_bonus: cross.next_game_bonus,
+        }
+    }
+}
+
+/// Final result of a played deal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Outcome {
+    pub declarer: usize,
+    pub trump_suit: String,
+    pub bid_length: u8,
+    /// Whether the acting seat was on the declaring team
+    pub declaring_team: bool,
+    pub scoring: SjavsScoring,
+    pub result: GameResult,
+}
+
+/// Collects the decisions of one deal until its outcome is known
+#[derive(Debug, Default)]
+pub struct DealRecorder {
+    game: u32,
+    deal: u32,
+    bidding: Vec<GameAction>,
+    records: Vec<DecisionRecord>,
+}
+
+impl DealRecorder {
+    pub fn new(game: u32, deal: u32) -> Self {
+        Self {
+            game,
+            deal,
+            ..Self::default()
+        }
+    }
+
+    /// Record the action the player to move is about to take
+    pub fn record(&mut self, game: &SjavsGame, cross: &CrossState, action: &GameAction) {
+        let seat = action.player();
+        self.records.push(DecisionRecord {
+            schema_version: DATASET_SCHEMA_VERSION,
+            game: self.game,
+            deal: self.deal,
+            decision: self.records.len() as u32,
+            phase: game.phase,
+            seat,
+            observation: Observation {
+                dealer: game.dealer,
+                hand: game.hands[seat].clone(),
+                bidding: self.bidding.clone(),
+                highest_bid: game.highest_bid.clone(),
+                tricks: game.tricks.clone(),
+                cross: CrossScores::for_seat(cross, seat),
+            },
+            legal_actions: mcts::legal_actions(game),
+            action: action.clone(),
+            outcome: None,
+        });
+
+        if game.phase == GamePhase::Bidding {
+            self.bidding.push(action.clone());
+        }
+    }
+
+    /// Decisions of a deal everybody passed
+    pub fn thrown_in(self) -> Vec<DecisionRecord> {
+        self.records
+    }
+
+    /// Decisions of a played deal, each labelled with the outcome
+    pub fn finish(
+        self,
+        bid: &Bid,
+        scoring: &SjavsScoring,
+        result: &GameResult,
+    ) -> Vec<DecisionRecord> {
+        self.records
+            .into_iter()
+            .map(|mut record| {
+                record.outcome = Some(Outcome {
+                    declarer: bid.player,
+                    trump_suit: bid.suit.clone(),
+                    bid_length: bid.length,
+                    declaring_team: record.seat % 2 == bid.player % 2,
+                    scoring: scoring.clone(),
+                    result: result.clone(),
+                });
+                record
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,10 @@
 use super::card::{Card, Rank, Suit};
+use super::rules::RuleSet;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// A Sjavs deck (32 cards: 7, 8, 9, 10, J, Q, K, A in all suits)
 #[derive(Debug, Clone)]
@@ -38,6 +42,37 @@ impl Deck {
         self.cards.shuffle(&mut rng);
     }
 
+    /// Shuffle the deck deterministically from a seed
+    ///
+    /// Uses ChaCha8 explicitly rather than `StdRng`, whose algorithm may change
+    /// between `rand` releases, so stored seeds keep reproducing the same deal.
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        self.cards.shuffle(&mut rng);
+    }
+
+    /// Rebuild a deck from a revealed card order
+    pub fn from_codes(codes: &[String]) -> Result<Self, String> {
+        let cards = codes
+            .iter()
+            .map(|code| Card::from_code(code))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut unique = cards.clone();
+        unique.sort_by_key(|card| card.code());
+        unique.dedup();
+        if cards.len() != 32 || unique.len() != 32 {
+            return Err("Deck order must contain all 32 cards exactly once".to_string());
+        }
+
+        Ok(Self { cards })
+    }
+
+    /// Card codes in deck order, top card first
+    pub fn to_codes(&self) -> Vec<String> {
+        self.cards.iter().map(|card| card.code()).collect()
+    }
+
     /// Deal cards to 4 players (8 cards each)
     pub fn deal(&mut self) -> Result<[Vec<Card>; 4], String> {
         if self.cards.len() != 32 {
@@ -78,43 +113,96 @@ impl Deck {
         counts
     }
 
-    /// Check if any hand has at least 5 trumps in any suit (minimum for valid bid)
-    pub fn has_valid_hands(hands: &[Vec<Card>; 4]) -> bool {
+    /// Check if any hand has at least `min_bid` trumps in any suit (minimum for valid bid)
+    pub fn has_valid_hands(hands: &[Vec<Card>; 4], min_bid: u8) -> bool {
         for hand in hands {
             let trump_counts = Self::calculate_trump_counts(hand);
-            if trump_counts.iter().any(|&count| count >= 5) {
+            if trump_counts.iter().any(|&count| count >= min_bid) {
                 return true;
             }
         }
         false
     }
 
-    /// Generate hands until at least one player has a valid bid (5+ trumps)
+    /// Generate hands until at least one player has a valid bid (`min_bid`+ trumps)
     /// This implements the authentic Sjavs rule of redealing until someone can bid
-    pub fn deal_until_valid() -> [Vec<Card>; 4] {
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: u32 = 1000; // Safety valve
+    pub fn deal_until_valid(min_bid: u8) -> [Vec<Card>; 4] {
+        Self::deal_until_valid_seeded(thread_rng().gen(), min_bid).0
+    }
 
-        loop {
-            attempts += 1;
-            if attempts > MAX_ATTEMPTS {
-                // This should never happen in practice, but prevents infinite loops
-                panic!(
-                    "Unable to generate valid hands after {} attempts",
-                    MAX_ATTEMPTS
-                );
-            }
+    /// Deal valid hands from a fresh random seed, returning the record needed to reproduce them
+    pub fn deal_recorded(min_bid: u8) -> ([Vec<Card>; 4], DealRecord) {
+        let mut rng = thread_rng();
+        let seed = rng.gen();
+        let salt: [u8; 16] = rng.gen();
+        let (deck, attempts) = Self::shuffle_until_valid_seeded(seed, min_bid);
+        let hands = deck
+            .clone()
+            .deal()
+            .expect("shuffled deck always has 32 cards");
+        let salt: String = salt.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let record = DealRecord {
+            seed,
+            attempts,
+            min_bid,
+            commitment: deal_commitment(&salt, seed, &deck.to_codes()),
+            salt,
+        };
+        (hands, record)
+    }
 
+    /// Reproducible version of `deal_until_valid`
+    ///
+    /// Every redeal draws from the same seeded stream, so the seed alone
+    /// determines the final hands. Also returns how many deals it took.
+    pub fn deal_until_valid_seeded(seed: u64, min_bid: u8) -> ([Vec<Card>; 4], u32) {
+        let (mut deck, attempts) = Self::shuffle_until_valid_seeded(seed, min_bid);
+        let hands = deck.deal().expect("shuffled deck always has 32 cards");
+        (hands, attempts)
+    }
+
+    /// Shuffle from a seed until the deck deals valid hands, returning that deck
+    pub fn shuffle_until_valid_seeded(seed: u64, min_bid: u8) -> (Deck, u32) {
+        const MAX_ATTEMPTS: u32 = 1000; // Safety valve
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        for attempts in 1..=MAX_ATTEMPTS {
             let mut deck = Deck::new();
-            deck.shuffle();
+            deck.cards.shuffle(&mut rng);
 
             if let Ok(hands) = deck.deal() {
-                if Self::has_valid_hands(&hands) {
-                    println!("Generated valid hands after {} attempts", attempts);
-                    return hands;
+                if Self::has_valid_hands(&hands, min_bid) {
+                    return (deck, attempts);
                 }
             }
         }
+
+        // This should never happen in practice, but prevents infinite loops
+        panic!(
+            "Unable to generate valid hands after {} attempts",
+            MAX_ATTEMPTS
+        );
+    }
+
+    /// Deal the cards `seat` can't see at random to the other three seats
+    ///
+    /// `known` must be a full 8-card hand; it stays with `seat`.
+    pub fn deal_around(known: &[Card], seat: usize, rng: &mut impl Rng) -> [Vec<Card>; 4] {
+        let mut unseen: Vec<Card> = Deck::new()
+            .cards
+            .into_iter()
+            .filter(|card| !known.contains(card))
+            .collect();
+        unseen.shuffle(rng);
+
+        let mut others = unseen.chunks(8).map(|chunk| chunk.to_vec());
+        [0, 1, 2, 3].map(|position| {
+            if position == seat {
+                known.to_vec()
+            } else {
+                others.next().unwrap_or_default()
+            }
+        })
     }
 
     /// Get statistics about trump distribution in a set of hands
@@ -183,6 +271,78 @@ impl Default for Deck {
     }
 }
 
+/// Seed and redeal count behind a set of dealt hands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealRecord {
+    pub seed: u64,
+    pub attempts: u32,
+    /// Fewest trumps someone had to hold for the deal to stand
+    #[serde(default = "default_min_bid")]
+    pub min_bid: u8,
+    /// Random hex salt mixed into the commitment so the seed can't be brute-forced from it
+    #[serde(default)]
+    pub salt: String,
+    /// Hash commitment published before bidding starts, kept exactly as it was sent
+    /// so the revealed values are checked against it rather than against themselves
+    #[serde(default)]
+    pub commitment: String,
+}
+
+impl DealRecord {
+    /// Regenerate the exact hands this record was dealt with
+    pub fn regenerate_hands(&self) -> [Vec<Card>; 4] {
+        Deck::deal_until_valid_seeded(self.seed, self.min_bid).0
+    }
+
+    /// Card codes of the deck the hands were dealt from
+    pub fn deck_order(&self) -> Vec<String> {
+        Deck::shuffle_until_valid_seeded(self.seed, self.min_bid)
+            .0
+            .to_codes()
+    }
+}
+
+/// Records from before the minimum bid was stored were dealt under the standard rules
+fn default_min_bid() -> u8 {
+    RuleSet::standard().min_bid
+}
+
+/// SHA-256 (hex) of `salt:seed:card,card,...`
+///
+/// Clients can recompute this from the revealed values and compare it with
+/// the commitment they were sent before bidding.
+pub fn deal_commitment(salt: &str, seed: u64, deck_order: &[String]) -> String {
+    let preimage = format!("{}:{}:{}", salt, seed, deck_order.join(","));
+    Sha256::digest(preimage.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Check a revealed deal against its commitment and return the hands it deals
+///
+/// The deck order must hash to the commitment and must be exactly what the
+/// seed shuffles to under the match's `min_bid`, so the server can't pick the
+/// order after the fact.
+pub fn verify_deal(
+    commitment: &str,
+    salt: &str,
+    seed: u64,
+    min_bid: u8,
+    deck_order: &[String],
+) -> Result<[Vec<Card>; 4], String> {
+    if deal_commitment(salt, seed, deck_order) != commitment {
+        return Err("Revealed deal does not match the commitment".to_string());
+    }
+
+    let seeded_order = Deck::shuffle_until_valid_seeded(seed, min_bid).0.to_codes();
+    if seeded_order != deck_order {
+        return Err("Deck order was not produced by the revealed seed".to_string());
+    }
+
+    Deck::from_codes(deck_order)?.deal()
+}
+
 /// Analysis of dealt hands
 #[derive(Debug)]
 pub struct HandAnalysis {
@@ -322,16 +482,19 @@ mod tests {
             ],
         ];
 
-        assert!(Deck::has_valid_hands(&hands), "Should detect valid hands");
+        assert!(
+            Deck::has_valid_hands(&hands, 5),
+            "Should detect valid hands"
+        );
     }
 
     #[test]
     fn test_deal_until_valid() {
         // This test might take a moment but should always succeed
-        let hands = Deck::deal_until_valid();
+        let hands = Deck::deal_until_valid(5);
 
         // Verify we got valid hands
-        assert!(Deck::has_valid_hands(&hands));
+        assert!(Deck::has_valid_hands(&hands, 5));
 
         // Verify structure is correct
         assert_eq!(hands.len(), 4);
@@ -340,6 +503,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deal_follows_min_bid() {
+        let (hands, attempts) = Deck::deal_until_valid_seeded(9, 7);
+        assert!(Deck::has_valid_hands(&hands, 7));
+        // A stricter minimum never stops earlier in the same seeded stream
+        assert!(attempts >= Deck::deal_until_valid_seeded(9, 5).1);
+
+        let (hands, record) = Deck::deal_recorded(7);
+        assert_eq!(record.min_bid, 7);
+        assert!(Deck::has_valid_hands(&hands, 7));
+        assert_eq!(record.regenerate_hands(), hands);
+    }
+
+    #[test]
+    fn test_seeded_shuffle_is_reproducible() {
+        let mut first = Deck::new();
+        let mut second = Deck::new();
+        first.shuffle_with_seed(42);
+        second.shuffle_with_seed(42);
+        assert_eq!(first.cards, second.cards);
+
+        let mut other = Deck::new();
+        other.shuffle_with_seed(43);
+        assert_ne!(first.cards, other.cards);
+    }
+
+    #[test]
+    fn test_deal_record_regenerates_hands() {
+        let (hands, record) = Deck::deal_recorded(5);
+        assert!(record.attempts >= 1);
+        assert_eq!(record.regenerate_hands(), hands);
+
+        let (seeded_hands, attempts) = Deck::deal_until_valid_seeded(record.seed, 5);
+        assert_eq!(seeded_hands, hands);
+        assert_eq!(attempts, record.attempts);
+    }
+
+    #[test]
+    fn test_deal_commitment_verifies() {
+        let (hands, record) = Deck::deal_recorded(5);
+        let commitment = record.commitment.clone();
+        let order = record.deck_order();
+        assert_eq!(
+            commitment,
+            deal_commitment(&record.salt, record.seed, &order)
+        );
+
+        let verified = verify_deal(&commitment, &record.salt, record.seed, 5, &order).unwrap();
+        assert_eq!(verified, hands);
+
+        // Any change to the revealed values breaks the commitment
+        assert!(verify_deal(&commitment, "other salt", record.seed, 5, &order).is_err());
+        assert!(verify_deal(&commitment, &record.salt, record.seed + 1, 5, &order).is_err());
+
+        let mut swapped = order.clone();
+        swapped.swap(0, 1);
+        assert!(verify_deal(&commitment, &record.salt, record.seed, 5, &swapped).is_err());
+    }
+
+    #[test]
+    fn test_deal_around_keeps_known_hand() {
+        let known = Deck::new().deal().unwrap()[2].clone();
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let hands = Deck::deal_around(&known, 2, &mut rng);
+
+        assert_eq!(hands[2], known);
+        let mut all: Vec<String> = hands.iter().flatten().map(|card| card.code()).collect();
+        assert!(hands.iter().all(|hand| hand.len() == 8));
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 32);
+    }
+
     #[test]
     fn test_hand_analysis() {
         let hands = [
@@ -0,0 +1,709 @@
+use crate::game::card::{Card, Suit};
+use crate::game::hand::{BidOption, Hand};
+use crate::game::rules::RuleSet;
+use crate::game::scoring::{GameResult, SjavsScoring};
+use crate::game::trick::GameTrickState;
+use crate::game::violation::RuleViolation;
+use serde::{Deserialize, Serialize};
+
+/// Valid trump suit names used throughout bidding
+const TRUMP_SUITS: [&str; 4] = ["hearts", "diamonds", "clubs", "spades"];
+
+/// Phase of a single Sjavs game (one deal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamePhase {
+    /// Players are bidding for trump
+    Bidding,
+    /// Everyone passed - the same dealer must deal again
+    Redeal,
+    /// Tricks are being played
+    Playing,
+    /// All 8 tricks are played and the game is scored
+    Complete,
+}
+
+/// A bid for trump
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bid {
+    pub player: usize,
+    pub length: u8,
+    pub suit: String,
+}
+
+/// Actions a player can take in a game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameAction {
+    Bid {
+        player: usize,
+        length: u8,
+        suit: String,
+    },
+    Pass {
+        player: usize,
+    },
+    PlayCard {
+        player: usize,
+        card: Card,
+    },
+}
+
+impl GameAction {
+    /// Seat of the player taking the action
+    pub fn player(&self) -> usize {
+        match self {
+            GameAction::Bid { player, .. }
+            | GameAction::Pass { player }
+            | GameAction::PlayCard { player, .. } => *player,
+        }
+    }
+}
+
+/// Events produced by applying an action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GameEvent {
+    BidMade {
+        player: usize,
+        length: u8,
+        suit: String,
+        next_bidder: usize,
+    },
+    PassMade {
+        player: usize,
+        next_bidder: usize,
+    },
+    /// Nobody bid - the hands must be redealt by the same dealer
+    AllPassed {
+        dealer: usize,
+    },
+    BiddingComplete {
+        declarer: usize,
+        trump_suit: String,
+        bid_length: u8,
+        leader: usize,
+    },
+    CardPlayed {
+        player: usize,
+        card: Card,
+        next_player: Option<usize>,
+    },
+    TrickCompleted {
+        trick_number: u8,
+        winner: usize,
+        points: u8,
+        trump_team_won: bool,
+    },
+    GameComplete {
+        scoring: SjavsScoring,
+        result: GameResult,
+    },
+}
+
+/// A complete Sjavs game: hands, bidding, tricks and scoring without any I/O
+///
+/// Callers load the stored state into a `SjavsGame`, `apply` a `GameAction`
+/// and persist whatever changed. Every rule lives here so it can be tested
+/// and reused by bots and simulations without Redis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SjavsGame {
+    pub game_id: String,
+    pub dealer: usize,
+    pub hands: [Hand; 4],
+    pub phase: GamePhase,
+    /// Player whose turn it is to bid (bidding phase only)
+    pub current_bidder: Option<usize>,
+    /// Best bid so far, which becomes the contract when bidding ends
+    pub highest_bid: Option<Bid>,
+    /// Trick play state, created once bidding completes
+    pub tricks: Option<GameTrickState>,
+    /// House rules for bidding and scoring
+    #[serde(default)]
+    pub rules: RuleSet,
+}
+
+impl SjavsGame {
+    /// Start bidding on a freshly dealt set of hands
+    pub fn new(game_id: String, dealer: usize, hands: [Hand; 4]) -> Self {
+        Self {
+            game_id,
+            dealer,
+            hands,
+            phase: GamePhase::Bidding,
+            current_bidder: Some((dealer + 1) % 4), // Left of dealer bids first
+            highest_bid: None,
+            tricks: None,
+            rules: RuleSet::standard(),
+        }
+    }
+
+    /// Play the game with the given house rules instead of the standard ones
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Player expected to act next, if any
+    pub fn current_player(&self) -> Option<usize> {
+        match self.phase {
+            GamePhase::Bidding => self.current_bidder,
+            GamePhase::Playing => self
+                .tricks
+                .as_ref()
+                .filter(|tricks| !tricks.current_trick.is_complete)
+                .map(|tricks| tricks.current_trick.current_player),
+            GamePhase::Redeal | GamePhase::Complete => None,
+        }
+    }
+
+    /// Trump suit of the game once bidding is complete
+    pub fn trump_suit(&self) -> Option<&str> {
+        self.tricks
+            .as_ref()
+            .map(|tricks| tricks.current_trick.trump_suit.as_str())
+    }
+
+    /// Bids the player could make right now
+    pub fn available_bids(&self, player: usize) -> Vec<BidOption> {
+        if self.phase != GamePhase::Bidding || self.current_bidder != Some(player) {
+            return vec![];
+        }
+
+        let current_highest = self.highest_bid.as_ref().map(|bid| bid.length);
+        self.hands[player]
+            .get_available_bids(current_highest, self.rules.min_bid)
+            .into_iter()
+            .filter(|option| self.check_bid(player, option.length, &option.suit).is_ok())
+            .collect()
+    }
+
+    /// Cards the player may legally play right now
+    pub fn legal_cards(&self, player: usize) -> Vec<Card> {
+        match (&self.tricks, self.current_player()) {
+            (Some(tricks), Some(current))
+                if self.phase == GamePhase::Playing && current == player =>
+            {
+                tricks
+                    .current_trick
+                    .get_legal_cards(&self.hands[player].cards)
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Apply an action, returning the resulting events or the rule it breaks
+    pub fn apply(&mut self, action: GameAction) -> Result<Vec<GameEvent>, RuleViolation> {
+        if action.player() > 3 {
+            return Err(RuleViolation::InvalidPlayer {
+                player: action.player(),
+            });
+        }
+
+        match action {
+            GameAction::Bid {
+                player,
+                length,
+                suit,
+            } => self.bid(player, length, suit),
+            GameAction::Pass { player } => self.pass(player),
+            GameAction::PlayCard { player, card } => self.play_card(player, card),
+        }
+    }
+
+    /// Validate a bid without applying it
+    pub fn check_bid(&self, player: usize, length: u8, suit: &str) -> Result<(), RuleViolation> {
+        self.check_bidding_turn(player)?;
+
+        // Bid length must be between the rule set's minimum and 8
+        if !(self.rules.min_bid..=8).contains(&length) {
+            return Err(RuleViolation::BidOutOfRange {
+                min: self.rules.min_bid,
+                max: 8,
+            });
+        }
+
+        if !TRUMP_SUITS.contains(&suit) {
+            return Err(RuleViolation::UnknownTrumpSuit {
+                suit: suit.to_string(),
+            });
+        }
+
+        // Player must actually hold the trumps they bid
+        let trump_count = self.hands[player].card_set().trump_count(Suit::from(suit));
+        if trump_count < length {
+            return Err(RuleViolation::NotEnoughTrumps {
+                suit: suit.to_string(),
+                held: trump_count,
+                bid: length,
+            });
+        }
+
+        // Bid must beat the current highest bid
+        if let Some(highest) = &self.highest_bid {
+            if length == highest.length {
+                // Same length only wins if it's clubs and current isn't clubs
+                if suit != "clubs" {
+                    return Err(RuleViolation::ClubPreferenceOnly {
+                        length: highest.length,
+                    });
+                }
+                if highest.suit == "clubs" {
+                    return Err(RuleViolation::BidTooLow {
+                        min: highest.length + 1,
+                    });
+                }
+            } else if length < highest.length {
+                return Err(RuleViolation::BidTooLow {
+                    min: highest.length + 1,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that it's the player's turn to bid or pass
+    fn check_bidding_turn(&self, player: usize) -> Result<(), RuleViolation> {
+        if self.phase != GamePhase::Bidding {
+            return Err(RuleViolation::WrongPhase {
+                expected: GamePhase::Bidding,
+                actual: self.phase,
+            });
+        }
+
+        if self.current_bidder != Some(player) {
+            return Err(RuleViolation::NotYourTurn {
+                current_player: self.current_bidder,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn bid(
+        &mut self,
+        player: usize,
+        length: u8,
+        suit: String,
+    ) -> Result<Vec<GameEvent>, RuleViolation> {
+        self.check_bid(player, length, &suit)?;
+
+        let next_bidder = (player + 1) % 4;
+        self.highest_bid = Some(Bid {
+            player,
+            length,
+            suit: suit.clone(),
+        });
+        self.current_bidder = Some(next_bidder);
+
+        Ok(vec![GameEvent::BidMade {
+            player,
+            length,
+            suit,
+            next_bidder,
+        }])
+    }
+
+    fn pass(&mut self, player: usize) -> Result<Vec<GameEvent>, RuleViolation> {
+        self.check_bidding_turn(player)?;
+
+        let next_bidder = (player + 1) % 4;
+        self.current_bidder = Some(next_bidder);
+        let mut events = vec![GameEvent::PassMade {
+            player,
+            next_bidder,
+        }];
+
+        match self.highest_bid.clone() {
+            // Full circle without a bid - everyone passed
+            None if next_bidder == (self.dealer + 1) % 4 => {
+                self.phase = GamePhase::Redeal;
+                self.current_bidder = None;
+                events.push(GameEvent::AllPassed {
+                    dealer: self.dealer,
+                });
+            }
+            // Back round to the player after the highest bidder - bidding is over
+            Some(bid) if next_bidder == (bid.player + 1) % 4 => {
+                events.push(self.finish_bidding(bid));
+            }
+            _ => {}
+        }
+
+        Ok(events)
+    }
+
+    /// Skip bidding and start trick play with `bid` as the contract
+    ///
+    /// For evaluating contracts and setting up positions; the bid isn't checked.
+    pub fn with_contract(mut self, bid: Bid) -> Self {
+        self.highest_bid = Some(bid.clone());
+        self.finish_bidding(bid);
+        self
+    }
+
+    /// Turn the winning bid into the contract and start trick play
+    fn finish_bidding(&mut self, bid: Bid) -> GameEvent {
+        let leader = (self.dealer + 1) % 4; // Left of dealer leads
+        let trump_team = (bid.player, (bid.player + 2) % 4); // Declarer + partner opposite
+
+        self.tricks = Some(GameTrickState::new(
+            self.game_id.clone(),
+            leader,
+            bid.suit.clone(),
+            trump_team,
+        ));
+        self.phase = GamePhase::Playing;
+        self.current_bidder = None;
+
+        GameEvent::BiddingComplete {
+            declarer: bid.player,
+            trump_suit: bid.suit,
+            bid_length: bid.length,
+            leader,
+        }
+    }
+
+    /// Return the last card played to the open trick to the player who played it
+    ///
+    /// Only possible before the next card lands; a card that finished a trick
+    /// can't be taken back.
+    pub fn take_back(&mut self, player: usize) -> Result<Card, String> {
+        if self.phase != GamePhase::Playing {
+            return Err("Game is not in playing phase".to_string());
+        }
+
+        let tricks = self.tricks.as_mut().ok_or("Trick state not initialized")?;
+
+        match tricks.current_trick.cards_played.last() {
+            Some((last, _)) if *last == player => {}
+            Some(_) => return Err("Only the last card played can be taken back".to_string()),
+            None => return Err("No card in the current trick to take back".to_string()),
+        }
+
+        let (_, card) = tricks.current_trick.take_back_last_card()?;
+        self.hands[player].add_card(card);
+        Ok(card)
+    }
+
+    fn play_card(&mut self, player: usize, card: Card) -> Result<Vec<GameEvent>, RuleViolation> {
+        if self.phase != GamePhase::Playing {
+            return Err(RuleViolation::WrongPhase {
+                expected: GamePhase::Playing,
+                actual: self.phase,
+            });
+        }
+
+        let tricks = self
+            .tricks
+            .as_mut()
+            .ok_or_else(|| RuleViolation::internal("Trick state not initialized"))?;
+
+        if tricks.current_trick.current_player != player {
+            return Err(RuleViolation::NotYourTurn {
+                current_player: Some(tricks.current_trick.current_player),
+            });
+        }
+
+        let hand = &mut self.hands[player];
+        if !hand.has_card(&card) {
+            return Err(RuleViolation::CardNotInHand { card });
+        }
+
+        let legal_cards = hand.card_set().legal_plays(tricks.current_trick.lead_suit);
+        if !legal_cards.contains(card) {
+            let lead_suit = tricks
+                .current_trick
+                .lead_suit
+                .ok_or_else(|| RuleViolation::internal("Card refused without a lead suit"))?;
+            let trump_suit = &tricks.current_trick.trump_suit;
+            let trump = Suit::from(trump_suit.as_str());
+            // Name the rule after the card that was led: a trump lead calls for trump,
+            // unless the refused card is a trump itself and only the suit was missed
+            let trump_led = tricks
+                .current_trick
+                .cards_played
+                .first()
+                .is_some_and(|(_, lead)| lead.is_trump(trump));
+            return Err(if trump_led && !card.is_trump(trump) {
+                RuleViolation::MustPlayTrump {
+                    trump_suit: trump_suit.clone(),
+                }
+            } else {
+                RuleViolation::MustFollowSuit { lead_suit }
+            });
+        }
+
+        tricks.current_trick.play_card(player, card)?;
+        hand.remove_card(&card);
+
+        let trick_complete = tricks.current_trick.is_complete;
+        let mut events = vec![GameEvent::CardPlayed {
+            player,
+            card,
+            next_player: if trick_complete {
+                None
+            } else {
+                Some(tricks.current_trick.current_player)
+            },
+        }];
+
+        if !trick_complete {
+            return Ok(events);
+        }
+
+        let trick_number = tricks.current_trick.trick_number;
+        let completion = tricks.complete_trick().map_err(RuleViolation::internal)?;
+        events.push(GameEvent::TrickCompleted {
+            trick_number,
+            winner: completion.winner,
+            points: completion.points,
+            trump_team_won: completion.trump_team_won,
+        });
+
+        if completion.game_complete {
+            let scoring = tricks
+                .get_final_scoring()
+                .map_err(RuleViolation::internal)?;
+            let result = scoring.calculate_game_result(&self.rules);
+            self.phase = GamePhase::Complete;
+            events.push(GameEvent::GameComplete { scoring, result });
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card::{Rank, Suit};
+    use crate::game::deck::Deck;
+
+    /// Unshuffled deal: seat 0 holds all four jacks plus the 7 of every suit
+    fn unshuffled_game(dealer: usize) -> SjavsGame {
+        let hands = Deck::new().deal().unwrap();
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        SjavsGame::new("test_game".to_string(), dealer, hands)
+    }
+
+    fn bid(player: usize, length: u8, suit: &str) -> GameAction {
+        GameAction::Bid {
+            player,
+            length,
+            suit: suit.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_bidding_turn_and_trump_count() {
+        let mut game = unshuffled_game(3);
+
+        // Seat 0 bids first (left of dealer 3)
+        assert!(game.apply(GameAction::Pass { player: 1 }).is_err());
+        assert!(game.apply(bid(0, 6, "hearts")).is_err()); // Only 5 hearts trumps
+
+        let events = game.apply(bid(0, 5, "hearts")).unwrap();
+        assert!(matches!(
+            events[0],
+            GameEvent::BidMade { next_bidder: 1, .. }
+        ));
+        assert_eq!(game.current_player(), Some(1));
+    }
+
+    #[test]
+    fn test_club_preference() {
+        let mut game = unshuffled_game(3);
+        game.hands[1] = game.hands[0].clone(); // Give seat 1 the same trumps
+
+        game.apply(bid(0, 5, "hearts")).unwrap();
+        assert!(game.apply(bid(1, 5, "spades")).is_err());
+        assert!(game.apply(bid(1, 5, "clubs")).is_ok());
+    }
+
+    #[test]
+    fn test_rule_set_minimum_bid() {
+        let mut rules = RuleSet::standard();
+        rules.min_bid = 6;
+        let mut game = unshuffled_game(3).with_rules(rules);
+
+        // Seat 0 holds 5 hearts trumps - enough by default but not under these rules
+        assert!(game.available_bids(0).is_empty());
+        assert!(game.apply(bid(0, 5, "hearts")).is_err());
+        assert!(game.apply(GameAction::Pass { player: 0 }).is_ok());
+    }
+
+    #[test]
+    fn test_all_pass_requires_redeal() {
+        let mut game = unshuffled_game(0);
+
+        for player in [1, 2, 3] {
+            game.apply(GameAction::Pass { player }).unwrap();
+        }
+        let events = game.apply(GameAction::Pass { player: 0 }).unwrap();
+
+        assert!(matches!(events[1], GameEvent::AllPassed { dealer: 0 }));
+        assert_eq!(game.phase, GamePhase::Redeal);
+        assert_eq!(game.current_player(), None);
+    }
+
+    #[test]
+    fn test_full_game_to_completion() {
+        let mut game = unshuffled_game(3);
+
+        game.apply(bid(0, 5, "clubs")).unwrap();
+        for player in [1, 2, 3] {
+            game.apply(GameAction::Pass { player }).unwrap();
+        }
+        let events = game.apply(GameAction::Pass { player: 0 }).unwrap();
+        assert!(matches!(
+            events[1],
+            GameEvent::BiddingComplete {
+                declarer: 0,
+                leader: 0,
+                ..
+            }
+        ));
+        assert_eq!(game.phase, GamePhase::Playing);
+        assert_eq!(game.trump_suit(), Some("clubs"));
+
+        // Can't play a card that isn't in hand
+        let not_held = Card::new(Suit::Hearts, Rank::Ace);
+        assert!(game
+            .apply(GameAction::PlayCard {
+                player: 0,
+                card: not_held
+            })
+            .is_err());
+
+        let mut tricks_completed = 0;
+        let mut final_result = None;
+        while let Some(player) = game.current_player() {
+            let card = game.legal_cards(player)[0];
+            for event in game.apply(GameAction::PlayCard { player, card }).unwrap() {
+                match event {
+                    GameEvent::TrickCompleted { .. } => tricks_completed += 1,
+                    GameEvent::GameComplete { scoring, result } => {
+                        final_result = Some((scoring, result))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        assert_eq!(tricks_completed, 8);
+        assert_eq!(game.phase, GamePhase::Complete);
+        assert!(game.hands.iter().all(|hand| hand.cards.is_empty()));
+
+        let (scoring, _result) = final_result.expect("game should be scored");
+        assert!(scoring.validate_total_points());
+    }
+
+    #[test]
+    fn test_rule_violations() {
+        let mut game = unshuffled_game(3);
+        game.hands[1] = game.hands[0].clone();
+
+        assert_eq!(
+            game.apply(GameAction::Pass { player: 1 }).unwrap_err(),
+            RuleViolation::NotYourTurn {
+                current_player: Some(0)
+            }
+        );
+        assert_eq!(
+            game.apply(bid(0, 4, "hearts")).unwrap_err(),
+            RuleViolation::BidOutOfRange { min: 5, max: 8 }
+        );
+        assert_eq!(
+            game.apply(bid(0, 6, "hearts")).unwrap_err(),
+            RuleViolation::NotEnoughTrumps {
+                suit: "hearts".to_string(),
+                held: 5,
+                bid: 6
+            }
+        );
+        game.apply(bid(0, 5, "clubs")).unwrap();
+        assert_eq!(
+            game.apply(bid(1, 5, "spades")).unwrap_err(),
+            RuleViolation::ClubPreferenceOnly { length: 5 }
+        );
+        assert_eq!(
+            game.apply(bid(1, 5, "clubs")).unwrap_err(),
+            RuleViolation::BidTooLow { min: 6 }
+        );
+
+        for player in [1, 2, 3] {
+            game.apply(GameAction::Pass { player }).unwrap();
+        }
+        game.apply(GameAction::Pass { player: 0 }).unwrap();
+
+        // Trump led: seat 1 must answer with trump, and with a club while holding one
+        game.apply(GameAction::PlayCard {
+            player: 0,
+            card: Card::new(Suit::Clubs, Rank::Seven),
+        })
+        .unwrap();
+        assert_eq!(
+            game.apply(GameAction::PlayCard {
+                player: 1,
+                card: Card::new(Suit::Hearts, Rank::Seven),
+            })
+            .unwrap_err(),
+            RuleViolation::MustPlayTrump {
+                trump_suit: "clubs".to_string()
+            }
+        );
+        assert_eq!(
+            game.apply(GameAction::PlayCard {
+                player: 1,
+                card: Card::new(Suit::Hearts, Rank::Jack),
+            })
+            .unwrap_err(),
+            RuleViolation::MustFollowSuit {
+                lead_suit: Suit::Clubs
+            }
+        );
+        assert_eq!(
+            game.apply(GameAction::Pass { player: 1 }).unwrap_err(),
+            RuleViolation::WrongPhase {
+                expected: GamePhase::Bidding,
+                actual: GamePhase::Playing
+            }
+        );
+    }
+
+    #[test]
+    fn test_take_back_last_card() {
+        let mut game = unshuffled_game(3);
+        game.apply(bid(0, 5, "clubs")).unwrap();
+        for player in [1, 2, 3, 0] {
+            game.apply(GameAction::Pass { player }).unwrap();
+        }
+        assert!(game.take_back(0).is_err()); // Nothing played yet
+
+        let lead = game.legal_cards(0)[0];
+        game.apply(GameAction::PlayCard {
+            player: 0,
+            card: lead,
+        })
+        .unwrap();
+        let follow = game.legal_cards(1)[0];
+        game.apply(GameAction::PlayCard {
+            player: 1,
+            card: follow,
+        })
+        .unwrap();
+
+        // Only the last card can come back
+        assert!(game.take_back(0).is_err());
+        assert_eq!(game.take_back(1).unwrap(), follow);
+        assert_eq!(game.current_player(), Some(1));
+        assert!(game.hands[1].has_card(&follow));
+        assert_eq!(game.hands[1].cards.len(), 8);
+
+        // Taking back the lead card opens the trick again
+        assert_eq!(game.take_back(0).unwrap(), lead);
+        let trick = &game.tricks.as_ref().unwrap().current_trick;
+        assert!(trick.cards_played.is_empty());
+        assert_eq!(trick.lead_suit, None);
+        assert_eq!(game.current_player(), Some(0));
+    }
+}
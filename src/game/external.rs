@@ -0,0 +1,202 @@
+//! External bot engines over a line-based stdin/stdout protocol
+//!
+//! Modelled on UCI: the engine runs as a child process, is told the position
+//! from its seat and asked for a move. Cards are written as codes (`QC`, `10H`)
+//! and bids as in Sjavs Game Notation (`5 hearts`, `pass`). Every line ends with
+//! a newline; engines must flush after writing.
+//!
+//! ```text
+//! server: sjavs
+//! engine: id name Greedy          (optional, any number of id lines)
+//! engine: sjavsok
+//! server: position seat 1 dealer 0
+//! server: hand QC JS 10H 9H 8D 7D AS KS
+//! server: bid 1 pass              (one line per bid or pass so far)
+//! server: bid 2 5 hearts
+//! server: contract 2 5 hearts     (declarer, length, suit; once bidding is over)
+//! server: trick 3 7H 8H 9H KH     (leader, then cards in play order)
+//! server: trick 2 AS
+//! server: go play KS              (legal cards, or `go bid pass,6 hearts,6 clubs`)
+//! engine: info thinking...        (ignored)
+//! engine: move KS                 (or `move pass`, `move 6 hearts`)
+//! server: quit
+//! ```
+//!
+//! A move that isn't in the `go` list is answered with `illegal <move>`.
+//!
+//! Only the protocol lives here. Starting engines and keeping them running is
+//! left to the program, which hands its runner to `BotDifficulty::choose_action`.
+
+use crate::game::card::Card;
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+
+/// Asks the named engine playing a seat for its action: `(engine, game, player, bidding)`
+pub type EngineRunner = fn(&str, &SjavsGame, usize, &[GameAction]) -> Result<GameAction, String>;
+
+/// The position as `player` sees it: own hand, bidding, contract and tricks
+pub fn position_lines(game: &SjavsGame, player: usize, bidding: &[GameAction]) -> Vec<String> {
+    let mut lines = vec![
+        format!("position seat {} dealer {}", player, game.dealer),
+        format!("hand {}", codes(&game.hands[player].cards)),
+    ];
+
+    for action in bidding {
+        match action {
+            GameAction::Bid {
+                player,
+                length,
+                suit,
+            } => lines.push(format!("bid {} {} {}", player, length, suit)),
+            GameAction::Pass { player } => lines.push(format!("bid {} pass", player)),
+            GameAction::PlayCard { .. } => {}
+        }
+    }
+
+    if let (Some(bid), Some(tricks)) = (&game.highest_bid, &game.tricks) {
+        lines.push(format!(
+            "contract {} {} {}",
+            bid.player, bid.length, bid.suit
+        ));
+
+        let current =
+            (!tricks.current_trick.cards_played.is_empty()).then_some(&tricks.current_trick);
+        for trick in tricks.completed_tricks.iter().chain(current) {
+            let Some((leader, _)) = trick.cards_played.first() else {
+                continue;
+            };
+            let cards: Vec<Card> = trick.cards_played.iter().map(|(_, card)| *card).collect();
+            lines.push(format!("trick {} {}", leader, codes(&cards)));
+        }
+    }
+
+    lines
+}
+
+/// `go bid pass,5 hearts` or `go play AS KS`
+pub fn go_line(phase: GamePhase, legal: &[GameAction]) -> String {
+    if phase == GamePhase::Bidding {
+        let bids: Vec<String> = legal.iter().map(move_text).collect();
+        format!("go bid {}", bids.join(","))
+    } else {
+        let cards: Vec<String> = legal.iter().map(move_text).collect();
+        format!("go play {}", cards.join(" "))
+    }
+}
+
+/// How an action is written after `move`
+pub fn move_text(action: &GameAction) -> String {
+    match action {
+        GameAction::Bid { length, suit, .. } => format!("{} {}", length, suit),
+        GameAction::Pass { .. } => "pass".to_string(),
+        GameAction::PlayCard { card, .. } => card.code(),
+    }
+}
+
+/// Read `pass`, `6 hearts` or a card code as an action for `player`
+pub fn parse_move(text: &str, player: usize) -> Result<GameAction, String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    match words.as_slice() {
+        ["pass"] => Ok(GameAction::Pass { player }),
+        [length, suit] => Ok(GameAction::Bid {
+            player,
+            length: length
+                .parse()
+                .map_err(|_| format!("'{}' is not a bid length", length))?,
+            suit: suit.to_string(),
+        }),
+        [code] => Ok(GameAction::PlayCard {
+            player,
+            card: Card::from_code(code)?,
+        }),
+        _ => Err(format!("'{}' is not a move", text)),
+    }
+}
+
+fn codes(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|card| card.code())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::deck::Deck;
+    use crate::game::hand::Hand;
+    use crate::game::mcts;
+
+    fn seeded_game(seed: u64) -> SjavsGame {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        SjavsGame::new("external_game".to_string(), 3, hands)
+    }
+
+    #[test]
+    fn test_moves_round_trip() {
+        let actions = [
+            GameAction::Pass { player: 2 },
+            GameAction::Bid {
+                player: 2,
+                length: 6,
+                suit: "clubs".to_string(),
+            },
+            GameAction::PlayCard {
+                player: 2,
+                card: Card::from_code("10H").unwrap(),
+            },
+        ];
+        for action in actions {
+            assert_eq!(parse_move(&move_text(&action), 2).unwrap(), action);
+        }
+        assert!(parse_move("6 hearts now", 2).is_err());
+        assert!(parse_move("x hearts", 2).is_err());
+    }
+
+    #[test]
+    fn test_position_describes_tricks() {
+        let mut game = seeded_game(1);
+        let mut bidding = Vec::new();
+        while game.phase == GamePhase::Bidding {
+            let player = game.current_player().unwrap();
+            let action = RuleBot::choose_action(&game, player).unwrap();
+            bidding.push(action.clone());
+            game.apply(action).unwrap();
+        }
+        assert_eq!(game.phase, GamePhase::Playing);
+        for _ in 0..5 {
+            let player = game.current_player().unwrap();
+            let action = RuleBot::choose_action(&game, player).unwrap();
+            game.apply(action).unwrap();
+        }
+
+        let player = game.current_player().unwrap();
+        let lines = position_lines(&game, player, &bidding);
+        assert_eq!(lines[0], format!("position seat {} dealer 3", player));
+        assert!(lines[1].starts_with("hand "));
+        assert_eq!(
+            lines.iter().filter(|line| line.starts_with("bid ")).count(),
+            bidding.len()
+        );
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|line| line.starts_with("contract "))
+                .count(),
+            1
+        );
+
+        let tricks: Vec<&String> = lines
+            .iter()
+            .filter(|line| line.starts_with("trick "))
+            .collect();
+        assert_eq!(tricks.len(), 2);
+        assert_eq!(tricks[0].split_whitespace().count(), 6); // trick, leader and 4 cards
+        assert_eq!(tricks[1].split_whitespace().count(), 3);
+
+        let go = go_line(game.phase, &mcts::legal_actions(&game));
+        assert!(go.starts_with("go play "));
+    }
+}
@@ -1,7 +1,17 @@
-use super::card::{Card, Suit};
+use super::advisor::{BiddingAdvice, BiddingAdvisor};
+use super::card::{Card, CardSet, Suit};
+use super::rules::RuleSet;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Trump suits by name, in the order bids are offered
+const TRUMP_SUITS: [(&str, Suit); 4] = [
+    ("hearts", Suit::Hearts),
+    ("diamonds", Suit::Diamonds),
+    ("clubs", Suit::Clubs),
+    ("spades", Suit::Spades),
+];
+
 /// A player's hand with bidding functionality
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hand {
@@ -18,13 +28,17 @@ impl Hand {
     }
 
     /// Get available trump bids for this hand based on current game state
-    pub fn get_available_bids(&self, current_highest: Option<u8>) -> Vec<BidOption> {
-        let trump_counts = self.calculate_trump_counts();
+    ///
+    /// `min_bid` is the fewest trumps the match's rules allow as an opening bid.
+    pub fn get_available_bids(&self, current_highest: Option<u8>, min_bid: u8) -> Vec<BidOption> {
+        let cards = self.card_set();
         let mut bids = Vec::new();
 
-        let min_bid = current_highest.map(|h| h + 1).unwrap_or(5);
+        let min_bid = current_highest.map(|h| h + 1).unwrap_or(min_bid);
 
-        for (suit_name, &count) in &trump_counts {
+        // Fixed suit order keeps equal-length bids in the same order every time
+        for (suit_name, suit) in TRUMP_SUITS {
+            let count = cards.trump_count(suit);
             if count >= min_bid {
                 // Can bid this length or higher
                 for bid_length in min_bid..=count {
@@ -32,10 +46,10 @@ impl Hand {
                         length: bid_length,
                         suit: suit_name.to_string(),
                         display_text: format!("{} trumps ({})", bid_length, suit_name),
-                        is_club_declaration: *suit_name == "clubs",
+                        is_club_declaration: suit_name == "clubs",
                     });
                 }
-            } else if count == current_highest.unwrap_or(0) && *suit_name == "clubs" {
+            } else if count == current_highest.unwrap_or(0) && suit_name == "clubs" {
                 // Can match current bid if we have clubs (club preference rule)
                 bids.push(BidOption {
                     length: count,
@@ -64,16 +78,11 @@ impl Hand {
 
     /// Calculate trump counts for all suits for this hand
     pub fn calculate_trump_counts(&self) -> HashMap<String, u8> {
+        let cards = self.card_set();
         let mut counts = HashMap::new();
 
-        for (suit_name, suit) in [
-            ("hearts", Suit::Hearts),
-            ("diamonds", Suit::Diamonds),
-            ("clubs", Suit::Clubs),
-            ("spades", Suit::Spades),
-        ] {
-            let count = self.cards.iter().filter(|card| card.is_trump(suit)).count() as u8;
-            counts.insert(suit_name.to_string(), count);
+        for (suit_name, suit) in TRUMP_SUITS {
+            counts.insert(suit_name.to_string(), cards.trump_count(suit));
         }
 
         counts
@@ -108,6 +117,23 @@ impl Hand {
         })
     }
 
+    /// Sampled estimate of how each trump suit would play, and what to bid
+    ///
+    /// Unlike `get_best_bid` this looks at the whole hand, not just the trump
+    /// count. The deals are seeded from the hand itself, so asking again for
+    /// the same hand gives the same advice.
+    pub fn bidding_advice(
+        &self,
+        dealer: usize,
+        rules: &RuleSet,
+        current_highest: Option<u8>,
+        samples: u32,
+    ) -> BiddingAdvice {
+        let available = self.get_available_bids(current_highest, rules.min_bid);
+        let seed = self.card_set().bits() as u64;
+        BiddingAdvisor::advise(self, dealer, rules, &available, samples, seed)
+    }
+
     /// Check if hand has a specific card
     pub fn has_card(&self, card: &Card) -> bool {
         self.cards.contains(card)
@@ -156,6 +182,18 @@ impl Hand {
         Ok(hand)
     }
 
+    /// The hand as a bitset, for code that looks at many hands
+    pub fn card_set(&self) -> CardSet {
+        CardSet::from_cards(&self.cards)
+    }
+
+    /// Create a sorted hand from a bitset
+    pub fn from_card_set(cards: CardSet, player_position: usize) -> Self {
+        let mut hand = Self::new(cards.to_vec(), player_position);
+        hand.sort_cards();
+        hand
+    }
+
     /// Get total point value of cards in hand
     pub fn point_value(&self) -> u8 {
         self.cards.iter().map(|c| c.point_value()).sum()
@@ -168,28 +206,23 @@ impl Hand {
 
     /// Get cards that can legally be played given the current trick state
     pub fn get_playable_cards(&self, trump_suit: Suit, lead_suit: Option<Suit>) -> Vec<Card> {
-        match lead_suit {
-            None => {
-                // Can lead any card
-                self.cards.clone()
-            }
-            Some(lead) => {
-                // Must follow suit if possible
-                let following_cards: Vec<_> = self
-                    .cards
-                    .iter()
-                    .filter(|&card| card.suit == lead && !card.is_trump(trump_suit))
-                    .copied()
-                    .collect();
-
-                if !following_cards.is_empty() {
-                    following_cards
-                } else {
-                    // Can't follow suit, can play any card
-                    self.cards.clone()
-                }
-            }
-        }
+        let cards = self.card_set();
+
+        // Must follow suit if possible; trumps don't count as the lead suit
+        let following = lead_suit
+            .map(|lead| cards.of_suit(lead) - CardSet::trumps(trump_suit))
+            .unwrap_or_default();
+        let playable = if following.is_empty() {
+            cards
+        } else {
+            following
+        };
+
+        self.cards
+            .iter()
+            .filter(|&&card| playable.contains(card))
+            .copied()
+            .collect()
     }
 
     /// Get a summary of the hand for display
@@ -210,7 +243,7 @@ impl Hand {
 }
 
 /// A bid option available to a player
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BidOption {
     pub length: u8,
     pub suit: String,
@@ -270,7 +303,7 @@ mod tests {
         ];
 
         let hand = Hand::new(cards, 0);
-        let bids = hand.get_available_bids(None);
+        let bids = hand.get_available_bids(None, 5);
 
         // Should have bids for hearts (6 trumps)
         let heart_bids: Vec<_> = bids.iter().filter(|b| b.suit == "hearts").collect();
@@ -297,7 +330,7 @@ mod tests {
         let hand = Hand::new(cards, 0);
 
         // Can match bid of 5 with clubs even though we have exactly 5
-        let bids = hand.get_available_bids(Some(5));
+        let bids = hand.get_available_bids(Some(5), 5);
         let club_bids: Vec<_> = bids.iter().filter(|b| b.suit == "clubs").collect();
 
         assert!(!club_bids.is_empty());
@@ -364,6 +397,20 @@ mod tests {
         assert_eq!(restored_hand.player_position, 1);
     }
 
+    #[test]
+    fn test_card_set_conversion() {
+        let hand =
+            Hand::from_codes(vec!["AS".into(), "7H".into(), "QC".into(), "10D".into()], 2).unwrap();
+
+        let set = hand.card_set();
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.trump_counts(), [2, 2, 1, 2]);
+
+        let back = Hand::from_card_set(set, 2);
+        assert_eq!(back.cards, hand.cards);
+        assert_eq!(back.player_position, 2);
+    }
+
     #[test]
     fn test_hand_validation() {
         let valid_cards = vec![
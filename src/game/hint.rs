@@ -0,0 +1,184 @@
+use crate::game::card::{Card, Rank, Suit};
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+use crate::game::mcts::{MctsBot, MctsConfig};
+
+/// One legal card and how the search rates it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardHint {
+    pub card: Card,
+    /// Times the search chose the card
+    pub visits: u32,
+    /// Average playout reward for the player's team (0.0 - 1.0)
+    pub expected_reward: f64,
+    /// Why the card is worth playing, in a few words
+    pub reason: String,
+}
+
+/// Rank every card `player` may play, best first
+///
+/// Uses the same search as hard bots, which only deals out cards the player
+/// can't see, so a hint never gives away another player's hand.
+pub fn suggest_cards(
+    game: &SjavsGame,
+    player: usize,
+    config: &MctsConfig,
+) -> Result<Vec<CardHint>, String> {
+    if game.phase != GamePhase::Playing {
+        return Err("Game is not in playing phase".to_string());
+    }
+    if game.current_player() != Some(player) {
+        return Err("It is not your turn to play".to_string());
+    }
+
+    Ok(MctsBot::analyze(game, player, config)
+        .into_iter()
+        .filter_map(|evaluation| match evaluation.action {
+            GameAction::PlayCard { card, .. } => Some(CardHint {
+                card,
+                visits: evaluation.visits,
+                expected_reward: evaluation.expected_reward,
+                reason: reason(game, player, card),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Describe what playing `card` does to the trick in progress
+fn reason(game: &SjavsGame, player: usize, card: Card) -> String {
+    let Some(tricks) = game.tricks.as_ref() else {
+        return "Only legal card".to_string();
+    };
+    let trick = &tricks.current_trick;
+    let trump = Suit::from(trick.trump_suit.as_str());
+    let on_trump_team = tricks.trump_team.0 == player || tricks.trump_team.1 == player;
+
+    let Some(lead_suit) = trick.lead_suit else {
+        return if card.is_trump(trump) && on_trump_team {
+            "Leads a trump to draw the opponents' trumps".to_string()
+        } else if card.is_trump(trump) {
+            "Leads a trump to cut down the declarers' trumps".to_string()
+        } else if card.rank == Rank::Ace {
+            "Cashes an ace while it can still win".to_string()
+        } else if card.point_value() == 0 {
+            "Leads low and keeps the high cards back".to_string()
+        } else {
+            format!("Leads {} points into the trick", card.point_value())
+        };
+    };
+
+    // Who is winning the trick before this card
+    let (winner, winning_card) =
+        trick.cards_played[1..]
+            .iter()
+            .fold(trick.cards_played[0], |best, &(seat, card)| {
+                if card.beats(&best.1, trump, lead_suit) {
+                    (seat, card)
+                } else {
+                    best
+                }
+            });
+    let points = trick.calculate_points();
+    let partner_winning = winner == (player + 2) % 4;
+
+    if partner_winning {
+        if card.beats(&winning_card, trump, lead_suit) {
+            "Takes over the trick from partner".to_string()
+        } else if card.point_value() > 0 {
+            format!(
+                "Adds {} points to a trick partner is winning",
+                card.point_value()
+            )
+        } else {
+            "Lets partner keep the trick".to_string()
+        }
+    } else if card.beats(&winning_card, trump, lead_suit) {
+        let lead_is_trump = trick.cards_played[0].1.is_trump(trump);
+        let action = if card.is_trump(trump) && !lead_is_trump {
+            "Trumps in"
+        } else {
+            "Wins the trick"
+        };
+        let hold = if trick.cards_played.len() == 3 {
+            ""
+        } else {
+            " for now"
+        };
+        format!(
+            "{}{}, taking {} points",
+            action,
+            hold,
+            points + card.point_value()
+        )
+    } else if card.point_value() == 0 {
+        "Throws away a card worth nothing".to_string()
+    } else {
+        format!("Gives up {} points to the opponents", card.point_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::deck::Deck;
+    use crate::game::hand::Hand;
+
+    /// A seeded game bid by `RuleBot` and played until `cards` cards are down
+    fn game_in_play(seed: u64, cards: usize) -> Option<SjavsGame> {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        let mut game = SjavsGame::new("hint_game".to_string(), 3, hands);
+
+        while game.phase == GamePhase::Bidding {
+            let player = game.current_player()?;
+            game.apply(RuleBot::choose_action(&game, player)?).ok()?;
+        }
+        for _ in 0..cards {
+            let player = game.current_player()?;
+            game.apply(RuleBot::choose_action(&game, player)?).ok()?;
+        }
+        (game.phase == GamePhase::Playing).then_some(game)
+    }
+
+    fn config() -> MctsConfig {
+        MctsConfig {
+            iterations: 100,
+            time_limit: None,
+            seed: Some(3),
+            ..MctsConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_hints_rank_every_legal_card() {
+        for cards in [0, 2, 13] {
+            let game = (0..20)
+                .find_map(|seed| game_in_play(seed, cards))
+                .expect("a seed gets to playing");
+            let player = game.current_player().unwrap();
+            let hints = suggest_cards(&game, player, &config()).unwrap();
+
+            let mut hinted: Vec<Card> = hints.iter().map(|hint| hint.card).collect();
+            let mut legal = game.legal_cards(player);
+            hinted.sort_by_key(|card| card.index());
+            legal.sort_by_key(|card| card.index());
+            assert_eq!(hinted, legal);
+
+            assert!(hints.iter().all(|hint| !hint.reason.is_empty()));
+            assert!(hints
+                .windows(2)
+                .all(|pair| pair[0].visits >= pair[1].visits));
+        }
+    }
+
+    #[test]
+    fn test_hints_only_for_player_to_move() {
+        let game = (0..20)
+            .find_map(|seed| game_in_play(seed, 1))
+            .expect("a seed gets to playing");
+        let waiting = (game.current_player().unwrap() + 1) % 4;
+
+        assert!(suggest_cards(&game, waiting, &config()).is_err());
+    }
+}
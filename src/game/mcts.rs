@@ -0,0 +1,531 @@
+use crate::game::bot::RuleBot;
+use crate::game::card::{Card, Suit};
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::time::{Duration, Instant};
+
+/// Search budget for the Monte Carlo player
+///
+/// Search stops at whichever limit is hit first, so servers can cap CPU time
+/// per move while tests and simulations use a fixed iteration count.
+#[derive(Debug, Clone)]
+pub struct MctsConfig {
+    /// Maximum number of search iterations (one determinized playout each)
+    pub iterations: u32,
+    /// Maximum wall-clock time per decision
+    pub time_limit: Option<Duration>,
+    /// UCB exploration constant
+    pub exploration: f64,
+    /// Fixed RNG seed for reproducible searches
+    pub seed: Option<u64>,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 2000,
+            time_limit: Some(Duration::from_millis(500)),
+            exploration: 0.7,
+            seed: None,
+        }
+    }
+}
+
+impl MctsConfig {
+    /// Default config overridden by `SJAVS_MCTS_ITERATIONS` and `SJAVS_MCTS_TIME_MS`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Some(iterations) = std::env::var("SJAVS_MCTS_ITERATIONS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+        {
+            config.iterations = iterations;
+        }
+
+        if let Some(time_ms) = std::env::var("SJAVS_MCTS_TIME_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+        {
+            config.time_limit = Some(Duration::from_millis(time_ms));
+        }
+
+        config
+    }
+}
+
+/// Search statistics for one candidate action
+#[derive(Debug, Clone)]
+pub struct ActionEvaluation {
+    pub action: GameAction,
+    /// Times the action was chosen during search
+    pub visits: u32,
+    /// Average playout reward for the acting player's team (0.0 - 1.0)
+    pub expected_reward: f64,
+}
+
+/// Information-set Monte Carlo tree search player
+///
+/// Each iteration deals the unseen cards at random, consistent with the
+/// observer's hand, the cards already played and the suits other players
+/// have shown out of. The tree is shared across those deals (SO-ISMCTS) and
+/// playouts are finished by `RuleBot`.
+pub struct MctsBot;
+
+impl MctsBot {
+    /// Pick the best action for `player`, or `None` if it isn't their turn
+    pub fn choose_action(
+        game: &SjavsGame,
+        player: usize,
+        config: &MctsConfig,
+    ) -> Option<GameAction> {
+        Self::analyze(game, player, config)
+            .into_iter()
+            .next()
+            .map(|evaluation| evaluation.action)
+    }
+
+    /// Evaluate every action available to `player`, most visited first
+    pub fn analyze(game: &SjavsGame, player: usize, config: &MctsConfig) -> Vec<ActionEvaluation> {
+        if game.current_player() != Some(player) {
+            return vec![];
+        }
+
+        let mut rng = match config.seed {
+            Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+            None => ChaCha8Rng::seed_from_u64(thread_rng().gen()),
+        };
+
+        // Only one choice - nothing to search
+        let actions = legal_actions(game);
+        if actions.len() == 1 {
+            return vec![ActionEvaluation {
+                action: actions[0].clone(),
+                visits: 0,
+                expected_reward: 0.5,
+            }];
+        }
+
+        let started = Instant::now();
+        let mut tree = Tree::new();
+
+        // Always search at least once, so a zero budget still gives an action
+        for iteration in 0..config.iterations.max(1) {
+            if iteration > 0
+                && config
+                    .time_limit
+                    .is_some_and(|limit| started.elapsed() >= limit)
+            {
+                break;
+            }
+
+            let mut state = determinize(game, player, &mut rng);
+            tree.iterate(&mut state, config.exploration, &mut rng);
+        }
+
+        let mut evaluations: Vec<ActionEvaluation> = tree.nodes[0]
+            .children
+            .iter()
+            .map(|&child| {
+                let node = &tree.nodes[child];
+                ActionEvaluation {
+                    action: node.action.clone().expect("child nodes have an action"),
+                    visits: node.visits,
+                    expected_reward: node.average_reward(),
+                }
+            })
+            .collect();
+        evaluations.sort_by(|a, b| {
+            b.visits
+                .cmp(&a.visits)
+                .then(b.expected_reward.total_cmp(&a.expected_reward))
+        });
+
+        evaluations
+    }
+}
+
+/// A search tree node, reached by taking `action` from its parent
+struct Node {
+    action: Option<GameAction>,
+    /// Seat that took `action`
+    player: usize,
+    children: Vec<usize>,
+    visits: u32,
+    /// Times this node's action was legal when its parent was visited
+    availability: u32,
+    total_reward: f64,
+}
+
+impl Node {
+    fn average_reward(&self) -> f64 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.total_reward / self.visits as f64
+        }
+    }
+}
+
+struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    fn new() -> Self {
+        Self {
+            nodes: vec![Node {
+                action: None,
+                player: 0,
+                children: vec![],
+                visits: 0,
+                availability: 0,
+                total_reward: 0.0,
+            }],
+        }
+    }
+
+    /// Select, expand, play out and back up one determinized state
+    fn iterate(&mut self, state: &mut SjavsGame, exploration: f64, rng: &mut ChaCha8Rng) {
+        let mut path = vec![0];
+        let mut node = 0;
+
+        while let Some(player) = state.current_player() {
+            let actions = legal_actions(state);
+            let untried: Vec<&GameAction> = actions
+                .iter()
+                .filter(|action| {
+                    !self.nodes[node]
+                        .children
+                        .iter()
+                        .any(|&child| self.nodes[child].action.as_ref() == Some(*action))
+                })
+                .collect();
+
+            if let Some(&action) = untried.choose(rng) {
+                // Expand one new action, then switch to the playout
+                let child = self.nodes.len();
+                self.nodes.push(Node {
+                    action: Some(action.clone()),
+                    player,
+                    children: vec![],
+                    visits: 0,
+                    availability: 0,
+                    total_reward: 0.0,
+                });
+                self.nodes[node].children.push(child);
+                state
+                    .apply(action.clone())
+                    .expect("expanded action is legal");
+                path.push(child);
+                break;
+            }
+
+            // Every legal action has a child: pick by UCB over the ones available here
+            let available: Vec<usize> = self.nodes[node]
+                .children
+                .iter()
+                .copied()
+                .filter(|&child| {
+                    actions.contains(self.nodes[child].action.as_ref().expect("child action"))
+                })
+                .collect();
+            for &child in &available {
+                self.nodes[child].availability += 1;
+            }
+
+            let child = *available
+                .iter()
+                .max_by(|&&a, &&b| {
+                    self.ucb(a, exploration)
+                        .total_cmp(&self.ucb(b, exploration))
+                })
+                .expect("a legal action always has a child here");
+            let action = self.nodes[child].action.clone().expect("child action");
+            state.apply(action).expect("selected action is legal");
+            path.push(child);
+            node = child;
+        }
+
+        // Finish the game with the rule-based player
+        while let Some(player) = state.current_player() {
+            let action = RuleBot::choose_action(state, player).expect("bot always has a move");
+            state.apply(action).expect("bot action is legal");
+        }
+
+        for &index in &path {
+            let reward = team_reward(state, self.nodes[index].player);
+            let node = &mut self.nodes[index];
+            node.visits += 1;
+            node.total_reward += reward;
+        }
+    }
+
+    fn ucb(&self, index: usize, exploration: f64) -> f64 {
+        let node = &self.nodes[index];
+        if node.visits == 0 {
+            return f64::INFINITY;
+        }
+        node.average_reward()
+            + exploration * ((node.availability.max(1) as f64).ln() / node.visits as f64).sqrt()
+    }
+}
+
+/// Every action the player to move could take
+pub fn legal_actions(game: &SjavsGame) -> Vec<GameAction> {
+    let Some(player) = game.current_player() else {
+        return vec![];
+    };
+
+    match game.phase {
+        GamePhase::Bidding => {
+            let mut actions: Vec<GameAction> = game
+                .available_bids(player)
+                .into_iter()
+                .map(|option| GameAction::Bid {
+                    player,
+                    length: option.length,
+                    suit: option.suit,
+                })
+                .collect();
+            actions.push(GameAction::Pass { player });
+            actions
+        }
+        GamePhase::Playing => game
+            .legal_cards(player)
+            .into_iter()
+            .map(|card| GameAction::PlayCard { player, card })
+            .collect(),
+        GamePhase::Redeal | GamePhase::Complete => vec![],
+    }
+}
+
+/// Reward in 0.0 - 1.0 for the team of `player` once the game has ended
+///
+/// Mostly the cross points won or lost, with a little weight on card points
+/// so the search still prefers 89 over 61 when the score is the same.
+fn team_reward(game: &SjavsGame, player: usize) -> f64 {
+    let Some(tricks) = game
+        .tricks
+        .as_ref()
+        .filter(|_| game.phase == GamePhase::Complete)
+    else {
+        return 0.5; // Passed out - nobody gains
+    };
+    let Ok(scoring) = tricks.get_final_scoring() else {
+        return 0.5;
+    };
+
+    let result = scoring.calculate_game_result(&game.rules);
+    let on_trump_team = tricks.trump_team.0 == player || tricks.trump_team.1 == player;
+    let (own_score, other_score, own_points) = if on_trump_team {
+        (
+            result.trump_team_score,
+            result.opponent_team_score,
+            tricks.points_accumulated.0,
+        )
+    } else {
+        (
+            result.opponent_team_score,
+            result.trump_team_score,
+            tricks.points_accumulated.1,
+        )
+    };
+
+    let score_term = (own_score as f64 - other_score as f64 + 32.0) / 64.0;
+    0.8 * score_term.clamp(0.0, 1.0) + 0.2 * (own_points as f64 / 120.0)
+}
+
+/// Suits each seat has failed to follow, as revealed by the tricks so far
+pub fn known_voids(game: &SjavsGame) -> [Vec<Suit>; 4] {
+    let mut voids: [Vec<Suit>; 4] = Default::default();
+    let Some(tricks) = &game.tricks else {
+        return voids;
+    };
+
+    for trick in tricks
+        .completed_tricks
+        .iter()
+        .chain(std::iter::once(&tricks.current_trick))
+    {
+        let Some(lead_suit) = trick.lead_suit else {
+            continue;
+        };
+        for (seat, card) in &trick.cards_played {
+            if card.suit != lead_suit && !voids[*seat].contains(&lead_suit) {
+                voids[*seat].push(lead_suit);
+            }
+        }
+    }
+
+    voids
+}
+
+/// Copy of `game` with every hand but the observer's replaced by a random,
+/// consistent guess
+///
+/// Hand sizes are kept and known voids respected; if no consistent deal turns
+/// up within a few attempts the voids are ignored.
+pub fn determinize(game: &SjavsGame, observer: usize, rng: &mut impl Rng) -> SjavsGame {
+    const MAX_ATTEMPTS: usize = 20;
+
+    let others: Vec<usize> = (0..4).filter(|&seat| seat != observer).collect();
+    let mut unseen: Vec<Card> = others
+        .iter()
+        .flat_map(|&seat| game.hands[seat].cards.iter().copied())
+        .collect();
+    let voids = known_voids(game);
+
+    let mut state = game.clone();
+    for attempt in 0..=MAX_ATTEMPTS {
+        let respect_voids = attempt < MAX_ATTEMPTS;
+        unseen.shuffle(rng);
+
+        // Place the most constrained cards first
+        let allowed =
+            |card: &Card, seat: usize| !respect_voids || !voids[seat].contains(&card.suit);
+        unseen.sort_by_key(|card| others.iter().filter(|&&seat| allowed(card, seat)).count());
+
+        let mut dealt: [Vec<Card>; 4] = Default::default();
+        let dealt_all = unseen.iter().all(|card| {
+            let open: Vec<usize> = others
+                .iter()
+                .copied()
+                .filter(|&seat| {
+                    allowed(card, seat) && dealt[seat].len() < game.hands[seat].cards.len()
+                })
+                .collect();
+            match open.choose(rng) {
+                Some(&seat) => {
+                    dealt[seat].push(*card);
+                    true
+                }
+                None => false,
+            }
+        });
+
+        if dealt_all {
+            for &seat in &others {
+                state.hands[seat].cards = std::mem::take(&mut dealt[seat]);
+            }
+            break;
+        }
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::Deck;
+    use crate::game::hand::Hand;
+
+    fn seeded_game(seed: u64) -> SjavsGame {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        SjavsGame::new("mcts_game".to_string(), 3, hands)
+    }
+
+    fn quick_config() -> MctsConfig {
+        MctsConfig {
+            iterations: 150,
+            time_limit: None,
+            exploration: 0.7,
+            seed: Some(7),
+        }
+    }
+
+    #[test]
+    fn test_determinize_keeps_hand_sizes_and_voids() {
+        let mut game = seeded_game(1);
+        while game.phase == GamePhase::Bidding {
+            let player = game.current_player().unwrap();
+            game.apply(RuleBot::choose_action(&game, player).unwrap())
+                .unwrap();
+        }
+        if game.phase != GamePhase::Playing {
+            return; // Passed out - nothing to play
+        }
+
+        // Play a few tricks with the rule-based bot
+        for _ in 0..12 {
+            let player = game.current_player().unwrap();
+            game.apply(RuleBot::choose_action(&game, player).unwrap())
+                .unwrap();
+        }
+
+        let voids = known_voids(&game);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..20 {
+            let state = determinize(&game, 0, &mut rng);
+            assert_eq!(state.hands[0].cards, game.hands[0].cards);
+            for (seat, seat_voids) in voids.iter().enumerate().skip(1) {
+                assert_eq!(state.hands[seat].cards.len(), game.hands[seat].cards.len());
+                assert!(state.hands[seat]
+                    .cards
+                    .iter()
+                    .all(|card| !seat_voids.contains(&card.suit)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_returns_legal_actions_until_game_end() {
+        let mut game = seeded_game(2);
+
+        while let Some(player) = game.current_player() {
+            let action = MctsBot::choose_action(&game, player, &quick_config())
+                .expect("search should find an action");
+            assert!(legal_actions(&game).contains(&action));
+            game.apply(action).unwrap();
+        }
+
+        assert!(matches!(
+            game.phase,
+            GamePhase::Complete | GamePhase::Redeal
+        ));
+    }
+
+    #[test]
+    fn test_analysis_covers_every_legal_action() {
+        let game = seeded_game(4);
+        let player = game.current_player().unwrap();
+
+        let evaluations = MctsBot::analyze(&game, player, &quick_config());
+        let actions = legal_actions(&game);
+
+        assert_eq!(evaluations.len(), actions.len());
+        assert!(evaluations
+            .windows(2)
+            .all(|pair| pair[0].visits >= pair[1].visits));
+        assert!(MctsBot::analyze(&game, (player + 1) % 4, &quick_config()).is_empty());
+    }
+
+    #[test]
+    fn test_zero_budget_still_chooses_an_action() {
+        // A bidder with a choice to make, so the single-choice shortcut doesn't apply
+        let game = (0..20)
+            .map(seeded_game)
+            .find(|game| legal_actions(game).len() > 1)
+            .expect("a seed gives the first bidder a choice");
+        let player = game.current_player().unwrap();
+
+        for config in [
+            MctsConfig {
+                iterations: 0,
+                ..quick_config()
+            },
+            MctsConfig {
+                time_limit: Some(Duration::ZERO),
+                ..quick_config()
+            },
+        ] {
+            let action = MctsBot::choose_action(&game, player, &config)
+                .expect("search should find an action");
+            assert!(legal_actions(&game).contains(&action));
+        }
+    }
+}
@@ -1,6 +1,21 @@
+pub mod advisor;
+pub mod analysis;
+pub mod bot;
 pub mod card;
+pub mod claim;
 pub mod cross;
+pub mod dataset;
 pub mod deck;
+pub mod engine;
+pub mod external;
 pub mod hand;
+pub mod hint;
+pub mod mcts;
+pub mod notation;
+pub mod replay;
+pub mod rules;
 pub mod scoring;
+pub mod simulation;
+pub mod solver;
 pub mod trick;
+pub mod violation;
@@ -0,0 +1,688 @@
+use crate::game::card::Card;
+use crate::game::deck::Deck;
+use crate::game::engine::{GameAction, GamePhase, SjavsGame};
+use crate::game::hand::Hand;
+use crate::game::rules::RuleSet;
+use crate::game::scoring::{GameResult, SjavsScoring};
+use crate::game::trick::PlayedDeal;
+use std::collections::HashSet;
+
+/// Tags every record must carry, in the order they are written
+const REQUIRED_TAGS: [&str; 8] = [
+    "Game", "Deal", "Seat0", "Seat1", "Seat2", "Seat3", "Dealer", "Rules",
+];
+
+/// Tags that may be left out
+const OPTIONAL_TAGS: [&str; 5] = ["Seed", "RuleSet", "Points", "Result", "Hints"];
+
+/// One deal of Sjavs, as written in Sjavs Game Notation (SGN)
+///
+/// ```text
+/// [Game "game_1700000000_ab12"]
+/// [Deal "0"]
+/// [Seat0 "alice"]
+/// [Seat1 "bob"]
+/// [Seat2 "carol"]
+/// [Seat3 "dave"]
+/// [Dealer "3"]
+/// [Seed "1234"]
+/// [Rules "standard"]
+/// [Points "75-45"]
+/// [Result "2-0"]
+/// [Hints "0 2 0 0"]
+///
+/// Hands
+/// 0: QC JS 10H ...
+///
+/// Bidding
+/// 0: 5 hearts
+/// 1: pass
+///
+/// Tricks
+/// 1. 0: QC 7H 8H 9H
+/// ```
+///
+/// Points are the card points and Result the game score of the trump team
+/// and opponents. Rule sets that aren't a preset are written as JSON in a
+/// `RuleSet` tag with `[Rules "custom"]`. Hints counts the hints each seat
+/// asked for and is left out when nobody used one, so rated games can reject
+/// records that have it. Lines starting with `;` are comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    pub game_id: String,
+    pub deal_number: u32,
+    /// Player names by seat
+    pub players: [String; 4],
+    pub dealer: usize,
+    /// Seed the hands were dealt from, if known
+    pub seed: Option<u64>,
+    pub rules: RuleSet,
+    /// Hands as dealt, by seat
+    pub hands: [Vec<Card>; 4],
+    /// Bids and passes in order
+    pub bidding: Vec<GameAction>,
+    /// Cards of each trick in play order; the last trick may be unfinished
+    pub tricks: Vec<Vec<Card>>,
+    /// Hints each seat asked for during the deal
+    pub hints_used: [u32; 4],
+}
+
+impl GameRecord {
+    /// Build a record from a deal archived at the end of a match game
+    pub fn from_played_deal(
+        game_id: String,
+        deal_number: u32,
+        players: [String; 4],
+        seed: Option<u64>,
+        rules: RuleSet,
+        deal: &PlayedDeal,
+    ) -> Self {
+        Self {
+            game_id,
+            deal_number,
+            players,
+            dealer: deal.dealer,
+            seed,
+            rules,
+            hands: deal.dealt_hands(),
+            bidding: deal.bidding.clone(),
+            tricks: deal
+                .tricks
+                .iter()
+                .map(|trick| trick.cards_played.iter().map(|(_, card)| *card).collect())
+                .collect(),
+            hints_used: deal.hints_used,
+        }
+    }
+
+    /// Whether any seat asked for a hint during the deal
+    pub fn used_hints(&self) -> bool {
+        self.hints_used.iter().any(|count| *count > 0)
+    }
+
+    /// Replay the record through the rules engine, rejecting any illegal action
+    pub fn replay(&self) -> Result<SjavsGame, String> {
+        self.replay_with(|_, _| {})
+    }
+
+    /// Replay the record, handing each action and the game it leads to to `on_action`
+    pub fn replay_with(
+        &self,
+        mut on_action: impl FnMut(&GameAction, &SjavsGame),
+    ) -> Result<SjavsGame, String> {
+        let hands = [0, 1, 2, 3].map(|seat| Hand::new(self.hands[seat].clone(), seat));
+        let mut game =
+            SjavsGame::new(self.game_id.clone(), self.dealer, hands).with_rules(self.rules.clone());
+
+        for (index, action) in self.bidding.iter().enumerate() {
+            if !matches!(action, GameAction::Bid { .. } | GameAction::Pass { .. }) {
+                return Err(format!("Bidding action {} is not a bid or pass", index + 1));
+            }
+            game.apply(action.clone())
+                .map_err(|e| format!("Bidding action {}: {}", index + 1, e))?;
+            on_action(action, &game);
+        }
+
+        if self.tricks.is_empty() {
+            return Ok(game);
+        }
+        if game.phase != GamePhase::Playing {
+            return Err("Tricks are recorded but bidding did not finish".to_string());
+        }
+
+        for (index, trick) in self.tricks.iter().enumerate() {
+            if trick.len() != 4 && index + 1 != self.tricks.len() {
+                return Err(format!("Trick {} does not have 4 cards", index + 1));
+            }
+            for &card in trick {
+                let player = game
+                    .current_player()
+                    .ok_or_else(|| format!("Trick {}: the game is already over", index + 1))?;
+                let action = GameAction::PlayCard { player, card };
+                game.apply(action.clone())
+                    .map_err(|e| format!("Trick {}: {}", index + 1, e))?;
+                on_action(&action, &game);
+            }
+        }
+
+        Ok(game)
+    }
+
+    /// Final scoring, or `None` while the deal is unfinished
+    pub fn result(&self) -> Result<Option<(SjavsScoring, GameResult)>, String> {
+        let game = self.replay()?;
+        if game.phase != GamePhase::Complete {
+            return Ok(None);
+        }
+
+        let scoring = game
+            .tricks
+            .as_ref()
+            .ok_or("Trick state not initialized")?
+            .get_final_scoring()?;
+        let result = scoring.calculate_game_result(&self.rules);
+        Ok(Some((scoring, result)))
+    }
+
+    /// Write the record as SGN text
+    ///
+    /// The record is replayed first, so only legal games can be written.
+    pub fn to_notation(&self) -> Result<String, String> {
+        let game = self.replay()?;
+        let result = self.result()?;
+        let mut out = String::new();
+
+        let mut tag = |name: &str, value: &str| {
+            out.push_str(&format!("[{} \"{}\"]\n", name, escape(value)));
+        };
+        tag("Game", &self.game_id);
+        tag("Deal", &self.deal_number.to_string());
+        for (seat, player) in self.players.iter().enumerate() {
+            tag(&format!("Seat{}", seat), player);
+        }
+        tag("Dealer", &self.dealer.to_string());
+        if let Some(seed) = self.seed {
+            tag("Seed", &seed.to_string());
+        }
+        if RuleSet::preset(&self.rules.name).as_ref() == Some(&self.rules) {
+            tag("Rules", &self.rules.name);
+        } else {
+            let json = serde_json::to_string(&self.rules)
+                .map_err(|e| format!("Failed to serialize rules: {}", e))?;
+            tag("Rules", "custom");
+            tag("RuleSet", &json);
+        }
+        if let Some((scoring, result)) = &result {
+            tag(
+                "Points",
+                &format!(
+                    "{}-{}",
+                    scoring.trump_team_points, scoring.opponent_team_points
+                ),
+            );
+            tag(
+                "Result",
+                &format!("{}-{}", result.trump_team_score, result.opponent_team_score),
+            );
+        }
+        if self.used_hints() {
+            let counts: Vec<String> = self.hints_used.iter().map(u32::to_string).collect();
+            tag("Hints", &counts.join(" "));
+        }
+
+        out.push_str("\nHands\n");
+        for (seat, hand) in self.hands.iter().enumerate() {
+            out.push_str(&format!("{}: {}\n", seat, codes(hand)));
+        }
+
+        out.push_str("\nBidding\n");
+        for action in &self.bidding {
+            match action {
+                GameAction::Bid {
+                    player,
+                    length,
+                    suit,
+                } => out.push_str(&format!("{}: {} {}\n", player, length, suit)),
+                GameAction::Pass { player } => out.push_str(&format!("{}: pass\n", player)),
+                GameAction::PlayCard { .. } => {}
+            }
+        }
+
+        if !self.tricks.is_empty() {
+            out.push_str("\nTricks\n");
+            let history = game.tricks.as_ref().ok_or("Trick state not initialized")?;
+            let leaders = history
+                .completed_tricks
+                .iter()
+                .chain(std::iter::once(&history.current_trick))
+                .map(|trick| trick.cards_played.first().map(|(player, _)| *player));
+            for (number, (trick, leader)) in self.tricks.iter().zip(leaders).enumerate() {
+                out.push_str(&format!(
+                    "{}. {}: {}\n",
+                    number + 1,
+                    leader.unwrap_or_default(),
+                    codes(trick)
+                ));
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Parse SGN text, checking every action against the rules
+    ///
+    /// Finished deals must state the Points and Result they score, and
+    /// unfinished ones must not. When a seed is given the hands must be the
+    /// ones it deals.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut tags: Vec<(String, String)> = Vec::new();
+        let mut section: Option<&str> = None;
+        let mut hand_lines = Vec::new();
+        let mut bidding = Vec::new();
+        let mut trick_lines = Vec::new();
+
+        for (index, raw) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            let at = |e: String| format!("Line {}: {}", line_number, e);
+
+            if line.starts_with('[') {
+                if section.is_some() {
+                    return Err(at("Tags must come before the hands".to_string()));
+                }
+                let (name, value) = parse_tag(line).map_err(at)?;
+                if tags.iter().any(|(existing, _)| *existing == name) {
+                    return Err(at(format!("Duplicate tag '{}'", name)));
+                }
+                tags.push((name, value));
+                continue;
+            }
+
+            match (section, line) {
+                (None, "Hands") => section = Some("Hands"),
+                (Some("Hands"), "Bidding") => section = Some("Bidding"),
+                (Some("Bidding"), "Tricks") => section = Some("Tricks"),
+                (Some("Hands"), _) => hand_lines.push((line_number, line)),
+                (Some("Bidding"), _) => bidding.push(parse_bidding_line(line).map_err(at)?),
+                (Some("Tricks"), _) => trick_lines.push((line_number, line)),
+                _ => return Err(at(format!("Unexpected line '{}'", line))),
+            }
+        }
+
+        let tag = |name: &str| {
+            tags.iter()
+                .find(|(existing, _)| existing == name)
+                .map(|(_, value)| value.as_str())
+        };
+        for (name, _) in &tags {
+            if !REQUIRED_TAGS.contains(&name.as_str()) && !OPTIONAL_TAGS.contains(&name.as_str()) {
+                return Err(format!("Unknown tag '{}'", name));
+            }
+        }
+        let required = |name: &str| tag(name).ok_or_else(|| format!("Missing tag '{}'", name));
+
+        let deal_number = required("Deal")?
+            .parse::<u32>()
+            .map_err(|_| "Deal must be a number".to_string())?;
+        let dealer = required("Dealer")?
+            .parse::<usize>()
+            .ok()
+            .filter(|dealer| *dealer < 4)
+            .ok_or("Dealer must be a seat from 0 to 3")?;
+        let seed = tag("Seed")
+            .map(|seed| seed.parse::<u64>().map_err(|_| "Seed must be a number"))
+            .transpose()?;
+        let rules = match (required("Rules")?, tag("RuleSet")) {
+            ("custom", Some(json)) => {
+                let rules: RuleSet = serde_json::from_str(json)
+                    .map_err(|e| format!("Invalid RuleSet tag: {}", e))?;
+                rules.validate()?;
+                rules
+            }
+            ("custom", None) => return Err("Custom rules need a RuleSet tag".to_string()),
+            (_, Some(_)) => return Err("RuleSet tag is only allowed with custom rules".to_string()),
+            (name, None) => {
+                RuleSet::preset(name).ok_or_else(|| format!("Unknown rule preset '{}'", name))?
+            }
+        };
+        let hints_used = match tag("Hints") {
+            Some(counts) => {
+                let counts = counts
+                    .split_whitespace()
+                    .map(|count| count.parse::<u32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .ok()
+                    .and_then(|counts| <[u32; 4]>::try_from(counts).ok())
+                    .ok_or("Hints must be four numbers, one per seat")?;
+                if counts.iter().all(|count| *count == 0) {
+                    return Err("Hints tag is only written when a hint was used".to_string());
+                }
+                counts
+            }
+            None => [0; 4],
+        };
+        let players = [0, 1, 2, 3].map(|seat| tag(&format!("Seat{}", seat)).map(str::to_string));
+        let players = match players {
+            [Some(a), Some(b), Some(c), Some(d)] => [a, b, c, d],
+            _ => return Err("Every seat needs a Seat tag".to_string()),
+        };
+
+        // Hands: one line per seat, in seat order, together a full deck
+        if hand_lines.len() != 4 {
+            return Err("Hands must list exactly 4 seats".to_string());
+        }
+        let mut hands: [Vec<Card>; 4] = Default::default();
+        let mut seen = HashSet::new();
+        for (seat, (line_number, line)) in hand_lines.into_iter().enumerate() {
+            let at = |e: String| format!("Line {}: {}", line_number, e);
+            let cards = line
+                .strip_prefix(&format!("{}:", seat))
+                .ok_or_else(|| at(format!("Expected the hand of seat {}", seat)))?;
+            let cards = parse_cards(cards).map_err(at)?;
+            if cards.len() != 8 {
+                return Err(at("A hand must have 8 cards".to_string()));
+            }
+            if let Some(card) = cards.iter().find(|card| !seen.insert(**card)) {
+                return Err(at(format!("{} is dealt twice", card.code())));
+            }
+            hands[seat] = cards;
+        }
+
+        if let Some(seed) = seed {
+            let dealt = Deck::deal_until_valid_seeded(seed, rules.min_bid).0;
+            for seat in 0..4 {
+                let expected: HashSet<_> = dealt[seat].iter().collect();
+                let recorded: HashSet<_> = hands[seat].iter().collect();
+                if expected != recorded {
+                    return Err(format!(
+                        "Hand of seat {} is not what seed {} deals",
+                        seat, seed
+                    ));
+                }
+            }
+        }
+
+        // Tricks: numbered in order, leader stated so mistakes are easy to spot
+        let mut tricks = Vec::new();
+        let mut leaders = Vec::new();
+        for (number, (line_number, line)) in trick_lines.into_iter().enumerate() {
+            let at = |e: String| format!("Line {}: {}", line_number, e);
+            let (leader, cards) = line
+                .strip_prefix(&format!("{}. ", number + 1))
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| at(format!("Expected trick {}", number + 1)))?;
+            let leader = leader
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| at("Leader must be a seat".to_string()))?;
+            leaders.push(leader);
+            tricks.push(parse_cards(cards).map_err(at)?);
+        }
+
+        let record = Self {
+            game_id: required("Game")?.to_string(),
+            deal_number,
+            players,
+            dealer,
+            seed,
+            rules,
+            hands,
+            bidding,
+            tricks,
+            hints_used,
+        };
+
+        // Replaying checks every bid and card; then the leaders and result must agree
+        let game = record.replay()?;
+        if let Some(history) = &game.tricks {
+            let actual = history
+                .completed_tricks
+                .iter()
+                .chain(std::iter::once(&history.current_trick))
+                .filter_map(|trick| trick.cards_played.first().map(|(player, _)| *player));
+            for (number, (stated, actual)) in leaders.iter().zip(actual).enumerate() {
+                if *stated != actual {
+                    return Err(format!(
+                        "Trick {} is led by seat {}, not seat {}",
+                        number + 1,
+                        actual,
+                        stated
+                    ));
+                }
+            }
+        }
+
+        match (record.result()?, tag("Points"), tag("Result")) {
+            (Some((scoring, result)), Some(points), Some(score)) => {
+                let expected_points = format!(
+                    "{}-{}",
+                    scoring.trump_team_points, scoring.opponent_team_points
+                );
+                let expected_score =
+                    format!("{}-{}", result.trump_team_score, result.opponent_team_score);
+                if points != expected_points {
+                    return Err(format!(
+                        "Points tag says {} but the tricks score {}",
+                        points, expected_points
+                    ));
+                }
+                if score != expected_score {
+                    return Err(format!(
+                        "Result tag says {} but the game scores {}",
+                        score, expected_score
+                    ));
+                }
+            }
+            (Some(_), _, _) => {
+                return Err("A finished deal needs Points and Result tags".to_string())
+            }
+            (None, None, None) => {}
+            (None, _, _) => {
+                return Err("An unfinished deal can't have Points or Result tags".to_string())
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+fn codes(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|card| card.code())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse space-separated card codes, rejecting anything that isn't a Sjavs card
+fn parse_cards(text: &str) -> Result<Vec<Card>, String> {
+    let valid = Deck::new().to_codes();
+    text.split_whitespace()
+        .map(|code| {
+            if valid.iter().any(|valid| valid == code) {
+                Card::from_code(code)
+            } else {
+                Err(format!("'{}' is not a card", code))
+            }
+        })
+        .collect()
+}
+
+/// `0: 5 hearts` or `1: pass`
+fn parse_bidding_line(line: &str) -> Result<GameAction, String> {
+    let (seat, action) = line
+        .split_once(':')
+        .ok_or("Expected '<seat>: <bid>' or '<seat>: pass'")?;
+    let player = seat
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|seat| *seat < 4)
+        .ok_or("Seat must be from 0 to 3")?;
+
+    let words: Vec<&str> = action.split_whitespace().collect();
+    match words.as_slice() {
+        ["pass"] => Ok(GameAction::Pass { player }),
+        [length, suit] => Ok(GameAction::Bid {
+            player,
+            length: length
+                .parse()
+                .map_err(|_| format!("'{}' is not a bid length", length))?,
+            suit: suit.to_string(),
+        }),
+        _ => Err(format!("'{}' is not a bid or pass", action.trim())),
+    }
+}
+
+/// `[Name "value"]`, with `\"` and `\\` escapes in the value
+fn parse_tag(line: &str) -> Result<(String, String), String> {
+    let inner = line
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or("Tag must be written as [Name \"value\"]")?;
+    let (name, quoted) = inner
+        .split_once(' ')
+        .ok_or("Tag must be written as [Name \"value\"]")?;
+    let quoted = quoted
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or("Tag value must be quoted")?;
+
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped @ ('"' | '\\')) => value.push(escaped),
+                _ => return Err("Invalid escape in tag value".to_string()),
+            },
+            '"' => return Err("Unescaped quote in tag value".to_string()),
+            _ => value.push(c),
+        }
+    }
+
+    Ok((name.to_string(), value))
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+
+    /// Play a seeded deal out with rule bots and record it
+    fn played_record(seed: u64, rules: RuleSet) -> Option<GameRecord> {
+        let hands = Deck::deal_until_valid_seeded(seed, rules.min_bid).0;
+        let mut game = SjavsGame::new(
+            "notation".to_string(),
+            3,
+            [0, 1, 2, 3].map(|seat| Hand::new(hands[seat].clone(), seat)),
+        )
+        .with_rules(rules.clone());
+
+        let mut bidding = Vec::new();
+        let mut tricks: Vec<Vec<Card>> = Vec::new();
+        while let Some(player) = game.current_player() {
+            let action = RuleBot::choose_action(&game, player)?;
+            match &action {
+                GameAction::PlayCard { card, .. } => match tricks.last_mut() {
+                    Some(trick) if trick.len() < 4 => trick.push(*card),
+                    _ => tricks.push(vec![*card]),
+                },
+                _ => bidding.push(action.clone()),
+            }
+            game.apply(action).ok()?;
+        }
+        if game.phase != GamePhase::Complete {
+            return None;
+        }
+
+        Some(GameRecord {
+            game_id: "notation".to_string(),
+            deal_number: 0,
+            players: ["alice", "bob", "carol", "dave \"the dealer\""].map(str::to_string),
+            dealer: 3,
+            seed: Some(seed),
+            rules,
+            hands,
+            bidding,
+            tricks,
+            hints_used: [0; 4],
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let record = (0..20)
+            .find_map(|seed| played_record(seed, RuleSet::standard()))
+            .expect("a seed gets played out");
+        let text = record.to_notation().unwrap();
+
+        assert!(text.contains("[Rules \"standard\"]"));
+        assert!(text.contains("[Seat3 \"dave \\\"the dealer\\\"\"]"));
+        assert!(text.contains("[Result \""));
+        assert_eq!(GameRecord::parse(&text).unwrap(), record);
+    }
+
+    #[test]
+    fn test_custom_rules_round_trip() {
+        let mut rules = RuleSet::standard();
+        rules.name = "custom".to_string();
+        rules.tie_bonus = 4;
+        let record = (0..20)
+            .find_map(|seed| played_record(seed, rules.clone()))
+            .expect("a seed gets played out");
+        let text = record.to_notation().unwrap();
+
+        assert!(text.contains("[Rules \"custom\"]"));
+        assert_eq!(GameRecord::parse(&text).unwrap().rules, rules);
+    }
+
+    #[test]
+    fn test_hints_round_trip() {
+        let mut record = (0..20)
+            .find_map(|seed| played_record(seed, RuleSet::standard()))
+            .expect("a seed gets played out");
+        assert!(!record.to_notation().unwrap().contains("[Hints"));
+
+        record.hints_used = [0, 2, 0, 1];
+        let text = record.to_notation().unwrap();
+        assert!(text.contains("[Hints \"0 2 0 1\"]"));
+        assert_eq!(GameRecord::parse(&text).unwrap(), record);
+
+        let bad = text.replace("[Hints \"0 2 0 1\"]", "[Hints \"0 2\"]");
+        assert!(GameRecord::parse(&bad).is_err());
+        let bad = text.replace("[Hints \"0 2 0 1\"]", "[Hints \"0 0 0 0\"]");
+        assert!(GameRecord::parse(&bad).is_err());
+    }
+
+    #[test]
+    fn test_parser_rejects_tampering() {
+        let record = (0..20)
+            .find_map(|seed| played_record(seed, RuleSet::standard()))
+            .expect("a seed gets played out");
+        let text = record.to_notation().unwrap();
+
+        // Wrong result
+        let points = text
+            .lines()
+            .find(|line| line.starts_with("[Result"))
+            .unwrap();
+        let bad = text.replace(points, "[Result \"99-0\"]");
+        assert!(GameRecord::parse(&bad).is_err());
+
+        // Two cards of a trick swapped between players breaks legality or the hands
+        let mut swapped = record.clone();
+        swapped.tricks[0].swap(1, 2);
+        assert!(swapped.replay().is_err());
+
+        // Unknown tag
+        let bad = format!("[Weather \"rain\"]\n{}", text);
+        assert!(GameRecord::parse(&bad).is_err());
+
+        // Hands that the seed doesn't deal
+        let mut reseeded = record.clone();
+        reseeded.seed = Some(record.seed.unwrap() + 1000);
+        let text = reseeded.to_notation().unwrap();
+        assert!(GameRecord::parse(&text).is_err());
+    }
+
+    #[test]
+    fn test_unfinished_deal() {
+        let mut record = (0..20)
+            .find_map(|seed| played_record(seed, RuleSet::standard()))
+            .expect("a seed gets played out");
+        record.tricks.truncate(3);
+        record.tricks[2].truncate(2);
+
+        let text = record.to_notation().unwrap();
+        assert!(!text.contains("[Result"));
+        assert_eq!(GameRecord::parse(&text).unwrap(), record);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::game::engine::{GameAction, SjavsGame};
+use crate::game::notation::GameRecord;
+use crate::game::trick::TrickState;
+
+/// The game as it stood right after one bid, pass or card
+#[derive(Debug, Clone)]
+pub struct ReplayStep {
+    pub action: GameAction,
+    /// The trick this card finished, if it was the fourth card played to it
+    pub completed_trick: Option<TrickState>,
+    pub game: SjavsGame,
+}
+
+/// Replay a deal one action at a time, so a client can step through it
+pub fn replay_steps(record: &GameRecord) -> Result<Vec<ReplayStep>, String> {
+    let mut steps = Vec::new();
+    let mut tricks_seen = 0;
+
+    record.replay_with(|action, game| {
+        // The engine moves straight on to the next trick, so catch the
+        // finished one here while it is the newest completed trick
+        let completed = game
+            .tricks
+            .as_ref()
+            .map(|tricks| tricks.completed_tricks.as_slice())
+            .unwrap_or_default();
+        let completed_trick = if completed.len() > tricks_seen {
+            tricks_seen = completed.len();
+            completed.last().cloned()
+        } else {
+            None
+        };
+
+        steps.push(ReplayStep {
+            action: action.clone(),
+            completed_trick,
+            game: game.clone(),
+        });
+    })?;
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bot::RuleBot;
+    use crate::game::card::Card;
+    use crate::game::deck::Deck;
+    use crate::game::engine::GamePhase;
+    use crate::game::hand::Hand;
+    use crate::game::rules::RuleSet;
+
+    /// Play a seeded deal out with rule bots, stopping after `limit` actions
+    fn record_for(seed: u64, limit: usize) -> Option<GameRecord> {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        let mut game = SjavsGame::new(
+            "replay".to_string(),
+            0,
+            [0, 1, 2, 3].map(|seat| Hand::new(hands[seat].clone(), seat)),
+        );
+
+        let mut bidding = Vec::new();
+        let mut tricks: Vec<Vec<Card>> = Vec::new();
+        let mut actions = 0;
+        while let Some(player) = game.current_player() {
+            if actions == limit {
+                break;
+            }
+            let action = RuleBot::choose_action(&game, player)?;
+            match &action {
+                GameAction::PlayCard { card, .. } => match tricks.last_mut() {
+                    Some(trick) if trick.len() < 4 => trick.push(*card),
+                    _ => tricks.push(vec![*card]),
+                },
+                _ => bidding.push(action.clone()),
+            }
+            game.apply(action).ok()?;
+            actions += 1;
+        }
+        if game.phase == GamePhase::Redeal {
+            return None;
+        }
+
+        Some(GameRecord {
+            game_id: "replay".to_string(),
+            deal_number: 0,
+            players: ["north", "east", "south", "west"].map(str::to_string),
+            dealer: 0,
+            seed: Some(seed),
+            rules: RuleSet::standard(),
+            hands,
+            bidding,
+            tricks,
+            hints_used: [0; 4],
+        })
+    }
+
+    #[test]
+    fn test_one_step_per_action() {
+        let record = (0..20)
+            .find_map(|seed| record_for(seed, usize::MAX))
+            .expect("a seed gets played out");
+        let steps = replay_steps(&record).unwrap();
+
+        assert_eq!(steps.len(), record.bidding.len() + 32);
+        assert_eq!(steps.last().unwrap().game.phase, GamePhase::Complete);
+
+        // Every fourth card finishes a trick, in order
+        let finished: Vec<u8> = steps
+            .iter()
+            .filter_map(|step| step.completed_trick.as_ref())
+            .map(|trick| trick.trick_number)
+            .collect();
+        assert_eq!(finished, (1..=8).collect::<Vec<u8>>());
+
+        // Each card leaves the player holding one fewer
+        for pair in steps.windows(2) {
+            if let GameAction::PlayCard { player, .. } = pair[1].action {
+                assert_eq!(
+                    pair[1].game.hands[player].cards.len() + 1,
+                    pair[0].game.hands[player].cards.len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unfinished_deal() {
+        let record = (0..20)
+            .find_map(|seed| {
+                let full = record_for(seed, usize::MAX)?;
+                record_for(seed, full.bidding.len() + 6)
+            })
+            .expect("a seed gets played out");
+        let steps = replay_steps(&record).unwrap();
+
+        assert_eq!(steps.len(), record.bidding.len() + 6);
+        assert_eq!(steps.last().unwrap().game.phase, GamePhase::Playing);
+        assert_eq!(
+            steps
+                .iter()
+                .filter(|step| step.completed_trick.is_some())
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+
+/// Cross points awarded for each kind of game result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreValues {
+    /// Trump team reached the high win threshold (90+)
+    pub high_win: u8,
+    /// Trump team reached the win threshold (61-89)
+    pub win: u8,
+    /// Trump team failed but avoided the double loss (31-59)
+    pub loss: u8,
+    /// Trump team fell below the double loss threshold (0-30)
+    pub double_loss: u8,
+    /// Trump team won all tricks
+    pub vol: u8,
+    /// A single trump team player won all tricks
+    pub individual_vol: u8,
+}
+
+impl ScoreValues {
+    fn all(&self) -> [u8; 6] {
+        [
+            self.high_win,
+            self.win,
+            self.loss,
+            self.double_loss,
+            self.vol,
+            self.individual_vol,
+        ]
+    }
+}
+
+/// House rules a match is played with
+///
+/// Chosen when the match is created and stored with it, so bidding, scoring
+/// and the cross all agree on the same numbers for the whole match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    /// Preset the rules came from, or "custom"
+    pub name: String,
+    /// Fewest trumps a player may bid
+    pub min_bid: u8,
+    /// Card points for the trump team's high win
+    pub high_win_points: u8,
+    /// Card points the trump team needs to win
+    pub win_points: u8,
+    /// Card points the trump team needs to avoid the double loss
+    pub avoid_double_points: u8,
+    /// Scores when any suit but clubs is trump
+    pub scores: ScoreValues,
+    /// Scores when clubs is trump
+    pub clubs_scores: ScoreValues,
+    /// Score for the opponents winning all tricks, whatever the trump suit
+    pub opponent_vol: u8,
+    /// Extra points the next game is worth after a 60-60 tie
+    pub tie_bonus: u8,
+    /// Points each team starts a cross with
+    pub cross_start: u8,
+}
+
+/// Names of the built-in presets
+pub const PRESETS: [&str; 3] = ["standard", "quick", "no_club_bonus"];
+
+impl RuleSet {
+    /// The rules as played in most of the Faroe Islands
+    pub fn standard() -> Self {
+        Self {
+            name: "standard".to_string(),
+            min_bid: 5,
+            high_win_points: 90,
+            win_points: 61,
+            avoid_double_points: 31,
+            scores: ScoreValues {
+                high_win: 4,
+                win: 2,
+                loss: 4,
+                double_loss: 8,
+                vol: 12,
+                individual_vol: 16,
+            },
+            clubs_scores: ScoreValues {
+                high_win: 8,
+                win: 4,
+                loss: 8,
+                double_loss: 16,
+                vol: 16,
+                individual_vol: 24,
+            },
+            opponent_vol: 16,
+            tie_bonus: 2,
+            cross_start: 24,
+        }
+    }
+
+    /// Look up a preset by name
+    pub fn preset(name: &str) -> Option<Self> {
+        let mut rules = Self::standard();
+        match name {
+            "standard" => {}
+            // Half-length crosses for a shorter session
+            "quick" => rules.cross_start = 12,
+            // Clubs score the same as the other suits
+            "no_club_bonus" => rules.clubs_scores = rules.scores.clone(),
+            _ => return None,
+        }
+        rules.name = name.to_string();
+        Some(rules)
+    }
+
+    /// Scores that apply with the given trump suit
+    pub fn scores_for(&self, trump_suit: &str) -> &ScoreValues {
+        if trump_suit == "clubs" {
+            &self.clubs_scores
+        } else {
+            &self.scores
+        }
+    }
+
+    /// Check that the rules make a playable game
+    pub fn validate(&self) -> Result<(), String> {
+        if !(4..=8).contains(&self.min_bid) {
+            return Err("Minimum bid must be between 4 and 8 trumps".to_string());
+        }
+
+        // Exactly one side can win a deal, and a 60-60 split stays a tie
+        if !(1..=60).contains(&self.avoid_double_points) {
+            return Err("Double loss threshold must be between 1 and 60 points".to_string());
+        }
+        if !(61..=120).contains(&self.win_points) {
+            return Err("Win threshold must be between 61 and 120 points".to_string());
+        }
+        if self.high_win_points < self.win_points || self.high_win_points > 120 {
+            return Err(format!(
+                "High win threshold must be between {} and 120 points",
+                self.win_points
+            ));
+        }
+
+        // A zero score would be mistaken for a tie by the cross
+        let values = self
+            .scores
+            .all()
+            .into_iter()
+            .chain(self.clubs_scores.all())
+            .chain([self.opponent_vol]);
+        for value in values {
+            if !(1..=32).contains(&value) {
+                return Err("Game scores must be between 1 and 32 points".to_string());
+            }
+        }
+
+        if self.tie_bonus > 8 {
+            return Err("Tie bonus can be at most 8 points".to_string());
+        }
+        if !(1..=60).contains(&self.cross_start) {
+            return Err("Crosses must start between 1 and 60 points".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_valid() {
+        for name in PRESETS {
+            let rules = RuleSet::preset(name).unwrap();
+            assert_eq!(rules.name, name);
+            assert!(rules.validate().is_ok(), "{} should be valid", name);
+        }
+        assert!(RuleSet::preset("unknown").is_none());
+        assert_eq!(RuleSet::default(), RuleSet::standard());
+    }
+
+    #[test]
+    fn test_scores_for_clubs() {
+        let rules = RuleSet::standard();
+        assert_eq!(rules.scores_for("clubs").win, 4);
+        assert_eq!(rules.scores_for("hearts").win, 2);
+
+        let flat = RuleSet::preset("no_club_bonus").unwrap();
+        assert_eq!(flat.scores_for("clubs"), flat.scores_for("hearts"));
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_rules() {
+        let mut rules = RuleSet::standard();
+        rules.min_bid = 9;
+        assert!(rules.validate().is_err());
+
+        let mut rules = RuleSet::standard();
+        rules.win_points = 60;
+        assert!(rules.validate().is_err());
+
+        let mut rules = RuleSet::standard();
+        rules.high_win_points = 70;
+        rules.win_points = 80;
+        assert!(rules.validate().is_err());
+
+        let mut rules = RuleSet::standard();
+        rules.scores.win = 0;
+        assert!(rules.validate().is_err());
+
+        let mut rules = RuleSet::standard();
+        rules.cross_start = 0;
+        assert!(rules.validate().is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::game::rules::RuleSet;
 use serde::{Deserialize, Serialize};
 
 /// Sjavs scoring rules implementation
@@ -18,33 +19,30 @@ pub struct SjavsScoring {
 }
 
 impl SjavsScoring {
-    /// Calculate the game result using authentic Sjavs scoring rules
-    pub fn calculate_game_result(&self) -> GameResult {
-        let is_clubs = self.trump_suit == "clubs";
+    /// Calculate the game result under the match's rule set
+    pub fn calculate_game_result(&self, rules: &RuleSet) -> GameResult {
+        let scores = rules.scores_for(&self.trump_suit);
 
         // Check for "Vol" (all tricks)
         if self.trump_team_tricks == 8 {
             if self.individual_vol {
                 // Single player from trump team won all tricks
                 GameResult {
-                    trump_team_score: if is_clubs { 24 } else { 16 },
+                    trump_team_score: scores.individual_vol,
                     opponent_team_score: 0,
                     result_type: SjavsResult::IndividualVol,
                     description: format!(
                         "Individual Vol - {} points for trump team",
-                        if is_clubs { 24 } else { 16 }
+                        scores.individual_vol
                     ),
                 }
             } else {
                 // Trump team won all tricks
                 GameResult {
-                    trump_team_score: if is_clubs { 16 } else { 12 },
+                    trump_team_score: scores.vol,
                     opponent_team_score: 0,
                     result_type: SjavsResult::Vol,
-                    description: format!(
-                        "Vol - {} points for trump team",
-                        if is_clubs { 16 } else { 12 }
-                    ),
+                    description: format!("Vol - {} points for trump team", scores.vol),
                 }
             }
         }
@@ -52,9 +50,9 @@ impl SjavsScoring {
         else if self.opponent_team_tricks == 8 {
             GameResult {
                 trump_team_score: 0,
-                opponent_team_score: 16, // Always 16 regardless of trump suit
+                opponent_team_score: rules.opponent_vol, // Same regardless of trump suit
                 result_type: SjavsResult::OpponentVol,
-                description: "Opponents won all tricks - 16 points".to_string(),
+                description: format!("Opponents won all tricks - {} points", rules.opponent_vol),
             }
         }
         // Check for tie (both teams have 60 points)
@@ -63,67 +61,70 @@ impl SjavsScoring {
                 trump_team_score: 0,
                 opponent_team_score: 0,
                 result_type: SjavsResult::Tie,
-                description: "Tie at 60-60 - no score, next game worth 2 extra points".to_string(),
+                description: format!(
+                    "Tie at 60-60 - no score, next game worth {} extra points",
+                    rules.tie_bonus
+                ),
             }
         }
         // Normal scoring based on trump team points
-        else {
-            match self.trump_team_points {
-                90..=120 => GameResult {
-                    trump_team_score: if is_clubs { 8 } else { 4 },
-                    opponent_team_score: 0,
-                    result_type: SjavsResult::TrumpTeamWin,
-                    description: format!(
-                        "Trump team 90-120 points - {} points",
-                        if is_clubs { 8 } else { 4 }
-                    ),
-                },
-                61..=89 => GameResult {
-                    trump_team_score: if is_clubs { 4 } else { 2 },
-                    opponent_team_score: 0,
-                    result_type: SjavsResult::TrumpTeamWin,
-                    description: format!(
-                        "Trump team 61-89 points - {} points",
-                        if is_clubs { 4 } else { 2 }
-                    ),
-                },
-                31..=59 => {
-                    // Trump team failed but avoided double loss ("at vera javnfrujjur")
-                    GameResult {
-                        trump_team_score: 0,
-                        opponent_team_score: if is_clubs { 8 } else { 4 },
-                        result_type: SjavsResult::OpponentWin,
-                        description: format!(
-                            "Trump team 31-59 points (avoided double) - opponents get {} points",
-                            if is_clubs { 8 } else { 4 }
-                        ),
-                    }
-                }
-                1..=30 => {
-                    // Trump team suffered double loss
-                    GameResult {
-                        trump_team_score: 0,
-                        opponent_team_score: if is_clubs { 16 } else { 8 },
-                        result_type: SjavsResult::OpponentDoubleWin,
-                        description: format!(
-                            "Trump team 0-30 points (double loss) - opponents get {} points",
-                            if is_clubs { 16 } else { 8 }
-                        ),
-                    }
-                }
-                0 => {
-                    // Trump team got no points at all
-                    GameResult {
-                        trump_team_score: 0,
-                        opponent_team_score: if is_clubs { 16 } else { 8 },
-                        result_type: SjavsResult::OpponentDoubleWin,
-                        description: format!(
-                            "Trump team 0 points - opponents get {} points",
-                            if is_clubs { 16 } else { 8 }
-                        ),
-                    }
-                }
-                _ => unreachable!("Invalid point total"),
+        else if self.trump_team_points >= rules.high_win_points {
+            GameResult {
+                trump_team_score: scores.high_win,
+                opponent_team_score: 0,
+                result_type: SjavsResult::TrumpTeamWin,
+                description: format!(
+                    "Trump team {}-120 points - {} points",
+                    rules.high_win_points, scores.high_win
+                ),
+            }
+        } else if self.trump_team_points >= rules.win_points {
+            GameResult {
+                trump_team_score: scores.win,
+                opponent_team_score: 0,
+                result_type: SjavsResult::TrumpTeamWin,
+                description: format!(
+                    "Trump team {}-{} points - {} points",
+                    rules.win_points,
+                    rules.high_win_points - 1,
+                    scores.win
+                ),
+            }
+        } else if self.trump_team_points >= rules.avoid_double_points {
+            // Trump team failed but avoided double loss ("at vera javnfrujjur")
+            GameResult {
+                trump_team_score: 0,
+                opponent_team_score: scores.loss,
+                result_type: SjavsResult::OpponentWin,
+                description: format!(
+                    "Trump team {}-{} points (avoided double) - opponents get {} points",
+                    rules.avoid_double_points,
+                    rules.win_points - 1,
+                    scores.loss
+                ),
+            }
+        } else if self.trump_team_points > 0 {
+            // Trump team suffered double loss
+            GameResult {
+                trump_team_score: 0,
+                opponent_team_score: scores.double_loss,
+                result_type: SjavsResult::OpponentDoubleWin,
+                description: format!(
+                    "Trump team 0-{} points (double loss) - opponents get {} points",
+                    rules.avoid_double_points - 1,
+                    scores.double_loss
+                ),
+            }
+        } else {
+            // Trump team got no points at all
+            GameResult {
+                trump_team_score: 0,
+                opponent_team_score: scores.double_loss,
+                result_type: SjavsResult::OpponentDoubleWin,
+                description: format!(
+                    "Trump team 0 points - opponents get {} points",
+                    scores.double_loss
+                ),
             }
         }
     }
@@ -186,7 +187,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 12);
         assert_eq!(result.result_type, SjavsResult::Vol);
     }
@@ -202,7 +203,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 16);
         assert_eq!(result.result_type, SjavsResult::Vol);
     }
@@ -218,7 +219,7 @@ mod tests {
             individual_vol: true,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 16);
         assert_eq!(result.result_type, SjavsResult::IndividualVol);
     }
@@ -234,7 +235,7 @@ mod tests {
             individual_vol: true,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 24);
         assert_eq!(result.result_type, SjavsResult::IndividualVol);
     }
@@ -250,7 +251,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 0);
         assert_eq!(result.opponent_team_score, 0);
         assert_eq!(result.result_type, SjavsResult::Tie);
@@ -267,7 +268,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 0);
         assert_eq!(result.opponent_team_score, 8);
         assert_eq!(result.result_type, SjavsResult::OpponentDoubleWin);
@@ -284,7 +285,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 0);
         assert_eq!(result.opponent_team_score, 4);
         assert_eq!(result.result_type, SjavsResult::OpponentWin);
@@ -302,7 +303,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 4);
         assert_eq!(result.opponent_team_score, 0);
         assert_eq!(result.result_type, SjavsResult::TrumpTeamWin);
@@ -319,7 +320,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 2);
         assert_eq!(result.opponent_team_score, 0);
         assert_eq!(result.result_type, SjavsResult::TrumpTeamWin);
@@ -336,7 +337,7 @@ mod tests {
             individual_vol: false,
         };
 
-        let result = scoring.calculate_game_result();
+        let result = scoring.calculate_game_result(&RuleSet::standard());
         assert_eq!(result.trump_team_score, 0);
         assert_eq!(result.opponent_team_score, 16);
         assert_eq!(result.result_type, SjavsResult::OpponentVol);
@@ -366,4 +367,29 @@ mod tests {
 
         assert!(!invalid_scoring.validate_total_points());
     }
+
+    #[test]
+    fn test_rule_set_changes_scores() {
+        let scoring = SjavsScoring {
+            trump_team_points: 85,
+            opponent_team_points: 35,
+            trump_team_tricks: 6,
+            opponent_team_tricks: 2,
+            trump_suit: "clubs".to_string(),
+            individual_vol: false,
+        };
+
+        let standard = scoring.calculate_game_result(&RuleSet::standard());
+        assert_eq!(standard.trump_team_score, 4);
+
+        let flat = scoring.calculate_game_result(&RuleSet::preset("no_club_bonus").unwrap());
+        assert_eq!(flat.trump_team_score, 2);
+
+        // A lower high win threshold turns the same points into a high win
+        let mut rules = RuleSet::standard();
+        rules.high_win_points = 80;
+        let result = scoring.calculate_game_result(&rules);
+        assert_eq!(result.trump_team_score, 8);
+        assert_eq!(result.result_type, SjavsResult::TrumpTeamWin);
+    }
 }
@@ -0,0 +1,385 @@
+use crate::game::bot::{BotDifficulty, RuleBot};
+use crate::game::cross::{CrossState, CrossTeam};
+use crate::game::dataset::{DealRecorder, DecisionRecord};
+use crate::game::deck::Deck;
+use crate::game::engine::{GameAction, GameEvent, GamePhase, SjavsGame};
+use crate::game::external::EngineRunner;
+use crate::game::hand::Hand;
+use crate::game::mcts::{MctsBot, MctsConfig};
+use crate::game::rules::RuleSet;
+use crate::game::scoring::{GameResult, SjavsResult, SjavsScoring};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::BTreeMap;
+
+/// Deals in a row where everybody passes before a simulation gives up
+const MAX_REDEALS: u32 = 100;
+
+/// Settings for a batch of bot-vs-bot games
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    /// Number of complete games to play
+    pub games: u32,
+    /// Seed for every deal and search; the same seed plays the same games
+    pub seed: u64,
+    /// Bot playing each seat; seats 0 and 2 are partners, as are 1 and 3
+    pub seats: [BotDifficulty; 4],
+    pub rules: RuleSet,
+    /// Search iterations per move for hard bots (no time limit, so runs stay reproducible)
+    pub mcts_iterations: u32,
+    /// Keep every decision point for dataset export
+    pub record_decisions: bool,
+    /// Runs `external:<name>` seats; without it they play as the rule-based bot
+    pub engines: Option<EngineRunner>,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            games: 1000,
+            seed: 0,
+            seats: Default::default(),
+            rules: RuleSet::standard(),
+            mcts_iterations: 200,
+            record_decisions: false,
+            engines: None,
+        }
+    }
+}
+
+/// Outcomes for one trump suit and bid length
+#[derive(Debug, Clone, Default)]
+pub struct ContractStats {
+    pub games: u32,
+    /// Games the declaring team won, including vol
+    pub declarer_wins: u32,
+    /// Card points taken by the declaring team, summed over all games
+    pub trump_team_points: u32,
+    /// How often each `SjavsResult` occurred
+    pub results: BTreeMap<String, u32>,
+}
+
+impl ContractStats {
+    fn record(&mut self, scoring: &SjavsScoring, result: &GameResult) {
+        self.games += 1;
+        if declarer_won(&result.result_type) {
+            self.declarer_wins += 1;
+        }
+        self.trump_team_points += scoring.trump_team_points as u32;
+        *self
+            .results
+            .entry(format!("{:?}", result.result_type))
+            .or_default() += 1;
+    }
+
+    /// Average card points of the declaring team
+    pub fn average_points(&self) -> f64 {
+        average(self.trump_team_points as f64, self.games)
+    }
+
+    /// Share of games the declaring team won (0.0 - 1.0)
+    pub fn win_rate(&self) -> f64 {
+        average(self.declarer_wins as f64, self.games)
+    }
+}
+
+/// Totals from a finished simulation
+#[derive(Debug, Clone, Default)]
+pub struct SimulationReport {
+    /// All games together
+    pub overall: ContractStats,
+    /// Games grouped by trump suit
+    pub by_suit: BTreeMap<String, ContractStats>,
+    /// Games grouped by trump suit and bid length
+    pub by_contract: BTreeMap<(String, u8), ContractStats>,
+    /// Deals thrown in because everybody passed
+    pub redeals: u32,
+    /// Games won per partnership: seats 0/2, then seats 1/3
+    pub team_games_won: [u32; 2],
+    /// Crosses won per partnership: seats 0/2, then seats 1/3
+    pub team_crosses_won: [u32; 2],
+    /// Number of games each finished cross took
+    pub cross_lengths: Vec<u32>,
+}
+
+impl SimulationReport {
+    /// Average number of games per finished cross
+    pub fn average_cross_length(&self) -> f64 {
+        average(
+            self.cross_lengths.iter().sum::<u32>() as f64,
+            self.cross_lengths.len() as u32,
+        )
+    }
+}
+
+/// A game played out by bots
+#[derive(Debug, Clone)]
+pub struct SimulatedGame {
+    pub declarer: usize,
+    pub trump_suit: String,
+    pub bid_length: u8,
+    pub scoring: SjavsScoring,
+    pub result: GameResult,
+    /// Deals thrown in before this one was bid
+    pub redeals: u32,
+    /// Every decision of every deal, thrown-in ones included (when recording)
+    pub decisions: Vec<DecisionRecord>,
+}
+
+/// Play `config.games` complete games in-process and tally the results
+///
+/// The deal rotates clockwise after every game and games are chained into
+/// crosses with the configured rules, just like a match at a real table.
+pub fn run(config: &SimulationConfig) -> Result<SimulationReport, String> {
+    run_with(config, |_| Ok(()))
+}
+
+/// Like `run`, handing each game to `on_game` as soon as it is played
+///
+/// Lets callers stream decisions to disk instead of keeping every game in memory.
+pub fn run_with(
+    config: &SimulationConfig,
+    mut on_game: impl FnMut(&SimulatedGame) -> Result<(), String>,
+) -> Result<SimulationReport, String> {
+    config.rules.validate()?;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    let mut report = SimulationReport::default();
+    let mut cross = CrossState::new("simulation".to_string(), &config.rules);
+    let mut cross_games = 0;
+    let mut dealer = 0;
+
+    for game_number in 0..config.games {
+        let game = play_game(config, game_number, dealer, &cross, &mut rng)?;
+        on_game(&game)?;
+        report.redeals += game.redeals;
+
+        report.overall.record(&game.scoring, &game.result);
+        report
+            .by_suit
+            .entry(game.trump_suit.clone())
+            .or_default()
+            .record(&game.scoring, &game.result);
+        report
+            .by_contract
+            .entry((game.trump_suit.clone(), game.bid_length))
+            .or_default()
+            .record(&game.scoring, &game.result);
+
+        let declaring_team = game.declarer % 2;
+        if declarer_won(&game.result.result_type) {
+            report.team_games_won[declaring_team] += 1;
+        } else if game.result.result_type != SjavsResult::Tie {
+            report.team_games_won[1 - declaring_team] += 1;
+        }
+
+        cross_games += 1;
+        let cross_result = cross.apply_game_result_for_declarer(&game.result, game.declarer);
+        if let Some(winner) = cross_result.cross_won {
+            let team = match winner.winning_team {
+                CrossTeam::TrumpTeam => 0,
+                CrossTeam::OpponentTeam => 1,
+            };
+            report.team_crosses_won[team] += 1;
+            report.cross_lengths.push(cross_games);
+            cross_games = 0;
+            cross.reset_for_new_cross();
+        }
+
+        dealer = (dealer + 1) % 4;
+    }
+
+    Ok(report)
+}
+
+/// Deal and play one game to the end, redealing while everybody passes
+pub fn play_game(
+    config: &SimulationConfig,
+    game_number: u32,
+    dealer: usize,
+    cross: &CrossState,
+    rng: &mut ChaCha8Rng,
+) -> Result<SimulatedGame, String> {
+    let mut decisions = Vec::new();
+
+    for redeals in 0..MAX_REDEALS {
+        let hands = Deck::deal_until_valid_seeded(rng.gen(), config.rules.min_bid).0;
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(hands[i].clone(), i));
+        let mut game = SjavsGame::new("simulation".to_string(), dealer, hands)
+            .with_rules(config.rules.clone());
+
+        let mut recorder = DealRecorder::new(game_number, redeals);
+        let mut bidding = Vec::new();
+        let mut completed = None;
+        while let Some(player) = game.current_player() {
+            let action = choose_action(config, &game, player, &bidding, rng)
+                .ok_or_else(|| format!("Bot in seat {} did not act", player))?;
+            if config.record_decisions {
+                recorder.record(&game, cross, &action);
+            }
+            if game.phase == GamePhase::Bidding {
+                bidding.push(action.clone());
+            }
+            for event in game.apply(action)? {
+                if let GameEvent::GameComplete { scoring, result } = event {
+                    completed = Some((scoring, result));
+                }
+            }
+        }
+
+        if game.phase == GamePhase::Redeal {
+            decisions.extend(recorder.thrown_in());
+            continue;
+        }
+
+        let (Some((scoring, result)), Some(bid)) = (completed, game.highest_bid) else {
+            return Err(format!("Game ended in {:?} phase unscored", game.phase));
+        };
+        decisions.extend(recorder.finish(&bid, &scoring, &result));
+        return Ok(SimulatedGame {
+            declarer: bid.player,
+            trump_suit: bid.suit,
+            bid_length: bid.length,
+            scoring,
+            result,
+            redeals,
+            decisions,
+        });
+    }
+
+    Err(format!("Everybody passed {} deals in a row", MAX_REDEALS))
+}
+
+/// Ask the seat's bot for its action, seeding searches from the simulation RNG
+fn choose_action(
+    config: &SimulationConfig,
+    game: &SjavsGame,
+    player: usize,
+    bidding: &[GameAction],
+    rng: &mut ChaCha8Rng,
+) -> Option<GameAction> {
+    match &config.seats[player] {
+        BotDifficulty::Normal => RuleBot::choose_action(game, player),
+        BotDifficulty::Hard => {
+            let search = MctsConfig {
+                iterations: config.mcts_iterations,
+                time_limit: None,
+                seed: Some(rng.gen()),
+                ..MctsConfig::default()
+            };
+            MctsBot::choose_action(game, player, &search)
+        }
+        external @ BotDifficulty::External(_) => {
+            external.choose_action(game, player, bidding, config.engines)
+        }
+    }
+}
+
+fn declarer_won(result: &SjavsResult) -> bool {
+    matches!(
+        result,
+        SjavsResult::TrumpTeamWin | SjavsResult::Vol | SjavsResult::IndividualVol
+    )
+}
+
+fn average(total: f64, count: u32) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(games: u32, seed: u64) -> SimulationConfig {
+        SimulationConfig {
+            games,
+            seed,
+            ..SimulationConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_simulation_is_reproducible() {
+        let first = run(&config(30, 7)).unwrap();
+        let second = run(&config(30, 7)).unwrap();
+
+        assert_eq!(first.overall.games, 30);
+        assert_eq!(first.overall.results, second.overall.results);
+        assert_eq!(
+            first.overall.trump_team_points,
+            second.overall.trump_team_points
+        );
+        assert_eq!(first.cross_lengths, second.cross_lengths);
+    }
+
+    #[test]
+    fn test_recorded_decisions() {
+        let config = SimulationConfig {
+            record_decisions: true,
+            ..config(1, 3)
+        };
+        let mut cross = CrossState::new("simulation".to_string(), &config.rules);
+        cross.trump_team_score = 10;
+        let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+        let game = play_game(&config, 0, 2, &cross, &mut rng).unwrap();
+
+        let played: Vec<_> = game
+            .decisions
+            .iter()
+            .filter(|record| record.deal == game.redeals)
+            .collect();
+        let cards = played
+            .iter()
+            .filter(|record| record.phase == GamePhase::Playing)
+            .count();
+        assert_eq!(cards, 32);
+        assert_eq!(played[0].decision, 0);
+        assert_eq!(played[0].seat, 3); // Left of dealer bids first
+
+        for record in &game.decisions {
+            assert!(record.legal_actions.contains(&record.action));
+            assert_eq!(record.observation.hand.player_position, record.seat);
+            let team_score = if record.seat % 2 == 1 { 24 } else { 10 };
+            assert_eq!(record.observation.cross.team, team_score);
+        }
+        assert!(played.iter().all(|record| record.outcome.is_some()));
+
+        // Bids and passes so far are visible to later bidders
+        let last_bid = played
+            .iter()
+            .rev()
+            .find(|record| record.phase == GamePhase::Bidding)
+            .unwrap();
+        assert_eq!(last_bid.observation.bidding.len() as u32, last_bid.decision);
+
+        // Records survive a round trip through JSONL
+        let line = serde_json::to_string(played.last().unwrap()).unwrap();
+        let back: DecisionRecord = serde_json::from_str(&line).unwrap();
+        assert_eq!(back.action, played.last().unwrap().action);
+    }
+
+    #[test]
+    fn test_report_breakdowns_add_up() {
+        let report = run(&config(40, 1)).unwrap();
+
+        let by_suit: u32 = report.by_suit.values().map(|stats| stats.games).sum();
+        let by_contract: u32 = report.by_contract.values().map(|stats| stats.games).sum();
+        let by_result: u32 = report.overall.results.values().sum();
+        assert_eq!(by_suit, 40);
+        assert_eq!(by_contract, 40);
+        assert_eq!(by_result, 40);
+
+        assert!(report
+            .by_contract
+            .keys()
+            .all(|(_, length)| (5..=8).contains(length)));
+        assert_eq!(
+            report.team_crosses_won.iter().sum::<u32>() as usize,
+            report.cross_lengths.len()
+        );
+    }
+}
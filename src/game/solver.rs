@@ -0,0 +1,675 @@
+use crate::game::card::{Card, CardSet, Suit};
+use crate::game::engine::SjavsGame;
+use crate::game::trick::TrickState;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// Bit indices set in a card mask, lowest first
+fn cards(mut mask: u32) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let index = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(index)
+    })
+}
+
+/// Cheap hasher for transposition keys, which are already well mixed card masks
+#[derive(Default)]
+struct PositionHasher(u64);
+
+impl Hasher for PositionHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.write_u64(byte as u64);
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 = (self.0.rotate_left(5) ^ value).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+/// (lower, upper) bounds per (hands, leader) at trick starts
+type TranspositionTable = HashMap<([u32; 4], usize), (u8, u8), BuildHasherDefault<PositionHasher>>;
+
+/// What the solver maximises for the trump team
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// Card points (0-120 over a full game)
+    Points,
+    /// Tricks (0-8 over a full game)
+    Tricks,
+}
+
+/// Outcome of perfect play from the current position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveResult {
+    /// Card points the trump team can force from here, including cards already in the current trick
+    pub trump_team_points: u8,
+    /// Tricks the trump team can force from here, including the current trick
+    pub trump_team_tricks: u8,
+    /// A card for the player to move that achieves `trump_team_points`
+    pub best_card: Option<Card>,
+}
+
+/// Per-card lookup tables for one trump suit
+struct Tables {
+    /// Trumps rank above all plain cards; higher is stronger
+    strength: [u8; 32],
+    trump_mask: u32,
+    points: [u8; 32],
+    suit_masks: [u32; 4],
+    /// Every card ordered strongest first
+    by_strength: [usize; 32],
+    /// Per card, the cards with a higher strength
+    stronger: [u32; 32],
+}
+
+impl Tables {
+    fn new(trump: Suit) -> Self {
+        let mut tables = Tables {
+            strength: [0; 32],
+            trump_mask: 0,
+            points: [0; 32],
+            suit_masks: [0; 4],
+            by_strength: [0; 32],
+            stronger: [0; 32],
+        };
+
+        for index in 0..32 {
+            let card = Card::from_index(index);
+            tables.points[index] = card.point_value();
+            tables.suit_masks[index / 8] |= 1 << index;
+            tables.strength[index] = match card.trump_order(trump) {
+                Some(order) => {
+                    tables.trump_mask |= 1 << index;
+                    order + 20
+                }
+                None => card.non_trump_order(),
+            };
+        }
+
+        for index in 0..32 {
+            tables.by_strength[index] = index;
+            tables.stronger[index] = (0..32)
+                .filter(|&other| tables.strength[other] > tables.strength[index])
+                .fold(0, |mask, other| mask | 1 << other);
+        }
+        let strength = tables.strength;
+        tables
+            .by_strength
+            .sort_by_key(|&index| std::cmp::Reverse(strength[index]));
+
+        tables
+    }
+
+    fn is_trump(&self, index: usize) -> bool {
+        self.trump_mask & (1 << index) != 0
+    }
+
+    /// Same rule as `Card::beats`: trumps win, otherwise only the lead suit counts
+    fn beats(&self, card: usize, other: usize, lead_suit: usize) -> bool {
+        match (self.is_trump(card), self.is_trump(other)) {
+            (true, true) => self.strength[card] > self.strength[other],
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => {
+                card / 8 == lead_suit
+                    && (other / 8 != lead_suit || self.strength[card] > self.strength[other])
+            }
+        }
+    }
+
+    /// Cards that compete with `index` for a trick: all trumps, or its own plain suit
+    fn rivals(&self, index: usize) -> u32 {
+        if self.is_trump(index) {
+            self.trump_mask
+        } else {
+            self.suit_masks[index / 8] & !self.trump_mask
+        }
+    }
+}
+
+/// Position during search
+#[derive(Clone, Copy)]
+struct Position {
+    hands: [u32; 4],
+    /// Cards in the current trick, in play order
+    trick: [(usize, usize); 4],
+    trick_len: usize,
+    to_move: usize,
+}
+
+impl Position {
+    fn trick_cards(&self) -> &[(usize, usize)] {
+        &self.trick[..self.trick_len]
+    }
+}
+
+/// Double-dummy solver for Sjavs trick play
+///
+/// Searches the remaining tricks with every hand visible, using alpha-beta
+/// pruning, a transposition table keyed on the hands at each trick start
+/// and 32-bit card masks. Follow-suit rules match `TrickState::get_legal_cards`.
+pub struct DoubleDummySolver {
+    tables: Tables,
+    start: Position,
+    trump_team: [bool; 4],
+    objective: Objective,
+    transpositions: TranspositionTable,
+    /// Nodes visited by the last search
+    pub nodes: u64,
+}
+
+impl DoubleDummySolver {
+    /// Set up a position from the remaining hands and the trick in progress
+    pub fn new(
+        hands: &[Vec<Card>; 4],
+        trump_suit: Suit,
+        trick: &TrickState,
+        trump_team: (usize, usize),
+    ) -> Result<Self, String> {
+        if trick.is_complete {
+            return Err("Trick is already complete".to_string());
+        }
+
+        let masks = [0, 1, 2, 3].map(|seat| CardSet::from_cards(&hands[seat]).bits());
+        let all_cards: usize = hands.iter().map(|hand| hand.len()).sum();
+        if masks
+            .iter()
+            .map(|mask| mask.count_ones() as usize)
+            .sum::<usize>()
+            != all_cards
+        {
+            return Err("Hands contain duplicate cards".to_string());
+        }
+
+        // Players who already played to this trick hold one card fewer
+        let played: Vec<usize> = trick.cards_played.iter().map(|(seat, _)| *seat).collect();
+        let max_len = hands.iter().map(|hand| hand.len()).max().unwrap_or(0);
+        for (seat, hand) in hands.iter().enumerate() {
+            let expected = if played.contains(&seat) {
+                max_len.saturating_sub(1)
+            } else {
+                max_len
+            };
+            if hand.len() != expected {
+                return Err(format!("Seat {} holds the wrong number of cards", seat));
+            }
+        }
+
+        let mut start = Position {
+            hands: masks,
+            trick: [(0, 0); 4],
+            trick_len: trick.cards_played.len(),
+            to_move: trick.current_player,
+        };
+        for (slot, (seat, card)) in trick.cards_played.iter().enumerate() {
+            start.trick[slot] = (*seat, card.index());
+        }
+
+        let mut on_trump_team = [false; 4];
+        on_trump_team[trump_team.0 % 4] = true;
+        on_trump_team[trump_team.1 % 4] = true;
+
+        Ok(Self {
+            tables: Tables::new(trump_suit),
+            start,
+            trump_team: on_trump_team,
+            objective: Objective::Points,
+            transpositions: HashMap::default(),
+            nodes: 0,
+        })
+    }
+
+    /// Set up the current position of a game in the playing phase
+    pub fn from_game(game: &SjavsGame) -> Result<Self, String> {
+        let tricks = game.tricks.as_ref().ok_or("Game is not in playing phase")?;
+        let hands = [0, 1, 2, 3].map(|seat| game.hands[seat].cards.clone());
+        Self::new(
+            &hands,
+            Suit::from(tricks.current_trick.trump_suit.as_str()),
+            &tricks.current_trick,
+            tricks.trump_team,
+        )
+    }
+
+    /// Play a card from the current position, keeping what the search has learnt
+    ///
+    /// Use this to walk through a game card by card: later positions reuse the
+    /// transposition table built for earlier ones.
+    pub fn advance(&mut self, card: Card) -> Result<(), String> {
+        let index = card.index();
+        if self.legal_cards(&self.start) & (1 << index) == 0 {
+            return Err(format!(
+                "{} is not a legal card for seat {}",
+                card.code(),
+                self.start.to_move
+            ));
+        }
+
+        self.start = self.play(self.start, index).0;
+        Ok(())
+    }
+
+    /// Solve the position for both points and tricks
+    pub fn solve(&mut self) -> SolveResult {
+        let start = self.start;
+
+        self.set_objective(Objective::Points);
+        let trump_team_points = self.exact_value(start);
+        let maximizing = self.trump_team[start.to_move];
+        let best_card = self.legal_moves(&start).into_iter().find(|&card| {
+            let (next, gained) = self.play(start, card);
+            if maximizing {
+                let target = trump_team_points.saturating_sub(gained);
+                gained + self.search(next, target.saturating_sub(1), target) >= trump_team_points
+            } else {
+                let target = trump_team_points.saturating_sub(gained);
+                gained + self.search(next, target, target + 1) <= trump_team_points
+            }
+        });
+
+        self.set_objective(Objective::Tricks);
+        let trump_team_tricks = self.exact_value(start);
+
+        SolveResult {
+            trump_team_points,
+            trump_team_tricks,
+            best_card: best_card.map(Card::from_index),
+        }
+    }
+
+    /// Value for the trump team of each legal card for the player to move
+    ///
+    /// Values include the current trick, so the difference between a card's
+    /// value and the best one is exactly what choosing it costs.
+    pub fn evaluate_moves(&mut self, objective: Objective) -> Vec<(Card, u8)> {
+        self.set_objective(objective);
+        let start = self.start;
+
+        cards(self.legal_cards(&start))
+            .map(|card| {
+                let (next, gained) = self.play(start, card);
+                (Card::from_index(card), gained + self.exact_value(next))
+            })
+            .collect()
+    }
+
+    /// Exact value by narrowing the bounds with null-window searches
+    fn exact_value(&mut self, position: Position) -> u8 {
+        let (mut lower, mut upper) = (0, self.most_remaining(&position));
+        while lower < upper {
+            let guess = lower + (upper - lower).div_ceil(2);
+            let value = self.search(position, guess - 1, guess);
+            if value >= guess {
+                lower = value;
+            } else {
+                upper = value;
+            }
+        }
+        lower
+    }
+
+    fn set_objective(&mut self, objective: Objective) {
+        if self.objective != objective {
+            self.objective = objective;
+            self.transpositions.clear();
+        }
+        self.nodes = 0;
+    }
+
+    /// Mask of the cards the player to move may play
+    fn legal_cards(&self, position: &Position) -> u32 {
+        let hand = position.hands[position.to_move];
+        match position.trick_cards().first() {
+            Some(&(_, lead)) if hand & self.tables.suit_masks[lead / 8] != 0 => {
+                hand & self.tables.suit_masks[lead / 8]
+            }
+            _ => hand,
+        }
+    }
+
+    /// Legal cards for the player to move, with equivalent cards removed
+    fn legal_moves(&self, position: &Position) -> Vec<usize> {
+        let legal = self.legal_cards(position);
+
+        // Cards still in play, which decide whether two cards are interchangeable
+        let live = position.hands.iter().fold(0, |mask, hand| mask | hand)
+            | position
+                .trick_cards()
+                .iter()
+                .fold(0, |mask, (_, card)| mask | 1 << card);
+
+        let mut kept: Vec<usize> = Vec::with_capacity(legal.count_ones() as usize);
+        for &card in &self.tables.by_strength {
+            if legal & (1 << card) == 0 {
+                continue;
+            }
+            let redundant = kept.last().is_some_and(|&higher| {
+                higher / 8 == card / 8
+                    && self.tables.is_trump(higher) == self.tables.is_trump(card)
+                    && (self.objective == Objective::Tricks
+                        || self.tables.points[higher] == self.tables.points[card])
+                    && !self.between(higher, card, live)
+            });
+            if !redundant {
+                kept.push(card);
+            }
+        }
+
+        self.order_moves(position, &mut kept);
+        kept
+    }
+
+    /// Try the likeliest best cards first so alpha-beta cuts early
+    ///
+    /// Leaders try high cards first. Followers beat an opponent as cheaply as
+    /// possible, smear points onto a partner's trick, and otherwise discard low.
+    fn order_moves(&self, position: &Position, moves: &mut [usize]) {
+        let Some(&(leader, lead)) = position.trick_cards().first() else {
+            return;
+        };
+
+        let lead_suit = lead / 8;
+        let (winner, winning_card) =
+            position.trick_cards()[1..]
+                .iter()
+                .fold((leader, lead), |best, &(seat, card)| {
+                    if self.tables.beats(card, best.1, lead_suit) {
+                        (seat, card)
+                    } else {
+                        best
+                    }
+                });
+
+        let partner_winning = self.trump_team[winner] == self.trump_team[position.to_move];
+        moves.sort_by_key(|&card| {
+            let points = self.tables.points[card];
+            let strength = self.tables.strength[card];
+            if partner_winning {
+                (0, u8::MAX - points, strength)
+            } else if self.tables.beats(card, winning_card, lead_suit) {
+                (0, strength, points)
+            } else {
+                (1, points, strength)
+            }
+        });
+    }
+
+    /// Whether any live rival card ranks strictly between `higher` and `lower`
+    fn between(&self, higher: usize, lower: usize, live: u32) -> bool {
+        let above_lower = self.tables.stronger[lower] & !self.tables.stronger[higher];
+        live & self.tables.rivals(lower) & above_lower & !(1 << higher) != 0
+    }
+
+    /// Most the trump team can still gain, including the current trick
+    fn most_remaining(&self, position: &Position) -> u8 {
+        let remaining = position.hands.iter().fold(0, |mask, hand| mask | hand)
+            | position
+                .trick_cards()
+                .iter()
+                .fold(0, |mask, (_, card)| mask | 1 << card);
+        match self.objective {
+            Objective::Points => cards(remaining)
+                .map(|index| self.tables.points[index])
+                .sum(),
+            Objective::Tricks => position.hands[position.to_move].count_ones() as u8,
+        }
+    }
+
+    /// Play a card, returning the new position and what the trump team gained
+    fn play(&self, mut position: Position, card: usize) -> (Position, u8) {
+        let seat = position.to_move;
+        position.hands[seat] &= !(1 << card);
+        position.trick[position.trick_len] = (seat, card);
+        position.trick_len += 1;
+
+        if position.trick_len < 4 {
+            position.to_move = (seat + 1) % 4;
+            return (position, 0);
+        }
+
+        let lead_suit = position.trick[0].1 / 8;
+        let (winner, _) =
+            position.trick[1..]
+                .iter()
+                .fold(position.trick[0], |best, &(seat, card)| {
+                    if self.tables.beats(card, best.1, lead_suit) {
+                        (seat, card)
+                    } else {
+                        best
+                    }
+                });
+
+        let gained = if self.trump_team[winner] {
+            match self.objective {
+                Objective::Points => position
+                    .trick
+                    .iter()
+                    .map(|(_, card)| self.tables.points[*card])
+                    .sum(),
+                Objective::Tricks => 1,
+            }
+        } else {
+            0
+        };
+
+        position.trick_len = 0;
+        position.to_move = winner;
+        (position, gained)
+    }
+
+    /// Alpha-beta search: trump team maximises, opponents minimise
+    fn search(&mut self, position: Position, mut alpha: u8, mut beta: u8) -> u8 {
+        self.nodes += 1;
+
+        let at_trick_start = position.trick_len == 0;
+        let key = (position.hands, position.to_move);
+        if at_trick_start {
+            if position.hands.iter().all(|&hand| hand == 0) {
+                return 0;
+            }
+            if beta == 0 {
+                return 0;
+            }
+            let most = self.most_remaining(&position);
+            if most <= alpha {
+                return most;
+            }
+            beta = beta.min(most);
+            if let Some(&(lower, upper)) = self.transpositions.get(&key) {
+                if lower == upper || lower >= beta {
+                    return lower;
+                }
+                if upper <= alpha {
+                    return upper;
+                }
+                alpha = alpha.max(lower);
+                beta = beta.min(upper);
+            }
+        }
+
+        let (window_alpha, window_beta) = (alpha, beta);
+        let maximizing = self.trump_team[position.to_move];
+        let mut best = if maximizing { 0 } else { u8::MAX };
+
+        for card in self.legal_moves(&position) {
+            let (next, gained) = self.play(position, card);
+            let value = gained
+                + self.search(
+                    next,
+                    alpha.saturating_sub(gained),
+                    beta.saturating_sub(gained),
+                );
+
+            if maximizing {
+                best = best.max(value);
+                alpha = alpha.max(value);
+            } else {
+                best = best.min(value);
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        if at_trick_start {
+            let bounds = self.transpositions.entry(key).or_insert((0, u8::MAX));
+            if best <= window_alpha {
+                bounds.1 = bounds.1.min(best);
+            } else if best >= window_beta {
+                bounds.0 = bounds.0.max(best);
+            } else {
+                *bounds = (best, best);
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::Deck;
+    use crate::game::trick::GameTrickState;
+
+    /// Plain minimax without pruning, tables or move merging
+    fn brute_force(solver: &DoubleDummySolver, position: Position) -> u8 {
+        if position.trick_len == 0 && position.hands.iter().all(|&hand| hand == 0) {
+            return 0;
+        }
+
+        let hand = position.hands[position.to_move];
+        let legal = match position.trick_cards().first() {
+            Some(&(_, lead)) if hand & solver.tables.suit_masks[lead / 8] != 0 => {
+                hand & solver.tables.suit_masks[lead / 8]
+            }
+            _ => hand,
+        };
+
+        let values = (0..32)
+            .filter(|&card| legal & (1 << card) != 0)
+            .map(|card| {
+                let (next, gained) = solver.play(position, card);
+                gained + brute_force(solver, next)
+            });
+        if solver.trump_team[position.to_move] {
+            values.max().unwrap()
+        } else {
+            values.min().unwrap()
+        }
+    }
+
+    /// A seeded deal cut down to `cards_left` cards per hand
+    fn endgame(seed: u64, cards_left: usize) -> [Vec<Card>; 4] {
+        let hands = Deck::deal_until_valid_seeded(seed, 5).0;
+        hands.map(|hand| hand[..cards_left].to_vec())
+    }
+
+    #[test]
+    fn test_matches_brute_force_on_endgames() {
+        for seed in 0..6 {
+            let hands = endgame(seed, 4);
+            let trick = TrickState::new("solver".to_string(), 5, 1, "hearts".to_string());
+            let mut solver = DoubleDummySolver::new(&hands, Suit::Hearts, &trick, (0, 2)).unwrap();
+
+            let result = solver.solve();
+            let start = solver.start;
+            solver.set_objective(Objective::Points);
+            assert_eq!(result.trump_team_points, brute_force(&solver, start));
+            solver.set_objective(Objective::Tricks);
+            assert_eq!(result.trump_team_tricks, brute_force(&solver, start));
+        }
+    }
+
+    #[test]
+    fn test_best_card_achieves_solved_value() {
+        let hands = endgame(11, 5);
+        let trick = TrickState::new("solver".to_string(), 4, 0, "clubs".to_string());
+        let mut solver = DoubleDummySolver::new(&hands, Suit::Clubs, &trick, (0, 2)).unwrap();
+
+        let result = solver.solve();
+        let best_card = result.best_card.expect("player to move has cards");
+        let values = solver.evaluate_moves(Objective::Points);
+
+        assert!(values.contains(&(best_card, result.trump_team_points)));
+        assert!(values
+            .iter()
+            .all(|(_, value)| *value <= result.trump_team_points));
+        assert!(result.trump_team_tricks <= 5);
+    }
+
+    #[test]
+    fn test_mid_trick_position() {
+        let hands = Deck::deal_until_valid_seeded(5, 5).0;
+        let mut trick = TrickState::new("solver".to_string(), 1, 0, "spades".to_string());
+        let mut remaining = hands.clone();
+        let lead = remaining[0].remove(0);
+        trick.play_card(0, lead).unwrap();
+
+        let mut solver = DoubleDummySolver::new(&remaining, Suit::Spades, &trick, (1, 3)).unwrap();
+        let result = solver.solve();
+
+        // Seat 1 must follow suit if it can
+        let best_card = result.best_card.unwrap();
+        if remaining[1].iter().any(|card| card.suit == lead.suit) {
+            assert_eq!(best_card.suit, lead.suit);
+        }
+        assert!(result.trump_team_points <= 120);
+        assert!(result.trump_team_tricks <= 8);
+
+        // Wrong hand sizes are rejected
+        assert!(DoubleDummySolver::new(&hands, Suit::Spades, &trick, (1, 3)).is_err());
+    }
+
+    #[test]
+    fn test_perfect_play_through_engine_scores_solved_value() {
+        let hands = Deck::deal_until_valid_seeded(42, 5).0;
+        let mut remaining = hands.clone();
+        let mut tricks = GameTrickState::new("solver".to_string(), 1, "clubs".to_string(), (0, 2));
+
+        let mut solver =
+            DoubleDummySolver::new(&hands, Suit::Clubs, &tricks.current_trick, (0, 2)).unwrap();
+        let expected = solver.solve().trump_team_points;
+
+        // Both sides keep playing a solver-optimal card
+        while !tricks.game_complete {
+            let mut solver =
+                DoubleDummySolver::new(&remaining, Suit::Clubs, &tricks.current_trick, (0, 2))
+                    .unwrap();
+            let result = solver.solve();
+            assert_eq!(
+                tricks.points_accumulated.0 as u16 + result.trump_team_points as u16,
+                expected as u16
+            );
+
+            let seat = tricks.current_trick.current_player;
+            let card = result.best_card.unwrap();
+            remaining[seat].retain(|held| *held != card);
+            tricks.current_trick.play_card(seat, card).unwrap();
+            if tricks.current_trick.is_complete {
+                tricks.complete_trick().unwrap();
+            }
+        }
+
+        assert_eq!(tricks.points_accumulated.0 as u16, expected as u16);
+    }
+}
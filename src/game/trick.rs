@@ -1,4 +1,6 @@
 use crate::game::card::{Card, Suit};
+use crate::game::engine::{Bid, GameAction};
+use crate::game::violation::RuleViolation;
 use serde::{Deserialize, Serialize};
 
 /// Current state of a trick in progress
@@ -50,23 +52,17 @@ impl TrickState {
     }
 
     /// Play a card to this trick
-    pub fn play_card(&mut self, player_position: usize, card: Card) -> Result<(), String> {
-        // Validate it's the player's turn
-        if player_position != self.current_player {
-            return Err(format!(
-                "Not your turn. Current player is {}",
-                self.current_player
-            ));
-        }
-
+    pub fn play_card(&mut self, player_position: usize, card: Card) -> Result<(), RuleViolation> {
         // Validate trick isn't complete
-        if self.is_complete {
-            return Err("Trick is already complete".to_string());
+        if self.is_complete || self.cards_played.len() >= 4 {
+            return Err(RuleViolation::TrickComplete);
         }
 
-        // Validate max 4 cards
-        if self.cards_played.len() >= 4 {
-            return Err("Trick already has 4 cards".to_string());
+        // Validate it's the player's turn
+        if player_position != self.current_player {
+            return Err(RuleViolation::NotYourTurn {
+                current_player: Some(self.current_player),
+            });
         }
 
         // Set lead suit if this is the first card
@@ -88,6 +84,26 @@ impl TrickState {
         Ok(())
     }
 
+    /// Take the last card back out of an unfinished trick, returning who played it
+    pub fn take_back_last_card(&mut self) -> Result<(usize, Card), String> {
+        if self.is_complete {
+            return Err("Trick is already complete".to_string());
+        }
+
+        let (player, card) = self
+            .cards_played
+            .pop()
+            .ok_or("No card has been played to this trick")?;
+
+        // It is the same player's turn again
+        self.current_player = player;
+        if self.cards_played.is_empty() {
+            self.lead_suit = None;
+        }
+
+        Ok((player, card))
+    }
+
     /// Determine the winner of this trick
     fn determine_winner(&self) -> usize {
         if self.cards_played.len() != 4 {
@@ -327,6 +343,45 @@ pub struct TrickCompletionResult {
     pub next_leader: Option<usize>,
 }
 
+/// A finished deal's winning bid and tricks, kept for review after the table moves on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayedDeal {
+    /// Position of the dealer
+    pub dealer: usize,
+
+    /// The bid that set trump
+    pub bid: Bid,
+
+    /// Every bid and pass in order
+    #[serde(default)]
+    pub bidding: Vec<GameAction>,
+
+    /// All 8 tricks in play order
+    pub tricks: Vec<TrickState>,
+
+    /// Hints each seat asked for during the deal
+    #[serde(default)]
+    pub hints_used: [u32; 4],
+}
+
+impl PlayedDeal {
+    /// Trump declaring team (trump_declarer, partner)
+    pub fn trump_team(&self) -> (usize, usize) {
+        (self.bid.player, (self.bid.player + 2) % 4)
+    }
+
+    /// The hands as dealt, rebuilt from the cards each player played
+    pub fn dealt_hands(&self) -> [Vec<Card>; 4] {
+        let mut hands: [Vec<Card>; 4] = Default::default();
+        for trick in &self.tricks {
+            for (player, card) in &trick.cards_played {
+                hands[*player].push(*card);
+            }
+        }
+        hands
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,138 @@
+use crate::game::card::{Card, Suit};
+use crate::game::engine::GamePhase;
+use serde::Serialize;
+use std::fmt;
+
+/// A game rule that an action breaks
+///
+/// Serializes with a snake_case `code` tag next to the details, so clients can
+/// react to the rule itself and word the message in their own language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum RuleViolation {
+    /// Seats are numbered 0-3
+    InvalidPlayer { player: usize },
+    /// The action belongs to another phase of the game
+    WrongPhase {
+        expected: GamePhase,
+        actual: GamePhase,
+    },
+    /// Another player is to act
+    NotYourTurn { current_player: Option<usize> },
+    /// The card isn't in the player's hand
+    CardNotInHand { card: Card },
+    /// A card of the led suit must be played while the player holds one
+    MustFollowSuit { lead_suit: Suit },
+    /// Trump was led and the player still holds trump
+    MustPlayTrump { trump_suit: String },
+    /// All four cards of the trick are already down
+    TrickComplete,
+    /// Bid length is outside what the rule set allows
+    BidOutOfRange { min: u8, max: u8 },
+    /// Not one of hearts, diamonds, clubs or spades
+    UnknownTrumpSuit { suit: String },
+    /// The player doesn't hold as many trumps as they bid
+    NotEnoughTrumps { suit: String, held: u8, bid: u8 },
+    /// The bid doesn't beat the highest bid
+    BidTooLow { min: u8 },
+    /// Only clubs may match the length of the highest bid
+    ClubPreferenceOnly { length: u8 },
+    /// The game state itself is inconsistent; no move by the player is at fault
+    Internal { reason: String },
+}
+
+impl RuleViolation {
+    /// The snake_case code clients match on
+    ///
+    /// Read back from the serialized `code` tag so it can't drift from the JSON.
+    pub fn code(&self) -> String {
+        let value = serde_json::to_value(self).unwrap_or_default();
+        value["code"].as_str().unwrap_or_default().to_string()
+    }
+
+    pub(crate) fn internal(reason: impl Into<String>) -> Self {
+        RuleViolation::Internal {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for RuleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleViolation::InvalidPlayer { player } => {
+                write!(f, "Invalid player position {}", player)
+            }
+            RuleViolation::WrongPhase { expected, .. } => write!(
+                f,
+                "Game is not in {} phase",
+                format!("{:?}", expected).to_lowercase()
+            ),
+            RuleViolation::NotYourTurn {
+                current_player: Some(current),
+            } => write!(f, "Not your turn. Current player is {}", current),
+            RuleViolation::NotYourTurn {
+                current_player: None,
+            } => write!(f, "Not your turn"),
+            RuleViolation::CardNotInHand { card } => {
+                write!(f, "You don't have the {} in your hand", card)
+            }
+            RuleViolation::MustFollowSuit { lead_suit } => {
+                write!(f, "Must follow suit ({})", lead_suit)
+            }
+            RuleViolation::MustPlayTrump { trump_suit } => {
+                write!(f, "Trump was led - you must play {}", trump_suit)
+            }
+            RuleViolation::TrickComplete => write!(f, "Trick is already complete"),
+            RuleViolation::BidOutOfRange { min, max } => {
+                write!(f, "Bid must be between {} and {} trumps", min, max)
+            }
+            RuleViolation::UnknownTrumpSuit { suit } => write!(f, "Invalid trump suit '{}'", suit),
+            RuleViolation::NotEnoughTrumps { suit, held, bid } => write!(
+                f,
+                "You only have {} {} trumps, but bid {} trumps",
+                held, suit, bid
+            ),
+            RuleViolation::BidTooLow { min } => write!(f, "Bid must be at least {} trumps", min),
+            RuleViolation::ClubPreferenceOnly { length } => write!(
+                f,
+                "Only clubs can match the current bid of {} trumps",
+                length
+            ),
+            RuleViolation::Internal { reason } => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for RuleViolation {}
+
+impl From<RuleViolation> for String {
+    fn from(violation: RuleViolation) -> Self {
+        violation.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::card::Rank;
+
+    #[test]
+    fn test_serializes_with_code_tag() {
+        let violation = RuleViolation::MustFollowSuit {
+            lead_suit: Suit::Hearts,
+        };
+        let json = serde_json::to_value(&violation).unwrap();
+        assert_eq!(json["code"], "must_follow_suit");
+        assert_eq!(violation.code(), "must_follow_suit");
+        assert_eq!(json["lead_suit"], "Hearts");
+
+        let json = serde_json::to_value(RuleViolation::TrickComplete).unwrap();
+        assert_eq!(json["code"], "trick_complete");
+
+        let card = Card::new(Suit::Spades, Rank::Ace);
+        let json = serde_json::to_value(RuleViolation::CardNotInHand { card }).unwrap();
+        assert_eq!(json["code"], "card_not_in_hand");
+        assert_eq!(json["card"]["suit"], "Spades");
+    }
+}
@@ -0,0 +1,5 @@
+//! Sjavs game logic and external bot engines, shared by the server and the
+//! `sjavs-sim` simulator
+
+pub mod bots;
+pub mod game;
@@ -5,13 +5,13 @@ use crate::websocket::handler::create_app_state;
 use crate::websocket::routes as ws_routes;
 use axum::Router;
 use hyper::http::{header, HeaderValue, Method};
+use sjavs_backend::{bots, game};
 use std::net::SocketAddr;
 use tower_http::cors::CorsLayer;
 
 mod api;
 mod auth;
 mod auth_layer;
-mod game;
 mod redis;
 mod websocket;
 
@@ -1,4 +1,5 @@
 use crate::game::cross::CrossState;
+use crate::game::rules::RuleSet;
 use deadpool_redis::Connection;
 use serde_json;
 
@@ -51,8 +52,9 @@ impl CrossStateRepository {
     pub async fn initialize_cross_state(
         conn: &mut Connection,
         match_id: &str,
+        rules: &RuleSet,
     ) -> Result<CrossState, String> {
-        let cross_state = CrossState::new(match_id.to_string());
+        let cross_state = CrossState::new(match_id.to_string(), rules);
         Self::store_cross_state(conn, match_id, &cross_state).await?;
         Ok(cross_state)
     }
@@ -74,10 +76,11 @@ impl CrossStateRepository {
     pub async fn get_or_create_cross_state(
         conn: &mut Connection,
         match_id: &str,
+        rules: &RuleSet,
     ) -> Result<CrossState, String> {
         match Self::get_cross_state(conn, match_id).await? {
             Some(state) => Ok(state),
-            None => Self::initialize_cross_state(conn, match_id).await,
+            None => Self::initialize_cross_state(conn, match_id, rules).await,
         }
     }
 
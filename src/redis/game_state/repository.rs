@@ -1,6 +1,9 @@
+use crate::game::deck::DealRecord;
+use crate::game::engine::GameAction;
 use crate::game::hand::Hand;
 use deadpool_redis::Connection;
 use serde_json;
+use std::collections::HashMap;
 
 pub struct GameStateRepository;
 
@@ -66,6 +69,22 @@ impl GameStateRepository {
         Ok(hands)
     }
 
+    /// Get all four hands of the current deal, failing if any is missing
+    pub async fn get_dealt_hands(conn: &mut Connection, game_id: &str) -> Result<[Hand; 4], String> {
+        let mut hands = Vec::with_capacity(4);
+
+        for i in 0..4 {
+            let hand = Self::get_hand(conn, game_id, i)
+                .await?
+                .ok_or_else(|| format!("Hand for position {} has not been dealt", i))?;
+            hands.push(hand);
+        }
+
+        hands
+            .try_into()
+            .map_err(|_| "Expected exactly 4 hands".to_string())
+    }
+
     /// Update a specific player's hand (for when cards are played)
     pub async fn update_hand(
         conn: &mut Connection,
@@ -101,6 +120,152 @@ impl GameStateRepository {
         Ok(())
     }
 
+    /// Append the seed behind a newly dealt set of hands to the game's deal history
+    ///
+    /// Returns the deal number (index in the history) of the stored record.
+    pub async fn store_deal_record(
+        conn: &mut Connection,
+        game_id: &str,
+        record: &DealRecord,
+    ) -> Result<usize, String> {
+        let key = format!("game:{}:deals", game_id);
+        let record_data = serde_json::to_string(record)
+            .map_err(|e| format!("Failed to serialize deal record: {}", e))?;
+
+        let deal_count: usize = redis::cmd("RPUSH")
+            .arg(&key)
+            .arg(&record_data)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store deal record: {}", e))?;
+
+        Ok(deal_count - 1)
+    }
+
+    /// Get every deal recorded for a game, oldest first
+    pub async fn get_deal_records(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<Vec<DealRecord>, String> {
+        let key = format!("game:{}:deals", game_id);
+
+        let records: Vec<String> = redis::cmd("LRANGE")
+            .arg(&key)
+            .arg(0)
+            .arg(-1)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to retrieve deal records: {}", e))?;
+
+        records
+            .iter()
+            .map(|data| {
+                serde_json::from_str(data)
+                    .map_err(|e| format!("Failed to deserialize deal record: {}", e))
+            })
+            .collect()
+    }
+
+    /// Append a bid or pass to the current deal's bidding history
+    pub async fn record_bidding_action(
+        conn: &mut Connection,
+        game_id: &str,
+        action: &GameAction,
+    ) -> Result<(), String> {
+        let key = format!("game:{}:bidding", game_id);
+        let action_data = serde_json::to_string(action)
+            .map_err(|e| format!("Failed to serialize bidding action: {}", e))?;
+
+        redis::cmd("RPUSH")
+            .arg(&key)
+            .arg(&action_data)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to record bidding action: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Get the current deal's bids and passes, oldest first
+    pub async fn get_bidding_actions(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<Vec<GameAction>, String> {
+        let key = format!("game:{}:bidding", game_id);
+
+        let actions: Vec<String> = redis::cmd("LRANGE")
+            .arg(&key)
+            .arg(0)
+            .arg(-1)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to retrieve bidding history: {}", e))?;
+
+        actions
+            .iter()
+            .map(|data| {
+                serde_json::from_str(data)
+                    .map_err(|e| format!("Failed to deserialize bidding action: {}", e))
+            })
+            .collect()
+    }
+
+    /// Forget the bidding history when a new deal starts
+    pub async fn clear_bidding_actions(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        let key = format!("game:{}:bidding", game_id);
+
+        redis::cmd("DEL")
+            .arg(&key)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear bidding history: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Count a hint asked for by the player in `seat` during the current deal
+    pub async fn record_hint_use(
+        conn: &mut Connection,
+        game_id: &str,
+        seat: usize,
+    ) -> Result<u32, String> {
+        let key = format!("game:{}:hints", game_id);
+
+        redis::cmd("HINCRBY")
+            .arg(&key)
+            .arg(seat)
+            .arg(1)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to record hint use: {}", e))
+    }
+
+    /// Hints asked for during the current deal, by seat
+    pub async fn get_hint_uses(conn: &mut Connection, game_id: &str) -> Result<[u32; 4], String> {
+        let key = format!("game:{}:hints", game_id);
+
+        let counts: HashMap<usize, u32> = redis::cmd("HGETALL")
+            .arg(&key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to retrieve hint uses: {}", e))?;
+
+        Ok([0, 1, 2, 3].map(|seat| counts.get(&seat).copied().unwrap_or(0)))
+    }
+
+    /// Forget hint uses when a new deal starts
+    pub async fn clear_hint_uses(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        let key = format!("game:{}:hints", game_id);
+
+        redis::cmd("DEL")
+            .arg(&key)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear hint uses: {}", e))?;
+
+        Ok(())
+    }
+
     /// Store game analysis data for debugging/statistics
     pub async fn store_hand_analysis(
         conn: &mut Connection,
@@ -0,0 +1,5 @@
+pub mod repository;
+pub mod token;
+
+pub use repository::{Invite, InviteRepository};
+pub use token::InviteToken;
@@ -0,0 +1,243 @@
+use crate::redis::invite::token::InviteToken;
+use deadpool_redis::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Key holding the shared signing secret when `SJAVS_INVITE_SECRET` is unset
+const SECRET_KEY: &str = "invite_secret";
+
+/// An invite the host created, with its use count so far
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invite {
+    pub invite_id: String,
+    pub game_id: String,
+    pub created_by: String,
+    pub max_uses: u32,
+    pub uses: u32,
+    /// Unix time (seconds) when the invite lapses
+    pub expires_at: u64,
+}
+
+impl Invite {
+    fn from_redis_hash(invite_id: &str, hash: &HashMap<String, String>) -> Option<Self> {
+        Some(Self {
+            invite_id: invite_id.to_string(),
+            game_id: hash.get("game_id")?.clone(),
+            created_by: hash.get("created_by")?.clone(),
+            max_uses: hash.get("max_uses")?.parse().ok()?,
+            uses: hash.get("uses")?.parse().ok()?,
+            expires_at: hash.get("expires_at")?.parse().ok()?,
+        })
+    }
+}
+
+pub struct InviteRepository;
+
+impl InviteRepository {
+    /// Secret invite tokens are signed with
+    ///
+    /// `SJAVS_INVITE_SECRET` if set, otherwise a random secret created on first
+    /// use and shared by every server through Redis.
+    pub async fn signing_key(conn: &mut Connection) -> Result<Vec<u8>, String> {
+        if let Ok(secret) = std::env::var("SJAVS_INVITE_SECRET") {
+            if !secret.is_empty() {
+                return Ok(secret.into_bytes());
+            }
+        }
+
+        let candidate: String = (0..32)
+            .map(|_| format!("{:02x}", rand::random::<u8>()))
+            .collect();
+        redis::cmd("SET")
+            .arg(SECRET_KEY)
+            .arg(&candidate)
+            .arg("NX")
+            .query_async::<_, Option<String>>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store invite secret: {}", e))?;
+
+        let secret: String = redis::cmd("GET")
+            .arg(SECRET_KEY)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get invite secret: {}", e))?;
+
+        Ok(secret.into_bytes())
+    }
+
+    /// Store a new invite to `game_id`, returning it with its signed token
+    pub async fn create(
+        conn: &mut Connection,
+        game_id: &str,
+        created_by: &str,
+        expires_at: u64,
+        max_uses: u32,
+    ) -> Result<(Invite, String), String> {
+        let token = InviteToken::new(game_id, expires_at);
+        let invite = Invite {
+            invite_id: token.invite_id.clone(),
+            game_id: game_id.to_string(),
+            created_by: created_by.to_string(),
+            max_uses,
+            uses: 0,
+            expires_at,
+        };
+
+        let key = format!("invite:{}", invite.invite_id);
+        redis::cmd("HSET")
+            .arg(&key)
+            .arg("game_id")
+            .arg(&invite.game_id)
+            .arg("created_by")
+            .arg(&invite.created_by)
+            .arg("max_uses")
+            .arg(invite.max_uses)
+            .arg("uses")
+            .arg(invite.uses)
+            .arg("expires_at")
+            .arg(invite.expires_at)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store invite: {}", e))?;
+
+        redis::cmd("EXPIREAT")
+            .arg(&key)
+            .arg(expires_at)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to set invite expiry: {}", e))?;
+
+        redis::cmd("SADD")
+            .arg(format!("normal_match:{}:invites", game_id))
+            .arg(&invite.invite_id)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to list invite: {}", e))?;
+
+        let secret = Self::signing_key(conn).await?;
+        let signed = token.sign(&secret);
+        Ok((invite, signed))
+    }
+
+    /// Get an invite that hasn't expired or been revoked
+    pub async fn get(conn: &mut Connection, invite_id: &str) -> Result<Option<Invite>, String> {
+        let hash: HashMap<String, String> = redis::cmd("HGETALL")
+            .arg(format!("invite:{}", invite_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get invite: {}", e))?;
+
+        Ok(Invite::from_redis_hash(invite_id, &hash))
+    }
+
+    /// Live invites to a match, soonest to expire first
+    ///
+    /// Expired invites are dropped from the match's list on the way.
+    pub async fn get_for_game(conn: &mut Connection, game_id: &str) -> Result<Vec<Invite>, String> {
+        let list_key = format!("normal_match:{}:invites", game_id);
+        let invite_ids: Vec<String> = redis::cmd("SMEMBERS")
+            .arg(&list_key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get invites: {}", e))?;
+
+        let mut invites = Vec::new();
+        for invite_id in invite_ids {
+            match Self::get(conn, &invite_id).await? {
+                Some(invite) => invites.push(invite),
+                None => {
+                    redis::cmd("SREM")
+                        .arg(&list_key)
+                        .arg(&invite_id)
+                        .query_async::<_, ()>(&mut *conn)
+                        .await
+                        .map_err(|e| format!("Failed to drop expired invite: {}", e))?;
+                }
+            }
+        }
+
+        invites.sort_by_key(|invite| invite.expires_at);
+        Ok(invites)
+    }
+
+    /// Use up one of the invite's joins
+    ///
+    /// Returns false when the invite is gone, belongs to another match or has
+    /// no uses left.
+    pub async fn redeem(conn: &mut Connection, token: &InviteToken) -> Result<bool, String> {
+        match Self::get(conn, &token.invite_id).await? {
+            Some(invite) if invite.game_id == token.game_id => {}
+            _ => return Ok(false),
+        }
+
+        let key = format!("invite:{}", token.invite_id);
+        let uses: i64 = redis::cmd("HINCRBY")
+            .arg(&key)
+            .arg("uses")
+            .arg(1)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to redeem invite: {}", e))?;
+
+        // Re-read after the increment so two joins can't take the last use
+        let invite = match Self::get(conn, &token.invite_id).await? {
+            Some(invite) => invite,
+            None => {
+                // Revoked in the meantime; don't leave the counter behind
+                redis::cmd("DEL")
+                    .arg(&key)
+                    .query_async::<_, ()>(&mut *conn)
+                    .await
+                    .map_err(|e| format!("Failed to clean up invite: {}", e))?;
+                return Ok(false);
+            }
+        };
+        if uses > invite.max_uses as i64 {
+            Self::release(conn, token).await?;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Give back a use taken by `redeem`, for a join that didn't go through
+    pub async fn release(conn: &mut Connection, token: &InviteToken) -> Result<(), String> {
+        if Self::get(conn, &token.invite_id).await?.is_none() {
+            return Ok(());
+        }
+
+        redis::cmd("HINCRBY")
+            .arg(format!("invite:{}", token.invite_id))
+            .arg("uses")
+            .arg(-1)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to release invite use: {}", e))
+    }
+
+    /// Revoke an invite to `game_id`, returning whether it existed
+    pub async fn revoke(
+        conn: &mut Connection,
+        game_id: &str,
+        invite_id: &str,
+    ) -> Result<bool, String> {
+        let removed: u32 = redis::cmd("SREM")
+            .arg(format!("normal_match:{}:invites", game_id))
+            .arg(invite_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to unlist invite: {}", e))?;
+
+        if removed == 0 {
+            return Ok(false);
+        }
+
+        redis::cmd("DEL")
+            .arg(format!("invite:{}", invite_id))
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to revoke invite: {}", e))?;
+
+        Ok(true)
+    }
+}
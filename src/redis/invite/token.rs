@@ -0,0 +1,147 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+/// What a signed invite token vouches for
+///
+/// The token itself carries the match and expiry, signed with HMAC-SHA256 so
+/// it can't be forged or edited. Use counts and revocation live in Redis,
+/// keyed by `invite_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InviteToken {
+    pub invite_id: String,
+    pub game_id: String,
+    /// Unix time (seconds) after which the token is refused
+    pub expires_at: u64,
+}
+
+impl InviteToken {
+    pub fn new(game_id: &str, expires_at: u64) -> Self {
+        Self {
+            invite_id: uuid::Uuid::new_v4().simple().to_string(),
+            game_id: game_id.to_string(),
+            expires_at,
+        }
+    }
+
+    /// Encode and sign as `payload.signature`, both URL-safe
+    pub fn sign(&self, secret: &[u8]) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(format!(
+            "{}:{}:{}",
+            self.invite_id, self.game_id, self.expires_at
+        ));
+        let signature = URL_SAFE_NO_PAD.encode(hmac_sha256(secret, payload.as_bytes()));
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Decode a token, rejecting it unless `secret` signed it
+    ///
+    /// Doesn't check expiry; see `is_expired`.
+    pub fn verify(token: &str, secret: &[u8]) -> Result<Self, String> {
+        let (payload, signature) = token
+            .split_once('.')
+            .ok_or_else(|| "Malformed invite token".to_string())?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| "Malformed invite token".to_string())?;
+        if !constant_time_eq(&signature, &hmac_sha256(secret, payload.as_bytes())) {
+            return Err("Invite token signature does not match".to_string());
+        }
+
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| "Malformed invite token".to_string())?;
+        let mut parts = payload.splitn(3, ':');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(invite_id), Some(game_id), Some(expires_at)) => Ok(Self {
+                invite_id: invite_id.to_string(),
+                game_id: game_id.to_string(),
+                expires_at: expires_at
+                    .parse()
+                    .map_err(|_| "Malformed invite token".to_string())?,
+            }),
+            _ => Err("Malformed invite token".to_string()),
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// HMAC-SHA256 (RFC 2104)
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let inner = Sha256::new()
+        .chain_update(block.map(|byte| byte ^ 0x36))
+        .chain_update(message)
+        .finalize();
+    Sha256::new()
+        .chain_update(block.map(|byte| byte ^ 0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+/// Compare without stopping at the first difference, so timing leaks nothing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"test secret";
+
+    #[test]
+    fn test_hmac_matches_rfc_4231() {
+        let hex: String = hmac_sha256(b"Jefe", b"what do ya want for nothing?")
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        assert_eq!(
+            hex,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_signed_token_round_trips() {
+        let invite = InviteToken::new("game_1700000000_a1b2", 1_700_003_600);
+        let token = invite.sign(SECRET);
+
+        assert_eq!(InviteToken::verify(&token, SECRET), Ok(invite.clone()));
+        assert!(!invite.is_expired(1_700_003_599));
+        assert!(invite.is_expired(1_700_003_600));
+    }
+
+    #[test]
+    fn test_tampered_or_foreign_tokens_are_rejected() {
+        let token = InviteToken::new("game_1", 1_700_003_600).sign(SECRET);
+        assert!(InviteToken::verify(&token, b"another secret").is_err());
+
+        // Point the token at another match, keeping the old signature
+        let (_, signature) = token.split_once('.').unwrap();
+        let forged = InviteToken {
+            game_id: "game_2".to_string(),
+            ..InviteToken::verify(&token, SECRET).unwrap()
+        }
+        .sign(b"forger");
+        let (payload, _) = forged.split_once('.').unwrap();
+        assert!(InviteToken::verify(&format!("{}.{}", payload, signature), SECRET).is_err());
+
+        assert!(InviteToken::verify("not a token", SECRET).is_err());
+        assert!(InviteToken::verify("", SECRET).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::game::rules::RuleSet;
+use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::player::repository::PlayerRepository;
+use crate::redis::pubsub::repository::PubSubRepository;
+use deadpool_redis::Connection;
+use serde::{Deserialize, Serialize};
+
+/// Set of public match IDs shown in the lobby
+const PUBLIC_MATCHES_KEY: &str = "public_matches";
+
+/// Seats at a Sjavs table
+const SEATS: u8 = 4;
+
+/// A public match waiting for players, as shown in the lobby
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LobbyListing {
+    pub game_id: String,
+    /// PIN to join with
+    pub pin: u32,
+    pub host_id: String,
+    pub host_name: String,
+    /// Seats taken, bots included
+    pub seats_taken: u8,
+    pub seats: u8,
+    pub rules: RuleSet,
+    pub number_of_crosses: u32,
+    pub created_timestamp: u64,
+}
+
+impl LobbyListing {
+    pub fn is_full(&self) -> bool {
+        self.seats_taken >= self.seats
+    }
+}
+
+pub struct LobbyRepository;
+
+impl LobbyRepository {
+    /// Every public match still waiting for players, oldest first
+    ///
+    /// Matches that were deleted or have started are dropped from the set on the way.
+    pub async fn get_listings(conn: &mut Connection) -> Result<Vec<LobbyListing>, String> {
+        let game_ids: Vec<String> = redis::cmd("SMEMBERS")
+            .arg(PUBLIC_MATCHES_KEY)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get public matches: {}", e))?;
+
+        let mut listings = Vec::new();
+        for game_id in game_ids {
+            match Self::get_listing(conn, &game_id).await? {
+                Some(listing) => listings.push(listing),
+                None => Self::remove(conn, &game_id).await.map(|_| ())?,
+            }
+        }
+
+        listings.sort_by_key(|listing| listing.created_timestamp);
+        Ok(listings)
+    }
+
+    /// Lobby entry for a match, or `None` if it is private, locked, gone or no longer waiting
+    pub async fn get_listing(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<Option<LobbyListing>, String> {
+        let game_match = match NormalMatchRepository::get_by_id(conn, game_id).await? {
+            Some(game_match)
+                if game_match.is_public
+                    && !game_match.is_locked
+                    && game_match.status == NormalMatchStatus::Waiting =>
+            {
+                game_match
+            }
+            _ => return Ok(None),
+        };
+
+        let players = PlayerRepository::get_players_in_game(conn, game_id).await?;
+        let host_id = players
+            .iter()
+            .find(|player| player.role == "host")
+            .map(|player| player.user_id.clone())
+            .unwrap_or_default();
+        let host_name: Option<String> = redis::cmd("HGET")
+            .arg("usernames")
+            .arg(&host_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get host name: {}", e))?;
+
+        Ok(Some(LobbyListing {
+            game_id: game_match.id,
+            pin: game_match.pin,
+            host_name: host_name.unwrap_or_else(|| host_id.clone()),
+            host_id,
+            seats_taken: players.len() as u8,
+            seats: SEATS,
+            rules: game_match.rules,
+            number_of_crosses: game_match.number_of_crosses,
+            created_timestamp: game_match.created_timestamp,
+        }))
+    }
+
+    /// Bring a match's lobby entry up to date and tell lobby subscribers
+    ///
+    /// Call after anything that may change a public match's listing: creation,
+    /// players joining or leaving, the game starting or the match being deleted.
+    /// Publishes `lobby_match_created`, `lobby_match_updated`, `lobby_match_filled`,
+    /// `lobby_match_started` or `lobby_match_closed`.
+    pub async fn refresh(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        let Some(listing) = Self::get_listing(conn, game_id).await? else {
+            if !Self::remove(conn, game_id).await? {
+                return Ok(()); // Private matches never appear in the lobby
+            }
+
+            let started = NormalMatchRepository::get_by_id(conn, game_id)
+                .await?
+                .is_some_and(|game_match| game_match.status != NormalMatchStatus::Waiting);
+            let (event, message) = if started {
+                ("lobby_match_started", "Match started")
+            } else {
+                ("lobby_match_closed", "Match closed")
+            };
+            return PubSubRepository::publish_lobby_event(conn, event, game_id, message, None)
+                .await;
+        };
+
+        let added: u32 = redis::cmd("SADD")
+            .arg(PUBLIC_MATCHES_KEY)
+            .arg(game_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to list public match: {}", e))?;
+
+        let (event, message) = if added == 1 {
+            ("lobby_match_created", "Match created")
+        } else if listing.is_full() {
+            ("lobby_match_filled", "Match is full")
+        } else {
+            ("lobby_match_updated", "Seats changed")
+        };
+        let data = serde_json::json!({ "listing": listing });
+        PubSubRepository::publish_lobby_event(conn, event, game_id, message, Some(data)).await
+    }
+
+    /// Take a match out of the lobby, returning whether it was listed
+    async fn remove(conn: &mut Connection, game_id: &str) -> Result<bool, String> {
+        let removed: u32 = redis::cmd("SREM")
+            .arg(PUBLIC_MATCHES_KEY)
+            .arg(game_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to unlist match: {}", e))?;
+
+        Ok(removed == 1)
+    }
+}
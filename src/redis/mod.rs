@@ -1,9 +1,12 @@
 pub mod cross_state;
 pub mod game_state;
+pub mod invite;
+pub mod lobby;
 pub mod normal_match;
 pub mod notification;
 pub mod player;
 pub mod pubsub;
+pub mod takeback;
 pub mod trick_state;
 
 // Re-export connection type for convenience
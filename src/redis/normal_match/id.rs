@@ -1,3 +1,7 @@
+use crate::game::engine::{Bid, GamePhase, SjavsGame};
+use crate::game::hand::Hand;
+use crate::game::rules::RuleSet;
+use crate::game::trick::GameTrickState;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -62,11 +66,34 @@ pub struct NormalMatch {
     pub highest_bid_length: Option<u8>, // Highest bid trump count
     pub highest_bidder: Option<usize>,  // Who has highest bid
     pub highest_bid_suit: Option<String>, // Trump suit of current highest bid
+
+    // House rules chosen when the match was created
+    pub rules: RuleSet,
+
+    // Match settings the host can change
+    pub allow_takebacks: bool, // Whether players may ask to take back their last card
+    pub allow_hints: bool,     // Whether players may ask for hints and bidding advice
+    pub is_public: bool,       // Whether the match is listed in the lobby
+    pub is_locked: bool,       // Whether the host has closed the room to new players
+    pub auto_start: bool,      // Whether the game starts by itself once everyone is ready
 }
 
 impl NormalMatch {
+    /// Hash fields that are only written when set. `NormalMatchRepository::update`
+    /// removes any of these that are absent so cleared values don't linger.
+    pub const OPTIONAL_FIELDS: &'static [&'static str] = &[
+        "dealer_position",
+        "current_bidder",
+        "current_leader",
+        "trump_suit",
+        "trump_declarer",
+        "highest_bid_length",
+        "highest_bidder",
+        "highest_bid_suit",
+    ];
+
     /// Create a new normal match with default values
-    pub fn new(id: String, pin: u32, number_of_crosses: u32) -> Self {
+    pub fn new(id: String, pin: u32, number_of_crosses: u32, rules: RuleSet) -> Self {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
@@ -87,6 +114,12 @@ impl NormalMatch {
             highest_bid_length: None,
             highest_bidder: None,
             highest_bid_suit: None,
+            rules,
+            allow_takebacks: true,
+            allow_hints: false,
+            is_public: false,
+            is_locked: false,
+            auto_start: false,
         }
     }
 
@@ -161,6 +194,21 @@ impl NormalMatch {
             .filter(|s| !s.is_empty())
             .map(|s| s.clone());
 
+        // Matches created before rule sets existed play the standard rules
+        let rules = match hash.get("rules") {
+            Some(json) => {
+                serde_json::from_str(json).map_err(|e| format!("Invalid rules format: {}", e))?
+            }
+            None => RuleSet::standard(),
+        };
+
+        // Matches from before takebacks existed allow them
+        let allow_takebacks = hash.get("allow_takebacks").map(String::as_str) != Some("false");
+        let allow_hints = hash.get("allow_hints").map(String::as_str) == Some("true");
+        let is_public = hash.get("is_public").map(String::as_str) == Some("true");
+        let is_locked = hash.get("is_locked").map(String::as_str) == Some("true");
+        let auto_start = hash.get("auto_start").map(String::as_str) == Some("true");
+
         Ok(Self {
             id,
             pin,
@@ -176,6 +224,12 @@ impl NormalMatch {
             highest_bid_length,
             highest_bidder,
             highest_bid_suit,
+            rules,
+            allow_takebacks,
+            allow_hints,
+            is_public,
+            is_locked,
+            auto_start,
         })
     }
 
@@ -194,6 +248,18 @@ impl NormalMatch {
             "created_timestamp".to_string(),
             self.created_timestamp.to_string(),
         );
+        hash.insert(
+            "rules".to_string(),
+            serde_json::to_string(&self.rules).expect("Rule set serializes to JSON"),
+        );
+        hash.insert(
+            "allow_takebacks".to_string(),
+            self.allow_takebacks.to_string(),
+        );
+        hash.insert("allow_hints".to_string(), self.allow_hints.to_string());
+        hash.insert("is_public".to_string(), self.is_public.to_string());
+        hash.insert("is_locked".to_string(), self.is_locked.to_string());
+        hash.insert("auto_start".to_string(), self.auto_start.to_string());
 
         // Add optional game state fields
         if let Some(dealer) = self.dealer_position {
@@ -233,18 +299,53 @@ impl NormalMatch {
     /// Start the dealing phase
     pub fn start_dealing(&mut self, dealer_position: usize) {
         if self.can_start() {
-            self.status = NormalMatchStatus::Dealing;
-            self.dealer_position = Some(dealer_position);
-            self.current_bidder = Some((dealer_position + 1) % 4); // Left of dealer bids first
+            self.begin_deal(dealer_position);
+        }
+    }
 
-            // Reset any previous game state
-            self.current_leader = None;
-            self.trump_suit = None;
-            self.trump_declarer = None;
-            self.highest_bid_length = None;
-            self.highest_bidder = None;
-            self.highest_bid_suit = None;
+    /// Move a completed game on to the next deal, passing the deal to the left
+    pub fn start_next_game(&mut self) -> Result<usize, String> {
+        if self.status != NormalMatchStatus::Completed {
+            return Err("Previous game must be completed before the next deal".to_string());
+        }
+
+        if self.is_match_complete() {
+            return Err("All crosses in this match have been played".to_string());
         }
+
+        let dealer_position = self
+            .dealer_position
+            .map(|dealer| (dealer + 1) % 4)
+            .ok_or("No previous dealer recorded")?;
+
+        self.begin_deal(dealer_position);
+        Ok(dealer_position)
+    }
+
+    /// Enter the dealing phase with a fresh bidding state
+    fn begin_deal(&mut self, dealer_position: usize) {
+        self.status = NormalMatchStatus::Dealing;
+        self.dealer_position = Some(dealer_position);
+        self.current_bidder = Some((dealer_position + 1) % 4); // Left of dealer bids first
+
+        // Reset any previous game state
+        self.current_leader = None;
+        self.trump_suit = None;
+        self.trump_declarer = None;
+        self.highest_bid_length = None;
+        self.highest_bidder = None;
+        self.highest_bid_suit = None;
+    }
+
+    /// Record that a cross has been won, returns true once the match is over
+    pub fn complete_cross(&mut self) -> bool {
+        self.current_cross += 1;
+        self.is_match_complete()
+    }
+
+    /// Check if every cross of the match has been played
+    pub fn is_match_complete(&self) -> bool {
+        self.current_cross >= self.number_of_crosses
     }
 
     /// Start the bidding phase
@@ -303,20 +404,6 @@ impl NormalMatch {
         (current_player + 1) % 4
     }
 
-    /// Initialize first trick after bidding completes
-    pub fn start_first_trick(&mut self) -> Result<(), String> {
-        if self.status != NormalMatchStatus::Playing {
-            return Err("Game must be in Playing state to start tricks".to_string());
-        }
-
-        if self.current_leader.is_none() {
-            return Err("Current leader must be set to start tricks".to_string());
-        }
-
-        // First trick starts - leader is already set in complete_bidding()
-        Ok(())
-    }
-
     /// Check if it's a player's turn to play a card
     pub fn is_player_turn_to_play(&self, player_position: usize) -> bool {
         self.status == NormalMatchStatus::Playing && self.current_leader == Some(player_position)
@@ -342,137 +429,6 @@ impl NormalMatch {
         )
     }
 
-    /// Validate if a bid is legal
-    pub fn is_valid_bid(
-        &self,
-        player_position: usize,
-        bid_length: u8,
-        bid_suit: &str,
-    ) -> Result<(), String> {
-        // Must be player's turn
-        if !self.is_player_turn_to_bid(player_position) {
-            return Err("Not your turn to bid".to_string());
-        }
-
-        // Must be in bidding state
-        if self.status != NormalMatchStatus::Bidding {
-            return Err("Game is not in bidding phase".to_string());
-        }
-
-        // Bid length must be 5-8
-        if bid_length < 5 || bid_length > 8 {
-            return Err("Bid must be between 5 and 8 trumps".to_string());
-        }
-
-        // Suit must be valid
-        if !["hearts", "diamonds", "clubs", "spades"].contains(&bid_suit) {
-            return Err("Invalid trump suit".to_string());
-        }
-
-        // Check if bid beats current highest bid
-        if let Some(current_highest) = self.highest_bid_length {
-            if bid_length > current_highest {
-                // Higher bid always wins
-                return Ok(());
-            } else if bid_length == current_highest {
-                // Same length only wins if it's clubs and current isn't clubs
-                let current_suit = self.highest_bid_suit.as_deref().unwrap_or("");
-                if bid_suit == "clubs" && current_suit != "clubs" {
-                    // Clubs can match any other suit of same length
-                    return Ok(());
-                } else if bid_suit == "clubs" && current_suit == "clubs" {
-                    return Err("Cannot bid clubs to match clubs".to_string());
-                } else {
-                    return Err(
-                        "Bid must be higher than current bid, or clubs to match".to_string()
-                    );
-                }
-            } else {
-                return Err(format!(
-                    "Bid must be at least {} trumps",
-                    current_highest + 1
-                ));
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Record a bid and advance game state
-    pub fn make_bid(
-        &mut self,
-        player_position: usize,
-        bid_length: u8,
-        bid_suit: String,
-    ) -> Result<bool, String> {
-        // Validate the bid first
-        self.is_valid_bid(player_position, bid_length, &bid_suit)?;
-
-        // Update bidding state
-        self.highest_bid_length = Some(bid_length);
-        self.highest_bidder = Some(player_position);
-        self.highest_bid_suit = Some(bid_suit);
-        self.current_bidder = Some((player_position + 1) % 4);
-
-        // Check if this completes bidding (if next 3 players pass)
-        // For now, return false - completion will be determined by subsequent passes
-        Ok(false)
-    }
-
-    /// Record a pass and advance game state
-    pub fn make_pass(&mut self, player_position: usize) -> Result<(bool, bool), String> {
-        // Must be player's turn
-        if !self.is_player_turn_to_bid(player_position) {
-            return Err("Not your turn to bid".to_string());
-        }
-
-        // Must be in bidding state
-        if self.status != NormalMatchStatus::Bidding {
-            return Err("Game is not in bidding phase".to_string());
-        }
-
-        // Move to next player
-        self.current_bidder = Some((player_position + 1) % 4);
-
-        // Check conditions for completion or redeal
-        if self.highest_bidder.is_none() {
-            // No bids yet, check if we've completed a full circle
-            if let Some(dealer) = self.dealer_position {
-                let starting_bidder = (dealer + 1) % 4;
-                if self.current_bidder == Some(starting_bidder) {
-                    // All 4 players passed - need redeal
-                    return Ok((true, false)); // (all_passed, bidding_complete)
-                }
-            }
-        } else {
-            // There's a bid, check if 3 consecutive passes after the bid
-            if let Some(bidder) = self.highest_bidder {
-                let next_after_bidder = (bidder + 1) % 4;
-                if self.current_bidder == Some(next_after_bidder) {
-                    // We've come full circle back to player after bidder
-                    // This means 3 players passed after the bid
-                    return Ok((false, true)); // (all_passed, bidding_complete)
-                }
-            }
-        }
-
-        Ok((false, false)) // Continue bidding
-    }
-
-    /// Complete bidding with the winning bid
-    pub fn finish_bidding(&mut self) -> Result<(u8, String, usize), String> {
-        if let (Some(bid_length), Some(bidder), Some(trump_suit)) = (
-            self.highest_bid_length,
-            self.highest_bidder,
-            self.highest_bid_suit.clone(),
-        ) {
-            self.complete_bidding(trump_suit.clone(), bidder);
-            Ok((bid_length, trump_suit, bidder))
-        } else {
-            Err("No valid bid to complete with".to_string())
-        }
-    }
-
     /// Determine partnerships based on trump declarer
     /// In Sjavs, trump declarer partners with holder of highest trump
     pub fn determine_partnerships(&self) -> Option<(usize, usize, Vec<usize>)> {
@@ -488,6 +444,97 @@ impl NormalMatch {
         }
     }
 
+    /// Rebuild the rules engine for the current deal from the stored match
+    pub fn to_engine(
+        &self,
+        hands: [Hand; 4],
+        tricks: Option<GameTrickState>,
+    ) -> Result<SjavsGame, String> {
+        let phase = match self.status {
+            NormalMatchStatus::Bidding => GamePhase::Bidding,
+            NormalMatchStatus::Playing => GamePhase::Playing,
+            NormalMatchStatus::Completed => GamePhase::Complete,
+            _ => {
+                return Err(format!(
+                    "Game is in '{}' state, no deal is in progress",
+                    self.status.to_string()
+                ))
+            }
+        };
+
+        let dealer = self
+            .dealer_position
+            .ok_or("No dealer recorded for this game")?;
+
+        if phase == GamePhase::Playing && tricks.is_none() {
+            return Err("Trick state not initialized".to_string());
+        }
+
+        let highest_bid = match (
+            self.highest_bidder,
+            self.highest_bid_length,
+            &self.highest_bid_suit,
+        ) {
+            (Some(player), Some(length), Some(suit)) => Some(Bid {
+                player,
+                length,
+                suit: suit.clone(),
+            }),
+            _ => None,
+        };
+
+        Ok(SjavsGame {
+            game_id: self.id.clone(),
+            dealer,
+            hands,
+            phase,
+            current_bidder: if phase == GamePhase::Bidding {
+                self.current_bidder
+            } else {
+                None
+            },
+            highest_bid,
+            tricks,
+            rules: self.rules.clone(),
+        })
+    }
+
+    /// Copy the engine's progress back onto the stored match
+    pub fn apply_engine_state(&mut self, game: &SjavsGame) {
+        self.status = match game.phase {
+            GamePhase::Bidding => NormalMatchStatus::Bidding,
+            GamePhase::Redeal => NormalMatchStatus::Dealing,
+            GamePhase::Playing => NormalMatchStatus::Playing,
+            GamePhase::Complete => NormalMatchStatus::Completed,
+        };
+        self.dealer_position = Some(game.dealer);
+        self.current_bidder = game.current_bidder;
+
+        self.highest_bidder = game.highest_bid.as_ref().map(|bid| bid.player);
+        self.highest_bid_length = game.highest_bid.as_ref().map(|bid| bid.length);
+        self.highest_bid_suit = game.highest_bid.as_ref().map(|bid| bid.suit.clone());
+
+        match &game.tricks {
+            Some(tricks) => {
+                let trick = &tricks.current_trick;
+                self.trump_suit = Some(trick.trump_suit.clone());
+                self.trump_declarer = Some(tricks.trump_team.0);
+                self.current_leader = Some(
+                    trick
+                        .cards_played
+                        .first()
+                        .map(|(player, _)| *player)
+                        .unwrap_or(trick.current_player),
+                );
+            }
+            None => {
+                self.trump_suit = None;
+                self.trump_declarer = None;
+                self.current_leader = None;
+            }
+        }
+    }
+
     /// Get current bidding summary
     pub fn get_bidding_state(&self) -> BiddingState {
         BiddingState {
@@ -516,7 +563,7 @@ mod tests {
 
     #[test]
     fn test_match_state_transitions() {
-        let mut match_obj = NormalMatch::new("test_game".to_string(), 1234, 3);
+        let mut match_obj = NormalMatch::new("test_game".to_string(), 1234, 3, RuleSet::standard());
 
         // Initial state
         assert_eq!(match_obj.status, NormalMatchStatus::Waiting);
@@ -553,7 +600,8 @@ mod tests {
 
     #[test]
     fn test_redeal_functionality() {
-        let mut match_obj = NormalMatch::new("test_redeal".to_string(), 5678, 3);
+        let mut match_obj =
+            NormalMatch::new("test_redeal".to_string(), 5678, 3, RuleSet::standard());
 
         // Set up bidding state
         match_obj.start_dealing(2);
@@ -569,6 +617,72 @@ mod tests {
         assert_eq!(match_obj.trump_suit, None);
     }
 
+    #[test]
+    fn test_next_game_rotates_dealer() {
+        let mut match_obj = NormalMatch::new("test_next".to_string(), 4321, 1, RuleSet::standard());
+        match_obj.start_dealing(3);
+        match_obj.start_bidding();
+        match_obj.update_bid(0, 6);
+        match_obj.complete_bidding("hearts".to_string(), 0);
+
+        // Can't deal again while the game is still being played
+        assert!(match_obj.start_next_game().is_err());
+
+        match_obj.status = NormalMatchStatus::Completed;
+        assert_eq!(match_obj.start_next_game(), Ok(0)); // Deal passes left
+        assert_eq!(match_obj.status, NormalMatchStatus::Dealing);
+        assert_eq!(match_obj.current_bidder, Some(1));
+        assert_eq!(match_obj.trump_suit, None);
+        assert_eq!(match_obj.highest_bid_length, None);
+        assert!(!match_obj.to_redis_hash().contains_key("trump_suit"));
+
+        // Winning the only cross ends the match
+        match_obj.status = NormalMatchStatus::Completed;
+        assert!(match_obj.complete_cross());
+        assert!(match_obj.start_next_game().is_err());
+    }
+
+    #[test]
+    fn test_engine_round_trip() {
+        use crate::game::deck::Deck;
+        use crate::game::engine::GameAction;
+
+        let mut match_obj =
+            NormalMatch::new("test_engine".to_string(), 2468, 3, RuleSet::standard());
+        match_obj.start_dealing(3);
+        match_obj.start_bidding();
+
+        let dealt = Deck::new().deal().unwrap();
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(dealt[i].clone(), i));
+
+        // Seat 0 bids and everyone passes round to seat 1
+        let mut game = match_obj.to_engine(hands, None).unwrap();
+        game.apply(GameAction::Bid {
+            player: 0,
+            length: 5,
+            suit: "spades".to_string(),
+        })
+        .unwrap();
+        for player in [1, 2, 3, 0] {
+            game.apply(GameAction::Pass { player }).unwrap();
+        }
+        match_obj.apply_engine_state(&game);
+
+        assert_eq!(match_obj.status, NormalMatchStatus::Playing);
+        assert_eq!(match_obj.trump_suit, Some("spades".to_string()));
+        assert_eq!(match_obj.trump_declarer, Some(0));
+        assert_eq!(match_obj.current_leader, Some(0)); // Left of dealer
+        assert_eq!(match_obj.current_bidder, None);
+
+        // Loading again picks up where the engine left off
+        let reloaded = match_obj
+            .to_engine(game.hands.clone(), game.tricks.clone())
+            .unwrap();
+        assert_eq!(reloaded.phase, GamePhase::Playing);
+        assert_eq!(reloaded.current_player(), Some(0));
+        assert!(match_obj.to_engine(game.hands.clone(), None).is_err());
+    }
+
     #[test]
     fn test_redis_serialization_backward_compatibility() {
         // Test that old matches without new fields can still be loaded
@@ -585,11 +699,17 @@ mod tests {
         assert_eq!(match_obj.dealer_position, None);
         assert_eq!(match_obj.current_bidder, None);
         assert_eq!(match_obj.trump_suit, None);
+        assert_eq!(match_obj.rules, RuleSet::standard());
+        assert!(match_obj.allow_takebacks);
+        assert!(!match_obj.allow_hints);
+        assert!(!match_obj.is_public);
+        assert!(!match_obj.is_locked);
+        assert!(!match_obj.auto_start);
     }
 
     #[test]
     fn test_redis_serialization_with_new_fields() {
-        let mut match_obj = NormalMatch::new("test_full".to_string(), 9999, 5);
+        let mut match_obj = NormalMatch::new("test_full".to_string(), 9999, 5, RuleSet::standard());
         match_obj.start_dealing(1);
         match_obj.start_bidding();
         match_obj.update_bid(2, 7);
@@ -606,6 +726,37 @@ mod tests {
         assert_eq!(restored_match.highest_bid_length, Some(7));
         assert_eq!(restored_match.highest_bidder, Some(2));
         assert_eq!(restored_match.status, NormalMatchStatus::Bidding);
+        assert!(restored_match.allow_takebacks);
+
+        match_obj.allow_takebacks = false;
+        match_obj.allow_hints = true;
+        match_obj.is_public = true;
+        match_obj.is_locked = true;
+        match_obj.auto_start = true;
+        let restored_match =
+            NormalMatch::from_redis_hash(match_obj.id.clone(), &match_obj.to_redis_hash()).unwrap();
+        assert!(!restored_match.allow_takebacks);
+        assert!(restored_match.allow_hints);
+        assert!(restored_match.is_public);
+        assert!(restored_match.is_locked);
+        assert!(restored_match.auto_start);
+    }
+
+    #[test]
+    fn test_rules_survive_redis_and_engine() {
+        let rules = RuleSet::preset("quick").unwrap();
+        let mut match_obj = NormalMatch::new("test_rules".to_string(), 1357, 3, rules.clone());
+        match_obj.start_dealing(0);
+        match_obj.start_bidding();
+
+        let hash = match_obj.to_redis_hash();
+        let restored = NormalMatch::from_redis_hash(match_obj.id.clone(), &hash).unwrap();
+        assert_eq!(restored.rules, rules);
+
+        let dealt = crate::game::deck::Deck::new().deal().unwrap();
+        let hands = [0, 1, 2, 3].map(|i| Hand::new(dealt[i].clone(), i));
+        let game = restored.to_engine(hands, None).unwrap();
+        assert_eq!(game.rules, rules);
     }
 
     #[test]
@@ -638,7 +789,7 @@ mod tests {
 
     #[test]
     fn test_helper_methods() {
-        let match_obj = NormalMatch::new("helper_test".to_string(), 1111, 3);
+        let match_obj = NormalMatch::new("helper_test".to_string(), 1111, 3, RuleSet::standard());
 
         // Test next player calculation
         assert_eq!(match_obj.next_player(0), 1);
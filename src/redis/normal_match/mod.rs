@@ -1,2 +1,4 @@
 pub mod id;
+pub mod ready;
 pub mod repository;
+pub mod seating;
@@ -0,0 +1,90 @@
+use crate::redis::normal_match::seating::SEATS;
+use crate::redis::player::repository::PlayerGameInfo;
+use std::collections::HashSet;
+
+/// Who at a waiting table is ready for the game to start
+///
+/// Players mark themselves ready in the waiting room; bots are always ready.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReadyCheck {
+    /// Ready players, in seat order
+    pub ready: Vec<String>,
+    /// Players the table is still waiting for, in seat order
+    pub waiting_for: Vec<String>,
+}
+
+impl ReadyCheck {
+    /// Split `players` by whether they are ready, given who marked themselves ready
+    pub fn new(players: &[PlayerGameInfo], marked_ready: &HashSet<String>) -> Self {
+        let (ready, waiting_for): (Vec<_>, Vec<_>) = players
+            .iter()
+            .partition(|player| player.is_bot() || marked_ready.contains(&player.user_id));
+
+        let user_ids = |players: Vec<&PlayerGameInfo>| {
+            players
+                .into_iter()
+                .map(|player| player.user_id.clone())
+                .collect()
+        };
+        Self {
+            ready: user_ids(ready),
+            waiting_for: user_ids(waiting_for),
+        }
+    }
+
+    pub fn is_ready(&self, user_id: &str) -> bool {
+        self.ready.iter().any(|ready| ready == user_id)
+    }
+
+    /// A full table with every player ready
+    pub fn everyone_ready(&self) -> bool {
+        self.waiting_for.is_empty() && self.ready.len() == SEATS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redis::player::repository::BOT_ROLE;
+
+    fn player(user_id: &str, role: &str) -> PlayerGameInfo {
+        PlayerGameInfo {
+            user_id: user_id.to_string(),
+            role: role.to_string(),
+            seat: None,
+        }
+    }
+
+    fn marked(user_ids: &[&str]) -> HashSet<String> {
+        user_ids.iter().map(|user_id| user_id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_bots_are_always_ready() {
+        let players = [
+            player("host", "host"),
+            player("bob", "player"),
+            player("bot_1", BOT_ROLE),
+            player("cat", "player"),
+        ];
+
+        let check = ReadyCheck::new(&players, &marked(&["cat"]));
+        assert_eq!(check.ready, vec!["bot_1", "cat"]);
+        assert_eq!(check.waiting_for, vec!["host", "bob"]);
+        assert!(check.is_ready("bot_1"));
+        assert!(!check.is_ready("host"));
+        assert!(!check.everyone_ready());
+
+        let check = ReadyCheck::new(&players, &marked(&["host", "bob", "cat"]));
+        assert!(check.everyone_ready());
+    }
+
+    #[test]
+    fn test_everyone_ready_needs_a_full_table() {
+        let players = [player("host", "host"), player("bob", "player")];
+        let check = ReadyCheck::new(&players, &marked(&["host", "bob", "left_already"]));
+
+        assert!(check.waiting_for.is_empty());
+        assert!(!check.everyone_ready());
+    }
+}
@@ -1,7 +1,20 @@
 use crate::redis::normal_match::id::NormalMatch;
+use crate::redis::normal_match::ready::ReadyCheck;
+use crate::redis::normal_match::seating::Seating;
 use crate::redis::player::repository::PlayerRepository;
 use deadpool_redis::Connection;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// What happened when a player left a match
+#[derive(Debug, Clone)]
+pub struct PlayerRemoval {
+    /// The match was deleted because no human was left to host it
+    pub game_deleted: bool,
+    /// Players to notify
+    pub affected_players: Vec<String>,
+    /// Who took over, when the host left
+    pub new_host: Option<String>,
+}
 
 pub struct NormalMatchRepository;
 
@@ -41,7 +54,12 @@ impl NormalMatchRepository {
             .await
             .map_err(|e| format!("Failed to set host player: {}", e))?;
 
-        // 4. Associate player with game - use PlayerRepository instead
+        // 4. Seat the host
+        let mut seating = Seating::default();
+        seating.seat(host_id);
+        Self::save_seating(conn, &normal_match.id, &seating).await?;
+
+        // 5. Associate player with game - use PlayerRepository instead
         PlayerRepository::associate_with_game(conn, host_id, &normal_match.id).await?;
 
         Ok(())
@@ -90,11 +108,27 @@ impl NormalMatchRepository {
 
         redis::cmd("HSET")
             .arg(&redis_key)
-            .arg(hash_map)
+            .arg(&hash_map)
             .query_async::<_, ()>(&mut *conn)
             .await
             .map_err(|e| format!("Failed to update game: {}", e))?;
 
+        // HSET never removes fields, so drop optional values that were reset
+        let cleared_fields: Vec<&str> = NormalMatch::OPTIONAL_FIELDS
+            .iter()
+            .filter(|field| !hash_map.contains_key(**field))
+            .copied()
+            .collect();
+
+        if !cleared_fields.is_empty() {
+            redis::cmd("HDEL")
+                .arg(&redis_key)
+                .arg(cleared_fields)
+                .query_async::<_, ()>(&mut *conn)
+                .await
+                .map_err(|e| format!("Failed to clear game fields: {}", e))?;
+        }
+
         Ok(())
     }
 
@@ -116,6 +150,11 @@ impl NormalMatchRepository {
             .await
             .map_err(|e| format!("Failed to add player: {}", e))?;
 
+        // Take the first free seat
+        let mut seating = Self::get_seating(conn, game_id).await?;
+        seating.seat(user_id);
+        Self::save_seating(conn, game_id, &seating).await?;
+
         // Associate player with game - use PlayerRepository instead
         PlayerRepository::associate_with_game(conn, user_id, game_id).await?;
 
@@ -123,11 +162,14 @@ impl NormalMatchRepository {
     }
 
     /// Remove a player from a match
+    ///
+    /// When the host leaves, the next human player in seat order becomes host.
+    /// The match is only deleted when no human is left to take over.
     pub async fn remove_player(
         conn: &mut Connection,
         game_id: &str,
         user_id: &str,
-    ) -> Result<(bool, Vec<String>), String> {
+    ) -> Result<PlayerRemoval, String> {
         let redis_key = format!("normal_match:{}", game_id);
         let players_key = format!("{}:players", redis_key);
 
@@ -150,7 +192,20 @@ impl NormalMatchRepository {
         let affected_players = all_players.keys().cloned().collect::<Vec<String>>();
 
         if is_host {
-            // Host is leaving - terminate the entire match
+            // Host is leaving - hand the match to the next human player, whatever its phase
+            let seating = Self::get_seating(conn, game_id).await?;
+            if let Some(new_host) = seating.next_host(&all_players, user_id) {
+                Self::set_role(conn, game_id, &new_host, "host").await?;
+                Self::detach_player(conn, game_id, user_id).await?;
+
+                return Ok(PlayerRemoval {
+                    game_deleted: false,
+                    affected_players,
+                    new_host: Some(new_host),
+                });
+            }
+
+            // Nobody left to host - terminate the entire match
 
             // Get match data for PIN removal
             let match_data = Self::get_by_id(conn, game_id).await?;
@@ -160,10 +215,15 @@ impl NormalMatchRepository {
                 PlayerRepository::remove_game_association(conn, player_id).await?;
             }
 
-            // Delete the match and players data
+            // Delete the match, players, seats, ready check and invite list; the invites
+            // themselves expire
             redis::cmd("DEL")
                 .arg(&redis_key)
                 .arg(&players_key)
+                .arg(format!("{}:seats", redis_key))
+                .arg(format!("{}:ready", redis_key))
+                .arg(format!("{}:auto_start", redis_key))
+                .arg(format!("{}:invites", redis_key))
                 .query_async::<_, ()>(&mut *conn)
                 .await
                 .map_err(|e| format!("Failed to delete game: {}", e))?;
@@ -178,41 +238,258 @@ impl NormalMatchRepository {
                     .map_err(|e| format!("Failed to remove pin mapping: {}", e))?;
             }
 
-            return Ok((true, affected_players)); // Game deleted with list of affected players
+            Self::delete_game_data(conn, game_id).await?;
+
+            // Game deleted with list of affected players
+            Ok(PlayerRemoval {
+                game_deleted: true,
+                affected_players,
+                new_host: None,
+            })
         } else {
             // Regular player leaving
+            Self::detach_player(conn, game_id, user_id).await?;
 
-            // Remove player from the game's player list
-            redis::cmd("HDEL")
-                .arg(&players_key)
-                .arg(user_id)
-                .query_async::<_, ()>(&mut *conn)
-                .await
-                .map_err(|e| format!("Failed to remove player: {}", e))?;
+            // Default to just the current player being affected
+            Ok(PlayerRemoval {
+                game_deleted: false,
+                affected_players: vec![user_id.to_string()],
+                new_host: None,
+            })
+        }
+    }
 
-            // Check if there are any players left
-            let remaining_players: u32 = redis::cmd("HLEN")
-                .arg(&players_key)
-                .query_async(&mut *conn)
-                .await
-                .map_err(|e| format!("Failed to count remaining players: {}", e))?;
+    /// Delete what the match's games left behind once the match itself is gone
+    ///
+    /// None of these keys expire, so a deleted match would otherwise keep them forever.
+    async fn delete_game_data(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        let mut keys = vec![
+            format!("cross_state:{}", game_id),
+            format!("game_result:{}", game_id),
+            format!("game_trick_state:{}", game_id),
+            format!("game:{}:deals", game_id),
+            format!("game:{}:played_deals", game_id),
+            format!("game:{}:bidding", game_id),
+            format!("game:{}:bot_difficulties", game_id),
+            format!("game:{}:player_hints", game_id),
+            format!("game:{}:hints", game_id),
+        ];
+        keys.extend(
+            (1..=8).map(|trick_number| format!("game_trick_history:{}:{}", game_id, trick_number)),
+        );
+        keys.extend((0..4).map(|position| format!("game:{}:hand:{}", game_id, position)));
+
+        redis::cmd("DEL")
+            .arg(keys)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to delete game data: {}", e))
+    }
 
-            // Default to just the current player being affected
-            let affected = vec![user_id.to_string()];
+    /// Take a player off the players list, their seat and the ready check, and out of the game
+    async fn detach_player(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+    ) -> Result<(), String> {
+        redis::cmd("HDEL")
+            .arg(format!("normal_match:{}:players", game_id))
+            .arg(user_id)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to remove player: {}", e))?;
 
-            if remaining_players == 0 {
-                // No players left, delete the game (shouldn't happen, but handle it)
-                // [... existing deletion code ...]
+        let mut seating = Self::get_seating(conn, game_id).await?;
+        seating.leave(user_id);
+        Self::save_seating(conn, game_id, &seating).await?;
+        Self::set_ready(conn, game_id, user_id, false).await?;
 
-                return Ok((true, affected));
-            }
+        PlayerRepository::remove_game_association(conn, user_id).await
+    }
 
-            // Disassociate only this player from game
-            PlayerRepository::remove_game_association(conn, user_id).await?;
+    /// Change a player's role ("host", "player" or "bot")
+    pub async fn set_role(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+        role: &str,
+    ) -> Result<(), String> {
+        redis::cmd("HSET")
+            .arg(format!("normal_match:{}:players", game_id))
+            .arg(user_id)
+            .arg(role)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to set player role: {}", e))
+    }
+
+    /// Keep a kicked player from rejoining for `seconds`
+    pub async fn set_kick_cooldown(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+        seconds: u64,
+    ) -> Result<(), String> {
+        redis::cmd("SET")
+            .arg(format!("normal_match:{}:kicked:{}", game_id, user_id))
+            .arg("1")
+            .arg("EX")
+            .arg(seconds)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store kick cooldown: {}", e))
+    }
 
-            Ok((false, affected)) // Game still exists
+    /// Seconds until a kicked player may rejoin, or `None` if they may now
+    pub async fn get_kick_cooldown(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+    ) -> Result<Option<u64>, String> {
+        let ttl: i64 = redis::cmd("TTL")
+            .arg(format!("normal_match:{}:kicked:{}", game_id, user_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to check kick cooldown: {}", e))?;
+
+        Ok(cooldown_from_ttl(ttl))
+    }
+
+    /// Get who sits where at a match's table
+    pub async fn get_seating(conn: &mut Connection, game_id: &str) -> Result<Seating, String> {
+        let hash: HashMap<String, String> = redis::cmd("HGETALL")
+            .arg(format!("normal_match:{}:seats", game_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get seats: {}", e))?;
+
+        Ok(Seating::from_redis_hash(&hash))
+    }
+
+    /// Replace a match's seating
+    pub async fn save_seating(
+        conn: &mut Connection,
+        game_id: &str,
+        seating: &Seating,
+    ) -> Result<(), String> {
+        let seats_key = format!("normal_match:{}:seats", game_id);
+        let hash = seating.to_redis_hash();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().cmd("DEL").arg(&seats_key).ignore();
+        if !hash.is_empty() {
+            pipe.cmd("HSET").arg(&seats_key).arg(hash).ignore();
         }
+        pipe.query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to save seats: {}", e))
+    }
+
+    /// Mark a player ready or not ready to start
+    pub async fn set_ready(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+        ready: bool,
+    ) -> Result<(), String> {
+        redis::cmd(if ready { "SADD" } else { "SREM" })
+            .arg(format!("normal_match:{}:ready", game_id))
+            .arg(user_id)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to set ready state: {}", e))
+    }
+
+    /// Get who at the table is ready to start
+    pub async fn get_ready_check(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<ReadyCheck, String> {
+        let players = PlayerRepository::get_players_in_game(conn, game_id).await?;
+        let marked_ready: HashSet<String> = redis::cmd("SMEMBERS")
+            .arg(format!("normal_match:{}:ready", game_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get ready players: {}", e))?;
+
+        Ok(ReadyCheck::new(&players, &marked_ready))
+    }
+
+    /// Start the auto-start countdown, ending at `starts_at` (milliseconds)
+    ///
+    /// Returns false if a countdown is already running.
+    pub async fn start_countdown(
+        conn: &mut Connection,
+        game_id: &str,
+        starts_at: i64,
+        seconds: u64,
+    ) -> Result<bool, String> {
+        let started: Option<String> = redis::cmd("SET")
+            .arg(format!("normal_match:{}:auto_start", game_id))
+            .arg(starts_at)
+            .arg("NX")
+            .arg("EX")
+            .arg(seconds + 30) // Outlive the countdown so the starting task still finds it
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to start countdown: {}", e))?;
+
+        Ok(started.is_some())
+    }
+
+    /// When the running auto-start countdown ends (milliseconds), if there is one
+    pub async fn get_countdown(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<Option<i64>, String> {
+        redis::cmd("GET")
+            .arg(format!("normal_match:{}:auto_start", game_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get countdown: {}", e))
+    }
+
+    /// Stop the auto-start countdown, returning whether one was running
+    pub async fn clear_countdown(conn: &mut Connection, game_id: &str) -> Result<bool, String> {
+        let cleared: u32 = redis::cmd("DEL")
+            .arg(format!("normal_match:{}:auto_start", game_id))
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear countdown: {}", e))?;
+
+        Ok(cleared == 1)
+    }
+
+    /// Forget the ready check once the game has started
+    pub async fn clear_ready_check(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        redis::cmd("DEL")
+            .arg(format!("normal_match:{}:ready", game_id))
+            .arg(format!("normal_match:{}:auto_start", game_id))
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear ready check: {}", e))
     }
 
     // Add more methods as needed...
 }
+
+/// Seconds left on a key from its `TTL`, or `None` once it has expired
+///
+/// `TTL` answers -2 for a missing key and -1 for one without an expiry.
+fn cooldown_from_ttl(ttl: i64) -> Option<u64> {
+    (ttl > 0).then_some(ttl as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooldown_from_ttl() {
+        assert_eq!(cooldown_from_ttl(300), Some(300));
+        assert_eq!(cooldown_from_ttl(1), Some(1));
+        assert_eq!(cooldown_from_ttl(0), None);
+        assert_eq!(cooldown_from_ttl(-1), None);
+        assert_eq!(cooldown_from_ttl(-2), None);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::redis::player::repository::BOT_ROLE;
+use std::collections::HashMap;
+
+/// Seats at a Sjavs table; partners sit opposite each other (0 & 2, 1 & 3)
+pub const SEATS: usize = 4;
+
+/// Who sits where in a match's waiting room
+///
+/// Seat numbers become player positions when the game starts, so whoever
+/// sits opposite you is your partner.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Seating {
+    seats: [Option<String>; SEATS],
+}
+
+impl Seating {
+    /// Build from the `seats` hash (seat number -> user ID)
+    pub fn from_redis_hash(hash: &HashMap<String, String>) -> Self {
+        let mut seating = Self::default();
+        for (seat, user_id) in hash {
+            if let Some(slot) = seat
+                .parse::<usize>()
+                .ok()
+                .and_then(|seat| seating.seats.get_mut(seat))
+            {
+                *slot = Some(user_id.clone());
+            }
+        }
+        seating
+    }
+
+    /// Convert to the `seats` hash, leaving out empty seats
+    pub fn to_redis_hash(&self) -> HashMap<String, String> {
+        self.seats
+            .iter()
+            .enumerate()
+            .filter_map(|(seat, user_id)| Some((seat.to_string(), user_id.clone()?)))
+            .collect()
+    }
+
+    pub fn seats(&self) -> &[Option<String>; SEATS] {
+        &self.seats
+    }
+
+    pub fn seat_of(&self, user_id: &str) -> Option<usize> {
+        self.seats
+            .iter()
+            .position(|seated| seated.as_deref() == Some(user_id))
+    }
+
+    /// Give a player the first free seat, unless they already have one
+    pub fn seat(&mut self, user_id: &str) -> Option<usize> {
+        if let Some(seat) = self.seat_of(user_id) {
+            return Some(seat);
+        }
+        let seat = self.seats.iter().position(Option::is_none)?;
+        self.seats[seat] = Some(user_id.to_string());
+        Some(seat)
+    }
+
+    /// Free a player's seat
+    pub fn leave(&mut self, user_id: &str) {
+        if let Some(seat) = self.seat_of(user_id) {
+            self.seats[seat] = None;
+        }
+    }
+
+    /// Move a player to an empty seat
+    pub fn sit(&mut self, user_id: &str, seat: usize) -> Result<(), String> {
+        match self.seats.get(seat) {
+            None => Err(format!("Seat must be between 0 and {}", SEATS - 1)),
+            Some(Some(seated)) if seated == user_id => Ok(()),
+            Some(Some(_)) => Err(format!("Seat {} is taken", seat)),
+            Some(None) => {
+                self.leave(user_id);
+                self.seats[seat] = Some(user_id.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Trade seats with whoever sits in `seat`, or just move there if it's empty
+    pub fn swap(&mut self, user_id: &str, seat: usize) -> Result<(), String> {
+        if seat >= SEATS {
+            return Err(format!("Seat must be between 0 and {}", SEATS - 1));
+        }
+        let from = self
+            .seat_of(user_id)
+            .ok_or_else(|| "You don't have a seat".to_string())?;
+        self.seats.swap(from, seat);
+        Ok(())
+    }
+
+    /// Seat `partner_id` opposite `user_id`, trading places with whoever sits there
+    pub fn choose_partner(&mut self, user_id: &str, partner_id: &str) -> Result<(), String> {
+        if user_id == partner_id {
+            return Err("You can't partner yourself".to_string());
+        }
+        let seat = self
+            .seat_of(user_id)
+            .ok_or_else(|| "You don't have a seat".to_string())?;
+        if self.seat_of(partner_id).is_none() {
+            return Err("Your partner doesn't have a seat".to_string());
+        }
+        self.swap(partner_id, (seat + 2) % SEATS)
+    }
+
+    /// Put players in seat order, seated players first
+    ///
+    /// Players without a seat keep their order after the seated ones, so
+    /// matches from before seating existed still order by join time.
+    pub fn order<T>(&self, players: Vec<T>, user_id: impl Fn(&T) -> &str) -> Vec<T> {
+        let mut players: Vec<(usize, T)> = players
+            .into_iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let rank = self.seat_of(user_id(&player)).unwrap_or(SEATS + index);
+                (rank, player)
+            })
+            .collect();
+        players.sort_by_key(|(rank, _)| *rank);
+        players.into_iter().map(|(_, player)| player).collect()
+    }
+
+    /// Who takes over when the host leaves: the next human in seat order
+    ///
+    /// `players` is the players hash (user ID -> role). Bots never host.
+    pub fn next_host(&self, players: &HashMap<String, String>, host_id: &str) -> Option<String> {
+        let mut candidates: Vec<&String> = players
+            .iter()
+            .filter(|(user_id, role)| *user_id != host_id && *role != BOT_ROLE)
+            .map(|(user_id, _)| user_id)
+            .collect();
+        // Unseated players fall back to this order, so keep it stable
+        candidates.sort();
+
+        self.order(candidates, |user_id| user_id.as_str())
+            .first()
+            .map(|user_id| user_id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seated(players: &[&str]) -> Seating {
+        let mut seating = Seating::default();
+        for player in players {
+            seating.seat(player);
+        }
+        seating
+    }
+
+    #[test]
+    fn test_players_are_seated_in_join_order() {
+        let mut seating = seated(&["host", "bob", "cat"]);
+        assert_eq!(seating.seat_of("cat"), Some(2));
+        assert_eq!(seating.seat("bob"), Some(1)); // Already seated
+
+        seating.leave("bob");
+        assert_eq!(seating.seat("dan"), Some(1)); // First free seat
+        assert_eq!(seating.seat("eve"), Some(3));
+        assert_eq!(seating.seat("fay"), None); // Table is full
+    }
+
+    #[test]
+    fn test_sit_and_swap() {
+        let mut seating = seated(&["host", "bob", "cat"]);
+
+        assert!(seating.sit("bob", 2).is_err()); // Taken
+        assert!(seating.sit("bob", 4).is_err());
+        seating.sit("bob", 3).unwrap();
+        assert_eq!(seating.seats()[1], None);
+        assert_eq!(seating.seat_of("bob"), Some(3));
+
+        seating.swap("host", 2).unwrap();
+        assert_eq!(seating.seat_of("host"), Some(2));
+        assert_eq!(seating.seat_of("cat"), Some(0));
+        assert!(seating.swap("nobody", 1).is_err());
+    }
+
+    #[test]
+    fn test_chosen_partners_sit_opposite() {
+        let mut seating = seated(&["host", "bob", "cat", "dan"]);
+
+        seating.choose_partner("host", "bob").unwrap();
+        assert_eq!(seating.seat_of("host"), Some(0));
+        assert_eq!(seating.seat_of("bob"), Some(2));
+        assert_eq!(seating.seat_of("cat"), Some(1)); // Traded places with bob
+
+        seating.choose_partner("dan", "cat").unwrap();
+        assert_eq!(seating.seat_of("cat"), Some(1)); // Already opposite
+        assert!(seating.choose_partner("dan", "dan").is_err());
+    }
+
+    #[test]
+    fn test_order_and_redis_round_trip() {
+        let mut seating = seated(&["host", "bob"]);
+        seating.sit("bob", 3).unwrap();
+
+        let restored = Seating::from_redis_hash(&seating.to_redis_hash());
+        assert_eq!(restored, seating);
+
+        let players = vec!["late", "bob", "host", "later"];
+        assert_eq!(
+            restored.order(players, |player| player),
+            vec!["host", "bob", "late", "later"]
+        );
+    }
+
+    #[test]
+    fn test_next_host_is_next_human_in_seat_order() {
+        let mut seating = seated(&["host", "bot_1", "cat", "bob"]);
+        let players: HashMap<String, String> = [
+            ("host", "host"),
+            ("bot_1", BOT_ROLE),
+            ("cat", "player"),
+            ("bob", "player"),
+        ]
+        .into_iter()
+        .map(|(user_id, role)| (user_id.to_string(), role.to_string()))
+        .collect();
+
+        // The bot in seat 1 is skipped
+        assert_eq!(seating.next_host(&players, "host"), Some("cat".to_string()));
+
+        seating.swap("bob", 1).unwrap();
+        assert_eq!(seating.next_host(&players, "host"), Some("bob".to_string()));
+
+        // Only bots left
+        let bots_only: HashMap<String, String> = [("host", "host"), ("bot_1", BOT_ROLE)]
+            .into_iter()
+            .map(|(user_id, role)| (user_id.to_string(), role.to_string()))
+            .collect();
+        assert_eq!(seating.next_host(&bots_only, "host"), None);
+    }
+}
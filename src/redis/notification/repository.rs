@@ -1,3 +1,4 @@
+use crate::redis::normal_match::ready::ReadyCheck;
 use crate::redis::pubsub::repository::PubSubRepository;
 use deadpool_redis::Connection;
 use serde_json::Value;
@@ -49,6 +50,159 @@ impl NotificationRepository {
         .await
     }
 
+    /// Tell players who hosts the match now, and why it changed
+    pub async fn publish_host_changed(
+        conn: &mut Connection,
+        game_id: &str,
+        new_host_id: &str,
+        affected_players: Vec<String>,
+        reason: &str,
+    ) -> Result<(), String> {
+        let username: Option<String> = redis::cmd("HGET")
+            .arg("usernames")
+            .arg(new_host_id)
+            .query_async(&mut *conn)
+            .await
+            .unwrap_or(None);
+        let username = username.unwrap_or_else(|| new_host_id.to_string());
+
+        let additional_data = serde_json::json!({
+            "new_host_id": new_host_id,
+            "new_host_username": username,
+            "reason": reason
+        });
+
+        Self::publish_event(
+            conn,
+            "host_changed",
+            game_id,
+            affected_players,
+            &format!("{}; {} is now the host", reason, username),
+            Some(additional_data),
+        )
+        .await
+    }
+
+    /// Tell players, the kicked one included, that the host removed someone
+    pub async fn publish_player_kicked(
+        conn: &mut Connection,
+        game_id: &str,
+        player_id: &str,
+        cooldown_seconds: u64,
+        affected_players: Vec<String>,
+    ) -> Result<(), String> {
+        let additional_data = serde_json::json!({
+            "player_id": player_id,
+            "cooldown_seconds": cooldown_seconds
+        });
+
+        Self::publish_event(
+            conn,
+            "player_kicked",
+            game_id,
+            affected_players,
+            &format!("Player {} was removed by the host", player_id),
+            Some(additional_data),
+        )
+        .await
+    }
+
+    /// Tell players the host locked or unlocked the room
+    pub async fn publish_room_locked(
+        conn: &mut Connection,
+        game_id: &str,
+        locked: bool,
+        affected_players: Vec<String>,
+    ) -> Result<(), String> {
+        Self::publish_event(
+            conn,
+            "room_locked",
+            game_id,
+            affected_players,
+            if locked {
+                "The host locked the room"
+            } else {
+                "The host unlocked the room"
+            },
+            Some(serde_json::json!({ "locked": locked })),
+        )
+        .await
+    }
+
+    /// Tell players who is ready now that someone marked themselves ready or not
+    pub async fn publish_ready_changed(
+        conn: &mut Connection,
+        game_id: &str,
+        player_id: &str,
+        ready_check: &ReadyCheck,
+        affected_players: Vec<String>,
+    ) -> Result<(), String> {
+        let ready = ready_check.is_ready(player_id);
+        let additional_data = serde_json::json!({
+            "player_id": player_id,
+            "ready": ready,
+            "ready_players": ready_check.ready,
+            "waiting_for": ready_check.waiting_for,
+            "everyone_ready": ready_check.everyone_ready()
+        });
+
+        Self::publish_event(
+            conn,
+            "ready_changed",
+            game_id,
+            affected_players,
+            &format!(
+                "Player {} is {}",
+                player_id,
+                if ready { "ready" } else { "not ready" }
+            ),
+            Some(additional_data),
+        )
+        .await
+    }
+
+    /// Tell players the game starts by itself at `starts_at` (milliseconds)
+    pub async fn publish_auto_start_countdown(
+        conn: &mut Connection,
+        game_id: &str,
+        starts_at: i64,
+        seconds: u64,
+        affected_players: Vec<String>,
+    ) -> Result<(), String> {
+        let additional_data = serde_json::json!({
+            "starts_at": starts_at,
+            "seconds": seconds
+        });
+
+        Self::publish_event(
+            conn,
+            "auto_start_countdown",
+            game_id,
+            affected_players,
+            &format!("Everyone is ready; the game starts in {} seconds", seconds),
+            Some(additional_data),
+        )
+        .await
+    }
+
+    /// Tell players the auto-start countdown was stopped, and why
+    pub async fn publish_auto_start_cancelled(
+        conn: &mut Connection,
+        game_id: &str,
+        reason: &str,
+        affected_players: Vec<String>,
+    ) -> Result<(), String> {
+        Self::publish_event(
+            conn,
+            "auto_start_cancelled",
+            game_id,
+            affected_players,
+            reason,
+            Some(serde_json::json!({ "reason": reason })),
+        )
+        .await
+    }
+
     pub async fn publish_game_terminated(
         conn: &mut Connection,
         game_id: &str,
@@ -1,3 +1,6 @@
+use crate::game::bot::BotDifficulty;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::normal_match::seating::SEATS;
 use deadpool_redis::Connection;
 
 pub struct PlayerRepository;
@@ -18,6 +21,82 @@ impl PlayerRepository {
         Ok(game_id)
     }
 
+    /// Check if a user is listed in the `admin_users` set
+    pub async fn is_admin(conn: &mut Connection, user_id: &str) -> Result<bool, String> {
+        redis::cmd("SISMEMBER")
+            .arg("admin_users")
+            .arg(user_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to check admin status: {}", e))
+    }
+
+    /// Remember how strongly a bot seat plays
+    pub async fn set_bot_difficulty(
+        conn: &mut Connection,
+        game_id: &str,
+        bot_id: &str,
+        difficulty: &BotDifficulty,
+    ) -> Result<(), String> {
+        redis::cmd("HSET")
+            .arg(format!("game:{}:bot_difficulties", game_id))
+            .arg(bot_id)
+            .arg(difficulty.to_string())
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store bot difficulty: {}", e))
+    }
+
+    /// Difficulty of a bot seat, `Normal` if none was stored
+    pub async fn get_bot_difficulty(
+        conn: &mut Connection,
+        game_id: &str,
+        bot_id: &str,
+    ) -> Result<BotDifficulty, String> {
+        let difficulty: Option<String> = redis::cmd("HGET")
+            .arg(format!("game:{}:bot_difficulties", game_id))
+            .arg(bot_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get bot difficulty: {}", e))?;
+
+        Ok(difficulty
+            .and_then(|value| BotDifficulty::parse(&value))
+            .unwrap_or_default())
+    }
+
+    /// Turn bidding advice on or off for a player in their match
+    pub async fn set_hints_enabled(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+        enabled: bool,
+    ) -> Result<(), String> {
+        redis::cmd("HSET")
+            .arg(format!("game:{}:player_hints", game_id))
+            .arg(user_id)
+            .arg(enabled.to_string())
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store hint setting: {}", e))
+    }
+
+    /// Whether a player wants bidding advice, off unless they turned it on
+    pub async fn get_hints_enabled(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+    ) -> Result<bool, String> {
+        let enabled: Option<String> = redis::cmd("HGET")
+            .arg(format!("game:{}:player_hints", game_id))
+            .arg(user_id)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to get hint setting: {}", e))?;
+
+        Ok(enabled.as_deref() == Some("true"))
+    }
+
     /// Associate a player with a game
     pub async fn associate_with_game(
         conn: &mut Connection,
@@ -50,33 +129,95 @@ impl PlayerRepository {
         Ok(())
     }
 
-    /// Get all players in a specific game with their roles
+    /// Get all players in a specific game with their roles and seats, in seat order
+    ///
+    /// Seats can be empty, so look players up by `seat` rather than by their
+    /// index in the list. Players from before seating existed take the free
+    /// seats in join order.
     pub async fn get_players_in_game(
         conn: &mut Connection,
         game_id: &str,
     ) -> Result<Vec<PlayerGameInfo>, String> {
-        use std::collections::HashMap;
-
         let players_key = format!("normal_match:{}:players", game_id);
 
-        let players_hash: HashMap<String, String> = redis::cmd("HGETALL")
+        // Keep Redis' order (join order) for players without a seat
+        let players_hash: Vec<(String, String)> = redis::cmd("HGETALL")
             .arg(&players_key)
             .query_async(&mut *conn)
             .await
             .map_err(|e| format!("Failed to get players: {}", e))?;
 
+        let seating = NormalMatchRepository::get_seating(conn, game_id).await?;
+        let mut free_seats = (0..SEATS).filter(|seat| seating.seats()[*seat].is_none());
+
         let players = players_hash
             .into_iter()
-            .map(|(user_id, role)| PlayerGameInfo { user_id, role })
+            .map(|(user_id, role)| PlayerGameInfo {
+                seat: seating.seat_of(&user_id),
+                user_id,
+                role,
+            })
             .collect();
 
+        let mut players = seating.order(players, |player| &player.user_id);
+        for player in players.iter_mut().filter(|player| player.seat.is_none()) {
+            player.seat = free_seats.next();
+        }
         Ok(players)
     }
 }
 
+/// Role given to server-controlled bot seats
+pub const BOT_ROLE: &str = "bot";
+
 /// Information about a player in a game
 #[derive(Debug, Clone)]
 pub struct PlayerGameInfo {
     pub user_id: String,
     pub role: String,
+    /// Position at the table, `None` only when more players joined than there are seats
+    pub seat: Option<usize>,
+}
+
+impl PlayerGameInfo {
+    /// Whether this seat is played by the server
+    pub fn is_bot(&self) -> bool {
+        self.role == BOT_ROLE
+    }
+}
+
+/// The player sitting in `seat`, if it is taken
+pub fn player_in_seat(players: &[PlayerGameInfo], seat: usize) -> Option<&PlayerGameInfo> {
+    players.iter().find(|player| player.seat == Some(seat))
+}
+
+/// The seat `user_id` sits in, if they are at the table
+pub fn seat_of(players: &[PlayerGameInfo], user_id: &str) -> Option<usize> {
+    players
+        .iter()
+        .find(|player| player.user_id == user_id)
+        .and_then(|player| player.seat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_players_are_found_by_seat_not_index() {
+        // Seat 1 is empty, so the player in seat 2 is second in the list
+        let players: Vec<PlayerGameInfo> = [("host", 0), ("bob", 2), ("cat", 3)]
+            .into_iter()
+            .map(|(user_id, seat)| PlayerGameInfo {
+                user_id: user_id.to_string(),
+                role: "player".to_string(),
+                seat: Some(seat),
+            })
+            .collect();
+
+        assert!(player_in_seat(&players, 1).is_none());
+        assert_eq!(player_in_seat(&players, 2).unwrap().user_id, "bob");
+        assert_eq!(seat_of(&players, "cat"), Some(3));
+        assert_eq!(seat_of(&players, "dan"), None);
+    }
 }
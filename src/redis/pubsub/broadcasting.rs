@@ -1,4 +1,6 @@
+use crate::redis::normal_match::seating::Seating;
 use crate::redis::player::repository::PlayerRepository;
+use crate::redis::takeback::TakebackRequest;
 use deadpool_redis::Connection;
 use serde_json::{json, Value};
 
@@ -100,6 +102,243 @@ pub async fn broadcast_bidding_complete(
     publish_event(redis_conn, game_id, &event_data).await
 }
 
+/// Broadcast that the next game of the match has been dealt
+pub async fn broadcast_next_game(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    dealer_position: u8,
+    current_bidder: u8,
+    current_cross: u32,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "next_game",
+        "game_id": game_id,
+        "dealer_position": dealer_position,
+        "current_bidder": current_bidder,
+        "current_cross": current_cross,
+        "affected_players": affected_players,
+        "message": "Next game dealt"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast the hash commitment of a new deal before bidding starts
+pub async fn broadcast_deal_commitment(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    deal_number: u32,
+    commitment: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "deal_commitment",
+        "game_id": game_id,
+        "deal_number": deal_number,
+        "commitment": commitment,
+        "affected_players": affected_players,
+        "message": "Deal committed"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast the seed and deck order behind a finished deal
+pub async fn broadcast_deal_revealed(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    deal_number: u32,
+    commitment: &str,
+    seed: u64,
+    salt: &str,
+    deck_order: &[String],
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "deal_revealed",
+        "game_id": game_id,
+        "deal_number": deal_number,
+        "commitment": commitment,
+        "seed": seed.to_string(),
+        "salt": salt,
+        "deck_order": deck_order,
+        "affected_players": affected_players,
+        "message": "Deal revealed"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast that a player's claim of the remaining tricks was accepted
+pub async fn broadcast_claim_accepted(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    player_position: u8,
+    statement: Option<&str>,
+    tricks_claimed: u8,
+    trump_team_score: u8,
+    opponent_team_score: u8,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "claim_accepted",
+        "game_id": game_id,
+        "player_position": player_position,
+        "statement": statement,
+        "tricks_claimed": tricks_claimed,
+        "trump_team_score": trump_team_score,
+        "opponent_team_score": opponent_team_score,
+        "affected_players": affected_players,
+        "message": "Claim accepted"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast that a player's claim was rejected because the other team can take a trick
+pub async fn broadcast_claim_rejected(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    player_position: u8,
+    statement: Option<&str>,
+    tricks_lost: u8,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "claim_rejected",
+        "game_id": game_id,
+        "player_position": player_position,
+        "statement": statement,
+        "tricks_lost": tricks_lost,
+        "affected_players": affected_players,
+        "message": "Claim rejected"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast a request to take back a card to all players in the game
+pub async fn broadcast_takeback_requested(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    request: &TakebackRequest,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "takeback_requested",
+        "game_id": game_id,
+        "request_id": request.request_id,
+        "player_position": request.player_position,
+        "card": request.card.code(),
+        "trick_number": request.trick_number,
+        "expires_at": request.expires_at,
+        "affected_players": affected_players,
+        "message": "Takeback requested"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast that a card was taken back to all players in the game
+pub async fn broadcast_takeback_accepted(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    request: &TakebackRequest,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "takeback_accepted",
+        "game_id": game_id,
+        "request_id": request.request_id,
+        "player_position": request.player_position,
+        "card": request.card.code(),
+        "trick_number": request.trick_number,
+        "next_player": request.player_position,
+        "affected_players": affected_players,
+        "message": "Card taken back"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast that a takeback request ended without the card coming back
+///
+/// `outcome` is "denied", "expired" or "cancelled" and names the event.
+pub async fn broadcast_takeback_closed(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    request: &TakebackRequest,
+    outcome: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": format!("takeback_{}", outcome),
+        "game_id": game_id,
+        "request_id": request.request_id,
+        "player_position": request.player_position,
+        "affected_players": affected_players,
+        "message": format!("Takeback {}", outcome)
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Broadcast the waiting room's seating to all players in the game
+pub async fn broadcast_seating_updated(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    seating: &Seating,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let affected_players: Vec<String> = players.iter().map(|p| p.user_id.clone()).collect();
+
+    let event_data = json!({
+        "event": "seating_updated",
+        "game_id": game_id,
+        "seats": seating.seats(),
+        "affected_players": affected_players,
+        "message": "Seats changed"
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
+/// Tell a player which rule their action broke and what they may do instead
+pub async fn broadcast_rule_violation(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    player_id: &str,
+    violation_data: &Value,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let event_data = json!({
+        "event": "rule_violation",
+        "game_id": game_id,
+        "player_id": player_id,
+        "violation": violation_data,
+        "affected_players": [player_id],
+        "message": violation_data["message"].as_str().unwrap_or("Illegal action")
+    });
+
+    publish_event(redis_conn, game_id, &event_data).await
+}
+
 /// Broadcast a hand update to a specific player
 pub async fn broadcast_hand_update(
     redis_conn: &mut Connection,
@@ -16,6 +16,11 @@ impl PubSubRepository {
         format!("player_events:{}", player_id)
     }
 
+    // Channel for lobby events, shared by every public match
+    pub fn lobby_channel() -> String {
+        "lobby_events".to_string()
+    }
+
     // Generate a unique instance ID for this server
     pub fn generate_instance_id() -> String {
         format!("instance:{}", Uuid::new_v4())
@@ -70,16 +75,56 @@ impl PubSubRepository {
         Ok(())
     }
 
+    /// Publish a lobby event to every instance; each forwards it to its lobby subscribers
+    pub async fn publish_lobby_event(
+        conn: &mut Connection,
+        event_type: &str,
+        game_id: &str,
+        message: &str,
+        additional_data: Option<Value>,
+    ) -> Result<(), String> {
+        let mut payload = serde_json::json!({
+            "event": event_type,
+            "game_id": game_id,
+            "message": message
+        });
+
+        if let Some(extra_data) = additional_data {
+            if let Some(obj) = payload.as_object_mut() {
+                for (key, value) in extra_data.as_object().unwrap_or(&serde_json::Map::new()) {
+                    obj.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        let json_payload = serde_json::to_string(&payload).unwrap_or_else(|_| "{}".to_string());
+
+        redis::cmd("PUBLISH")
+            .arg(Self::lobby_channel())
+            .arg(&json_payload)
+            .query_async::<_, i32>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to publish to lobby channel: {}", e))?;
+
+        Ok(())
+    }
+
     /// Subscribe to channels for specific games and players
     /// Returns the PubSub object that can be used for listening to messages
     pub async fn subscribe_to_channels(
         conn: redis::aio::Connection,
         game_ids: &HashSet<String>,
         player_ids: &HashSet<String>,
+        lobby: bool,
     ) -> Result<redis::aio::PubSub, String> {
         // Create a vector of channels to subscribe to
         let mut channels = Vec::new();
 
+        // Add the lobby channel while anyone here is watching the lobby
+        if lobby {
+            channels.push(Self::lobby_channel());
+        }
+
         // Add game channels
         for game_id in game_ids {
             channels.push(Self::game_channel(game_id));
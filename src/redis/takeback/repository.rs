@@ -0,0 +1,148 @@
+use crate::game::card::Card;
+use crate::game::hand::Hand;
+use crate::game::trick::GameTrickState;
+use deadpool_redis::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json;
+
+/// A player's pending request to take back the last card they played
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakebackRequest {
+    pub request_id: String,
+    pub requester_id: String,
+    pub player_position: usize,
+    pub card: Card,
+    /// Trick the card was played to
+    pub trick_number: u8,
+    /// Cards in that trick, including the one to take back
+    pub cards_in_trick: usize,
+    /// Milliseconds since the epoch when the request lapses
+    pub expires_at: i64,
+}
+
+pub struct TakebackRepository;
+
+impl TakebackRepository {
+    /// Store a new request unless one is already pending
+    ///
+    /// Returns false when another request holds the slot.
+    pub async fn create_request(
+        conn: &mut Connection,
+        game_id: &str,
+        request: &TakebackRequest,
+        ttl_seconds: u64,
+    ) -> Result<bool, String> {
+        let key = format!("game:{}:takeback", game_id);
+        let serialized = serde_json::to_string(request)
+            .map_err(|e| format!("Failed to serialize takeback request: {}", e))?;
+
+        let created: Option<String> = redis::cmd("SET")
+            .arg(&key)
+            .arg(&serialized)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl_seconds)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to store takeback request: {}", e))?;
+
+        Ok(created.is_some())
+    }
+
+    /// Get the pending request, if any
+    pub async fn get_request(
+        conn: &mut Connection,
+        game_id: &str,
+    ) -> Result<Option<TakebackRequest>, String> {
+        let key = format!("game:{}:takeback", game_id);
+
+        let serialized: Option<String> = redis::cmd("GET")
+            .arg(&key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to retrieve takeback request: {}", e))?;
+
+        serialized
+            .map(|data| {
+                serde_json::from_str(&data)
+                    .map_err(|e| format!("Failed to deserialize takeback request: {}", e))
+            })
+            .transpose()
+    }
+
+    /// Record a player's approval, returning how many players have approved
+    pub async fn add_approval(
+        conn: &mut Connection,
+        game_id: &str,
+        user_id: &str,
+        ttl_seconds: u64,
+    ) -> Result<usize, String> {
+        let key = format!("game:{}:takeback:approvals", game_id);
+
+        redis::cmd("SADD")
+            .arg(&key)
+            .arg(user_id)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to record takeback approval: {}", e))?;
+
+        redis::cmd("EXPIRE")
+            .arg(&key)
+            .arg(ttl_seconds)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to set expiration: {}", e))?;
+
+        redis::cmd("SCARD")
+            .arg(&key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to count takeback approvals: {}", e))
+    }
+
+    /// Drop the pending request and its approvals
+    pub async fn clear_request(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        redis::cmd("DEL")
+            .arg(format!("game:{}:takeback", game_id))
+            .arg(format!("game:{}:takeback:approvals", game_id))
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear takeback request: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Write the rolled back trick and hand and close the request in one transaction
+    pub async fn commit_takeback(
+        conn: &mut Connection,
+        game_id: &str,
+        trick_state: &GameTrickState,
+        player_position: usize,
+        hand: &Hand,
+    ) -> Result<(), String> {
+        let trick_data = serde_json::to_string(trick_state)
+            .map_err(|e| format!("Failed to serialize trick state: {}", e))?;
+        let hand_data =
+            serde_json::to_string(hand).map_err(|e| format!("Failed to serialize hand: {}", e))?;
+
+        redis::pipe()
+            .atomic()
+            .cmd("SET")
+            .arg(format!("game_trick_state:{}", game_id))
+            .arg(&trick_data)
+            .ignore()
+            .cmd("SET")
+            .arg(format!("game:{}:hand:{}", game_id, player_position))
+            .arg(&hand_data)
+            .ignore()
+            .cmd("DEL")
+            .arg(format!("game:{}:takeback", game_id))
+            .arg(format!("game:{}:takeback:approvals", game_id))
+            .ignore()
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to commit takeback: {}", e))?;
+
+        Ok(())
+    }
+}
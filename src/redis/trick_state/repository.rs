@@ -1,4 +1,4 @@
-use crate::game::trick::{GameTrickState, TrickState};
+use crate::game::trick::{GameTrickState, PlayedDeal, TrickState};
 use deadpool_redis::Connection;
 use serde_json;
 
@@ -62,6 +62,8 @@ impl TrickStateRepository {
     }
 
     /// Store completed trick for history
+    ///
+    /// Kept until the next deal starts, so a slow deal never loses its early tricks.
     pub async fn store_completed_trick(
         conn: &mut Connection,
         game_id: &str,
@@ -74,8 +76,6 @@ impl TrickStateRepository {
         redis::cmd("SET")
             .arg(&key)
             .arg(&serialized)
-            .arg("EX")
-            .arg(3600) // Expire after 1 hour
             .query_async::<_, ()>(&mut *conn)
             .await
             .map_err(|e| format!("Failed to store completed trick: {}", e))?;
@@ -83,6 +83,19 @@ impl TrickStateRepository {
         Ok(())
     }
 
+    /// Forget the finished deal's tricks before the next deal is played
+    pub async fn clear_trick_history(conn: &mut Connection, game_id: &str) -> Result<(), String> {
+        let keys: Vec<String> = (1..=8)
+            .map(|trick_number| format!("game_trick_history:{}:{}", game_id, trick_number))
+            .collect();
+
+        redis::cmd("DEL")
+            .arg(keys)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to clear trick history: {}", e))
+    }
+
     /// Get trick history for a game
     pub async fn get_trick_history(
         conn: &mut Connection,
@@ -111,6 +124,52 @@ impl TrickStateRepository {
         Ok(tricks)
     }
 
+    /// Keep a finished deal's bid and tricks, since the trick history is
+    /// cleared when the next deal starts
+    pub async fn archive_played_deal(
+        conn: &mut Connection,
+        game_id: &str,
+        deal_number: usize,
+        deal: &PlayedDeal,
+    ) -> Result<(), String> {
+        let key = format!("game:{}:played_deals", game_id);
+        let serialized = serde_json::to_string(deal)
+            .map_err(|e| format!("Failed to serialize played deal: {}", e))?;
+
+        redis::cmd("HSET")
+            .arg(&key)
+            .arg(deal_number)
+            .arg(&serialized)
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to archive played deal: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Get an archived deal by its deal number
+    pub async fn get_played_deal(
+        conn: &mut Connection,
+        game_id: &str,
+        deal_number: usize,
+    ) -> Result<Option<PlayedDeal>, String> {
+        let key = format!("game:{}:played_deals", game_id);
+
+        let serialized: Option<String> = redis::cmd("HGET")
+            .arg(&key)
+            .arg(deal_number)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to retrieve played deal: {}", e))?;
+
+        serialized
+            .map(|data| {
+                serde_json::from_str(&data)
+                    .map_err(|e| format!("Failed to deserialize played deal: {}", e))
+            })
+            .transpose()
+    }
+
     /// Check if trick state exists for a game
     pub async fn trick_state_exists(conn: &mut Connection, game_id: &str) -> Result<bool, String> {
         let key = format!("game_trick_state:{}", game_id);
@@ -1,5 +1,9 @@
 pub mod bidding;
 pub mod join;
+pub mod lobby;
+pub mod moderation;
 pub mod playing;
+pub mod ready;
+pub mod takeback;
 pub mod team_up_request;
 pub mod team_up_response;
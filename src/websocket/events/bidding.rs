@@ -1,5 +1,5 @@
 use crate::redis::normal_match::repository::NormalMatchRepository;
-use crate::redis::player::repository::PlayerRepository;
+use crate::redis::player::repository::{player_in_seat, PlayerGameInfo, PlayerRepository};
 use crate::websocket::handler::AppState;
 use crate::websocket::types::GameMessage;
 use deadpool_redis::Connection;
@@ -40,14 +40,14 @@ pub async fn handle_bid_made_event(
     // Get player information
     let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
 
-    if bidder_position as usize >= players.len() {
+    if player_in_seat(&players, bidder_position as usize).is_none() {
         return Err("Invalid bidder position".into());
     }
 
     let bidder_username =
-        get_player_username(redis_conn, &players[bidder_position as usize].user_id).await?;
+        get_player_username(redis_conn, &players, bidder_position as usize).await?;
     let next_bidder_username =
-        get_player_username(redis_conn, &players[current_bidder as usize].user_id).await?;
+        get_player_username(redis_conn, &players, current_bidder as usize).await?;
 
     // Create bid event message
     let bid_event = GameMessage::new(
@@ -110,12 +110,12 @@ pub async fn handle_pass_made_event(
     // Get player information
     let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
 
-    if passer_position as usize >= players.len() {
+    if player_in_seat(&players, passer_position as usize).is_none() {
         return Err("Invalid passer position".into());
     }
 
     let passer_username =
-        get_player_username(redis_conn, &players[passer_position as usize].user_id).await?;
+        get_player_username(redis_conn, &players, passer_position as usize).await?;
 
     let message = if all_passed {
         format!(
@@ -126,7 +126,7 @@ pub async fn handle_pass_made_event(
         format!("{} passed - Bidding complete!", passer_username)
     } else {
         let next_bidder_username =
-            get_player_username(redis_conn, &players[current_bidder as usize].user_id).await?;
+            get_player_username(redis_conn, &players, current_bidder as usize).await?;
         format!(
             "{} passed - {} to bid",
             passer_username, next_bidder_username
@@ -180,9 +180,9 @@ pub async fn handle_redeal_event(
     let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
 
     let dealer_username =
-        get_player_username(redis_conn, &players[dealer_position as usize].user_id).await?;
+        get_player_username(redis_conn, &players, dealer_position as usize).await?;
     let first_bidder_username =
-        get_player_username(redis_conn, &players[current_bidder as usize].user_id).await?;
+        get_player_username(redis_conn, &players, current_bidder as usize).await?;
 
     // Create redeal event message
     let redeal_event = GameMessage::new(
@@ -233,30 +233,22 @@ pub async fn handle_bidding_complete_event(
     let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
 
     let trump_declarer_username =
-        get_player_username(redis_conn, &players[trump_declarer as usize].user_id).await?;
+        get_player_username(redis_conn, &players, trump_declarer as usize).await?;
 
     // Determine partnership (trump declarer + partner with highest trump)
     // For now, use opposite player as partner (will be enhanced in trick-taking phase)
     let partner_position = (trump_declarer + 2) % 4;
     let partner_username =
-        get_player_username(redis_conn, &players[partner_position as usize].user_id).await?;
+        get_player_username(redis_conn, &players, partner_position as usize).await?;
 
     let opponents = vec![
         (
             (trump_declarer + 1) % 4,
-            get_player_username(
-                redis_conn,
-                &players[((trump_declarer + 1) % 4) as usize].user_id,
-            )
-            .await?,
+            get_player_username(redis_conn, &players, ((trump_declarer + 1) % 4) as usize).await?,
         ),
         (
             (trump_declarer + 3) % 4,
-            get_player_username(
-                redis_conn,
-                &players[((trump_declarer + 3) % 4) as usize].user_id,
-            )
-            .await?,
+            get_player_username(redis_conn, &players, ((trump_declarer + 3) % 4) as usize).await?,
         ),
     ];
 
@@ -382,14 +374,16 @@ pub async fn handle_game_state_update_event(
     Ok(())
 }
 
-/// Helper function to get player username
+/// Helper function to get the username of the player in `seat`
 async fn get_player_username(
     redis_conn: &mut Connection,
-    user_id: &str,
+    players: &[PlayerGameInfo],
+    seat: usize,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let player = player_in_seat(players, seat).ok_or("No player in that seat")?;
     let username: String = redis::cmd("HGET")
         .arg("usernames")
-        .arg(user_id)
+        .arg(&player.user_id)
         .query_async(redis_conn)
         .await
         .unwrap_or_else(|_| "Unknown Player".to_string());
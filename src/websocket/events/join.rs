@@ -76,7 +76,8 @@ pub async fn handle_join_event(
                 "number_of_crosses": game.number_of_crosses,
                 "current_cross": game.current_cross,
                 "created_timestamp": game.created_timestamp,
-                "host": host_id.unwrap_or_default()
+                "host": host_id.unwrap_or_default(),
+                "rules": game.rules
             }
         }),
     )
@@ -0,0 +1,39 @@
+use crate::redis::lobby::LobbyRepository;
+use crate::websocket::handler::AppState;
+use crate::websocket::types::GameMessage;
+use deadpool_redis::Connection;
+use std::sync::Arc;
+
+/// Start sending lobby events to the user, beginning with the current listings
+pub async fn handle_lobby_subscribe(
+    state: &Arc<AppState>,
+    user_id: &str,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    state
+        .lobby_subscribers
+        .lock()
+        .await
+        .insert(user_id.to_string());
+
+    let listings = LobbyRepository::get_listings(redis_conn).await?;
+    let lobby_msg = GameMessage::new(
+        "lobby_state".to_string(),
+        serde_json::json!({
+            "message": "Subscribed to lobby updates",
+            "matches": listings
+        }),
+    );
+
+    if let Some(tx) = state.user_connections.get(user_id) {
+        let msg = serde_json::to_string(&lobby_msg)?;
+        tx.send(axum::extract::ws::Message::Text(msg)).await?;
+    }
+
+    Ok(())
+}
+
+/// Stop sending lobby events to the user
+pub async fn handle_lobby_unsubscribe(state: &Arc<AppState>, user_id: &str) {
+    state.lobby_subscribers.lock().await.remove(user_id);
+}
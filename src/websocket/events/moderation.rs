@@ -0,0 +1,78 @@
+use crate::api::handlers::moderation::{self, Refusal};
+use crate::websocket::handler::AppState;
+use crate::websocket::types::GameMessage;
+use deadpool_redis::Connection;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Host command: remove a player from a waiting match
+pub async fn handle_kick_player(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let player_id = match data.get("player_id").and_then(|v| v.as_str()) {
+        Some(id) => id,
+        None => return Err("Missing player_id in kick request".into()),
+    };
+
+    let result = moderation::kick_player(redis_conn, user_id, player_id).await;
+    report(state, user_id, result).await
+}
+
+/// Host command: hand the host role to another player
+pub async fn handle_transfer_host(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let player_id = match data.get("player_id").and_then(|v| v.as_str()) {
+        Some(id) => id,
+        None => return Err("Missing player_id in host transfer".into()),
+    };
+
+    let result = moderation::transfer_host(redis_conn, user_id, player_id).await;
+    report(state, user_id, result).await
+}
+
+/// Host command: lock or unlock the room
+pub async fn handle_lock_room(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let locked = match data.get("locked").and_then(|v| v.as_bool()) {
+        Some(locked) => locked,
+        None => return Err("Missing locked in lock request".into()),
+    };
+
+    let result = moderation::lock_room(redis_conn, user_id, locked).await;
+    report(state, user_id, result).await
+}
+
+/// Everyone hears about a successful action through pub/sub; only refusals
+/// need answering here
+async fn report(
+    state: &Arc<AppState>,
+    user_id: &str,
+    result: Result<String, Refusal>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Err((_, reason)) = result else {
+        return Ok(());
+    };
+
+    let error_msg = GameMessage::new(
+        "moderation_error".to_string(),
+        serde_json::json!({ "message": reason }),
+    );
+
+    if let Some(tx) = state.user_connections.get(user_id) {
+        let msg = serde_json::to_string(&error_msg)?;
+        tx.send(axum::extract::ws::Message::Text(msg)).await?;
+    }
+
+    Err(reason.into())
+}
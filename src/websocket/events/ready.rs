@@ -0,0 +1,131 @@
+use crate::api::handlers::game_start;
+use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::notification::repository::NotificationRepository;
+use crate::redis::player::repository::PlayerRepository;
+use crate::websocket::handler::AppState;
+use crate::websocket::types::GameMessage;
+use deadpool_redis::Connection;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Mark yourself ready or not ready to start; leaving out `ready` toggles it
+pub async fn handle_set_ready(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let ready = data.get("ready").and_then(|v| v.as_bool());
+
+    match set_ready(state, user_id, ready, redis_conn).await {
+        Ok(()) => Ok(()),
+        Err(reason) => {
+            send_error(state, user_id, &reason).await?;
+            Err(reason.into())
+        }
+    }
+}
+
+/// Host command: stop the auto-start countdown, leaving auto-start on
+pub async fn handle_cancel_auto_start(
+    state: &Arc<AppState>,
+    user_id: &str,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match cancel_countdown(user_id, redis_conn).await {
+        Ok(()) => Ok(()),
+        Err(reason) => {
+            send_error(state, user_id, &reason).await?;
+            Err(reason.into())
+        }
+    }
+}
+
+async fn set_ready(
+    state: &Arc<AppState>,
+    user_id: &str,
+    ready: Option<bool>,
+    redis_conn: &mut Connection,
+) -> Result<(), String> {
+    // 1. Only players at a waiting table can get ready
+    let game_id = PlayerRepository::get_player_game(redis_conn, user_id)
+        .await?
+        .ok_or("You are not in a match")?;
+    let game_match = NormalMatchRepository::get_by_id(redis_conn, &game_id)
+        .await?
+        .ok_or("Game not found")?;
+    if game_match.status != NormalMatchStatus::Waiting {
+        return Err("The game has already started".to_string());
+    }
+
+    let ready = match ready {
+        Some(ready) => ready,
+        None => !NormalMatchRepository::get_ready_check(redis_conn, &game_id)
+            .await?
+            .is_ready(user_id),
+    };
+
+    // 2. Record it and tell the table who is ready
+    NormalMatchRepository::set_ready(redis_conn, &game_id, user_id, ready).await?;
+
+    let ready_check = NormalMatchRepository::get_ready_check(redis_conn, &game_id).await?;
+    let affected_players = ready_check
+        .ready
+        .iter()
+        .chain(&ready_check.waiting_for)
+        .cloned()
+        .collect();
+    if let Err(e) = NotificationRepository::publish_ready_changed(
+        redis_conn,
+        &game_id,
+        user_id,
+        &ready_check,
+        affected_players,
+    )
+    .await
+    {
+        eprintln!("Failed to publish ready change: {}", e);
+    }
+
+    // 3. The last player getting ready may start the countdown, anyone backing out stops it
+    if ready {
+        game_start::check_auto_start(&state.redis_pool, redis_conn, &game_id).await
+    } else {
+        game_start::cancel_auto_start(redis_conn, &game_id, "A player is no longer ready").await
+    }
+}
+
+async fn cancel_countdown(user_id: &str, redis_conn: &mut Connection) -> Result<(), String> {
+    let game_id = PlayerRepository::get_player_game(redis_conn, user_id)
+        .await?
+        .ok_or("You are not in a match")?;
+
+    let players = PlayerRepository::get_players_in_game(redis_conn, &game_id).await?;
+    if !players
+        .iter()
+        .any(|p| p.user_id == user_id && p.role == "host")
+    {
+        return Err("Only the host can cancel the countdown".to_string());
+    }
+
+    game_start::cancel_auto_start(redis_conn, &game_id, "The host cancelled the countdown").await
+}
+
+async fn send_error(
+    state: &Arc<AppState>,
+    user_id: &str,
+    reason: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let error_msg = GameMessage::new(
+        "ready_error".to_string(),
+        serde_json::json!({ "message": reason }),
+    );
+
+    if let Some(tx) = state.user_connections.get(user_id) {
+        let msg = serde_json::to_string(&error_msg)?;
+        tx.send(axum::extract::ws::Message::Text(msg)).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,337 @@
+use crate::api::handlers::bots;
+use crate::game::engine::SjavsGame;
+use crate::redis::game_state::repository::GameStateRepository;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::player::repository::{seat_of, PlayerGameInfo, PlayerRepository};
+use crate::redis::pubsub::broadcasting;
+use crate::redis::takeback::{TakebackRepository, TakebackRequest};
+use crate::redis::trick_state::TrickStateRepository;
+use crate::websocket::handler::AppState;
+use crate::websocket::types::GameMessage;
+use chrono::Utc;
+use deadpool_redis::Connection;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// How long the other players have to answer a takeback request
+pub const TAKEBACK_TIMEOUT_SECONDS: u64 = 30;
+
+/// Approvals needed: every other player at the table
+const APPROVALS_NEEDED: usize = 3;
+
+/// Ask the table to take back the last card you played
+pub async fn handle_takeback_request(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let game_id = match data.get("game_id").and_then(|v| v.as_str()) {
+        Some(id) => id,
+        None => return Err("Missing game_id in takeback request".into()),
+    };
+
+    match request_takeback(state, user_id, game_id, redis_conn).await {
+        Ok(()) => Ok(()),
+        Err(reason) => {
+            send_error(state, user_id, "takeback_request_error", game_id, &reason).await?;
+            Err(reason.into())
+        }
+    }
+}
+
+/// Approve or deny another player's takeback request
+pub async fn handle_takeback_response(
+    state: &Arc<AppState>,
+    user_id: &str,
+    data: &Value,
+    redis_conn: &mut Connection,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let game_id = match data.get("game_id").and_then(|v| v.as_str()) {
+        Some(id) => id,
+        None => return Err("Missing game_id in takeback response".into()),
+    };
+
+    let approve = match data.get("approve").and_then(|v| v.as_bool()) {
+        Some(approve) => approve,
+        None => return Err("Missing approve in takeback response".into()),
+    };
+
+    match respond_to_takeback(state, user_id, game_id, approve, redis_conn).await {
+        Ok(()) => Ok(()),
+        Err(reason) => {
+            send_error(state, user_id, "takeback_response_error", game_id, &reason).await?;
+            Err(reason.into())
+        }
+    }
+}
+
+async fn request_takeback(
+    state: &Arc<AppState>,
+    user_id: &str,
+    game_id: &str,
+    redis_conn: &mut Connection,
+) -> Result<(), String> {
+    // 1. Load the table and check the player's card is the last one down
+    let (players, game) = load_table(redis_conn, game_id).await?;
+    let player_position = seat_of(&players, user_id).ok_or("You are not in this game")?;
+
+    let trick = &game
+        .tricks
+        .as_ref()
+        .ok_or("Trick state not initialized")?
+        .current_trick;
+    let card = match trick.cards_played.last() {
+        Some((player, card)) if *player == player_position => *card,
+        Some(_) => return Err("Only the last card played can be taken back".to_string()),
+        None => return Err("There is no card in the current trick to take back".to_string()),
+    };
+
+    // 2. Open the request unless another one is pending
+    let request = TakebackRequest {
+        request_id: Uuid::new_v4().to_string(),
+        requester_id: user_id.to_string(),
+        player_position,
+        card,
+        trick_number: trick.trick_number,
+        cards_in_trick: trick.cards_played.len(),
+        expires_at: Utc::now().timestamp_millis() + TAKEBACK_TIMEOUT_SECONDS as i64 * 1000,
+    };
+
+    // The key outlives the vote so the timeout task still finds it
+    if !TakebackRepository::create_request(
+        redis_conn,
+        game_id,
+        &request,
+        TAKEBACK_TIMEOUT_SECONDS + 10,
+    )
+    .await?
+    {
+        return Err("A takeback request is already pending".to_string());
+    }
+
+    if let Err(e) = broadcasting::broadcast_takeback_requested(redis_conn, game_id, &request).await
+    {
+        eprintln!("Failed to broadcast takeback request: {}", e);
+    }
+
+    // 3. Bots never stand in the way of a takeback
+    let mut approvals = 0;
+    for bot in players
+        .iter()
+        .filter(|p| p.is_bot() && p.user_id != user_id)
+    {
+        approvals = TakebackRepository::add_approval(
+            redis_conn,
+            game_id,
+            &bot.user_id,
+            TAKEBACK_TIMEOUT_SECONDS + 10,
+        )
+        .await?;
+    }
+    if approvals >= APPROVALS_NEEDED {
+        return accept_takeback(redis_conn, game_id, &request).await;
+    }
+
+    // 4. Close the vote if the table hasn't answered in time
+    spawn_takeback_timeout(state, game_id.to_string(), request.request_id.clone());
+
+    Ok(())
+}
+
+async fn respond_to_takeback(
+    state: &Arc<AppState>,
+    user_id: &str,
+    game_id: &str,
+    approve: bool,
+    redis_conn: &mut Connection,
+) -> Result<(), String> {
+    // 1. Find the pending request
+    let request = TakebackRepository::get_request(redis_conn, game_id)
+        .await?
+        .ok_or("No takeback request is pending")?;
+
+    if request.requester_id == user_id {
+        return Err("You cannot answer your own takeback request".to_string());
+    }
+
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    if !players.iter().any(|p| p.user_id == user_id) {
+        return Err("You are not in this game".to_string());
+    }
+
+    // 2. A single denial ends the vote
+    if !approve {
+        TakebackRepository::clear_request(redis_conn, game_id).await?;
+        if let Err(e) =
+            broadcasting::broadcast_takeback_closed(redis_conn, game_id, &request, "denied").await
+        {
+            eprintln!("Failed to broadcast takeback denial: {}", e);
+        }
+
+        // Play was held up by the vote, so a bot may be waiting to move
+        bots::spawn_bot_turns(state.redis_pool.clone(), game_id.to_string());
+        return Ok(());
+    }
+
+    // 3. Take the card back once everyone else has agreed
+    let approvals = TakebackRepository::add_approval(
+        redis_conn,
+        game_id,
+        user_id,
+        TAKEBACK_TIMEOUT_SECONDS + 10,
+    )
+    .await?;
+    if approvals >= APPROVALS_NEEDED {
+        accept_takeback(redis_conn, game_id, &request).await?;
+    }
+
+    Ok(())
+}
+
+/// Roll the card back out of the trick and into the requester's hand
+async fn accept_takeback(
+    redis_conn: &mut Connection,
+    game_id: &str,
+    request: &TakebackRequest,
+) -> Result<(), String> {
+    // 1. Make sure nothing has moved on since the request was made
+    let (_, mut game) = load_table(redis_conn, game_id).await?;
+    let trick = &game
+        .tricks
+        .as_ref()
+        .ok_or("Trick state not initialized")?
+        .current_trick;
+    let unchanged = trick.trick_number == request.trick_number
+        && trick.cards_played.len() == request.cards_in_trick
+        && trick.cards_played.last() == Some(&(request.player_position, request.card));
+
+    if !unchanged {
+        TakebackRepository::clear_request(redis_conn, game_id).await?;
+        if let Err(e) =
+            broadcasting::broadcast_takeback_closed(redis_conn, game_id, request, "cancelled").await
+        {
+            eprintln!("Failed to broadcast takeback cancellation: {}", e);
+        }
+        return Err("The trick has moved on since the takeback was requested".to_string());
+    }
+
+    // 2. Roll back the trick, hand and turn together
+    game.take_back(request.player_position)?;
+    let trick_state = game.tricks.as_ref().ok_or("Trick state not initialized")?;
+    let hand = &game.hands[request.player_position];
+    TakebackRepository::commit_takeback(
+        redis_conn,
+        game_id,
+        trick_state,
+        request.player_position,
+        hand,
+    )
+    .await?;
+
+    // 3. Tell the table and hand the card back to its owner
+    if let Err(e) = broadcasting::broadcast_takeback_accepted(redis_conn, game_id, request).await {
+        eprintln!("Failed to broadcast takeback: {}", e);
+    }
+
+    let hand_data = serde_json::json!({
+        "cards": hand.to_codes(),
+        "trump_counts": hand.calculate_trump_counts()
+    });
+    if let Err(e) =
+        broadcasting::broadcast_hand_update(redis_conn, game_id, &request.requester_id, &hand_data)
+            .await
+    {
+        eprintln!("Failed to broadcast hand update: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Load the players and the rules engine for a game that allows takebacks
+async fn load_table(
+    redis_conn: &mut Connection,
+    game_id: &str,
+) -> Result<(Vec<PlayerGameInfo>, SjavsGame), String> {
+    let game_match = NormalMatchRepository::get_by_id(redis_conn, game_id)
+        .await?
+        .ok_or("Game not found")?;
+
+    if !game_match.is_playing() {
+        return Err("Game is not in playing phase".to_string());
+    }
+    if !game_match.allow_takebacks {
+        return Err("Takebacks are turned off for this match".to_string());
+    }
+
+    let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
+    let trick_state = TrickStateRepository::get_trick_state(redis_conn, game_id)
+        .await?
+        .ok_or("Trick state not initialized")?;
+    let hands = GameStateRepository::get_dealt_hands(redis_conn, game_id).await?;
+    let game = game_match.to_engine(hands, Some(trick_state))?;
+
+    Ok((players, game))
+}
+
+/// Close the vote when time runs out, unless it was already settled
+fn spawn_takeback_timeout(state: &Arc<AppState>, game_id: String, request_id: String) {
+    let redis_pool = state.redis_pool.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(TAKEBACK_TIMEOUT_SECONDS)).await;
+
+        let mut conn = match redis_pool.get().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                eprintln!("Failed to get Redis connection for takeback timeout: {}", e);
+                return;
+            }
+        };
+
+        let request = match TakebackRepository::get_request(&mut conn, &game_id).await {
+            Ok(Some(request)) if request.request_id == request_id => request,
+            Ok(_) => return,
+            Err(e) => {
+                eprintln!("Failed to check takeback request: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = TakebackRepository::clear_request(&mut conn, &game_id).await {
+            eprintln!("Failed to clear takeback request: {}", e);
+            return;
+        }
+        if let Err(e) =
+            broadcasting::broadcast_takeback_closed(&mut conn, &game_id, &request, "expired").await
+        {
+            eprintln!("Failed to broadcast takeback expiry: {}", e);
+        }
+
+        bots::spawn_bot_turns(redis_pool.clone(), game_id);
+    });
+}
+
+async fn send_error(
+    state: &Arc<AppState>,
+    user_id: &str,
+    event: &str,
+    game_id: &str,
+    reason: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let error_msg = GameMessage::new(
+        event.to_string(),
+        serde_json::json!({
+            "message": reason,
+            "game_id": game_id
+        }),
+    );
+
+    if let Some(tx) = state.user_connections.get(user_id) {
+        let msg = serde_json::to_string(&error_msg)?;
+        tx.send(axum::extract::ws::Message::Text(msg)).await?;
+    }
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use crate::redis::normal_match::id::NormalMatchStatus;
+use crate::redis::normal_match::repository::NormalMatchRepository;
+use crate::redis::pubsub::broadcasting;
 use crate::websocket::handler::AppState;
 use crate::websocket::types::GameMessage;
 use deadpool_redis::Connection;
@@ -98,6 +101,20 @@ pub async fn handle_team_up_response(
             new_team_id
         };
 
+        // Seat the new partners opposite each other so the team holds when the game starts
+        if let Some(game_match) = NormalMatchRepository::get_by_id(redis_conn, game_id).await? {
+            if game_match.status == NormalMatchStatus::Waiting {
+                let mut seating = NormalMatchRepository::get_seating(redis_conn, game_id).await?;
+                seating.choose_partner(from_player_id, user_id)?;
+                NormalMatchRepository::save_seating(redis_conn, game_id, &seating).await?;
+                if let Err(e) =
+                    broadcasting::broadcast_seating_updated(redis_conn, game_id, &seating).await
+                {
+                    eprintln!("Failed to broadcast seating update: {}", e);
+                }
+            }
+        }
+
         // Notify both players about the team creation
         let team_created_msg = GameMessage::new(
             "team_created".to_string(),
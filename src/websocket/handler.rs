@@ -5,6 +5,12 @@ use crate::websocket::events::bidding::{
     handle_hand_update_event, handle_pass_made_event, handle_redeal_event,
 };
 use crate::websocket::events::join::handle_join_event;
+use crate::websocket::events::lobby::{handle_lobby_subscribe, handle_lobby_unsubscribe};
+use crate::websocket::events::moderation::{
+    handle_kick_player, handle_lock_room, handle_transfer_host,
+};
+use crate::websocket::events::ready::{handle_cancel_auto_start, handle_set_ready};
+use crate::websocket::events::takeback::{handle_takeback_request, handle_takeback_response};
 use crate::websocket::events::team_up_request::handle_team_up_request;
 use crate::websocket::events::team_up_response::handle_team_up_response;
 use crate::RedisPool;
@@ -37,6 +43,7 @@ pub struct AppState {
     pub redis_pool: RedisPool,
     pub subscribed_games: Mutex<HashSet<String>>,
     pub subscribed_players: Mutex<HashSet<String>>,
+    pub lobby_subscribers: Mutex<HashSet<UserId>>,
 }
 
 pub fn create_app_state(redis_pool: RedisPool) -> Arc<AppState> {
@@ -50,6 +57,7 @@ pub fn create_app_state(redis_pool: RedisPool) -> Arc<AppState> {
         redis_pool,
         subscribed_games: Mutex::new(HashSet::new()),
         subscribed_players: Mutex::new(HashSet::new()),
+        lobby_subscribers: Mutex::new(HashSet::new()),
     });
 
     // Start the new PubSub listener
@@ -65,8 +73,9 @@ fn start_pubsub_listener(app_state: Arc<AppState>) {
             // Get a copy of our current subscriptions
             let game_ids = app_state.subscribed_games.lock().await.clone();
             let player_ids = app_state.subscribed_players.lock().await.clone();
+            let lobby = !app_state.lobby_subscribers.lock().await.is_empty();
 
-            if game_ids.is_empty() && player_ids.is_empty() {
+            if game_ids.is_empty() && player_ids.is_empty() && !lobby {
                 // If we have no subscriptions yet, wait and try again
                 sleep(Duration::from_secs(1)).await;
                 continue;
@@ -93,17 +102,21 @@ fn start_pubsub_listener(app_state: Arc<AppState>) {
             };
 
             // Subscribe to channels and get PubSub object
-            let mut pubsub =
-                match PubSubRepository::subscribe_to_channels(connection, &game_ids, &player_ids)
-                    .await
-                {
-                    Ok(pubsub) => pubsub,
-                    Err(e) => {
-                        eprintln!("Failed to subscribe to channels: {}", e);
-                        sleep(Duration::from_secs(1)).await;
-                        continue;
-                    }
-                };
+            let mut pubsub = match PubSubRepository::subscribe_to_channels(
+                connection,
+                &game_ids,
+                &player_ids,
+                lobby,
+            )
+            .await
+            {
+                Ok(pubsub) => pubsub,
+                Err(e) => {
+                    eprintln!("Failed to subscribe to channels: {}", e);
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
 
             println!(
                 "PubSub subscribed to {} games and {} players",
@@ -116,6 +129,7 @@ fn start_pubsub_listener(app_state: Arc<AppState>) {
             let mut msg_stream = pubsub.on_message();
             let prev_game_ids = game_ids.clone();
             let prev_player_ids = player_ids.clone();
+            let lobby_channel = PubSubRepository::lobby_channel();
             loop {
                 match timeout(Duration::from_secs(1), msg_stream.next()).await {
                     Ok(Some(msg)) => {
@@ -127,11 +141,18 @@ fn start_pubsub_listener(app_state: Arc<AppState>) {
                             }
                         };
                         if let Ok(event) = serde_json::from_str::<serde_json::Value>(&payload) {
-                            if let Some(arr) = event["affected_players"].as_array() {
-                                let players: Vec<String> = arr
-                                    .iter()
-                                    .filter_map(|v| v.as_str().map(str::to_string))
-                                    .collect();
+                            // Lobby events go to everyone here watching the lobby
+                            let recipients = if msg.get_channel_name() == lobby_channel {
+                                let subscribers = app_state_clone.lobby_subscribers.lock().await;
+                                Some(subscribers.iter().cloned().collect())
+                            } else {
+                                event["affected_players"].as_array().map(|arr| {
+                                    arr.iter()
+                                        .filter_map(|v| v.as_str().map(str::to_string))
+                                        .collect::<Vec<String>>()
+                                })
+                            };
+                            if let Some(players) = recipients {
                                 let event_type =
                                     event["event"].as_str().unwrap_or("game_update").to_string();
                                 let gid = event["game_id"].as_str().unwrap_or("").to_string();
@@ -169,7 +190,9 @@ fn start_pubsub_listener(app_state: Arc<AppState>) {
                     Err(_) => {
                         let new_g = app_state.subscribed_games.lock().await.clone();
                         let new_p = app_state.subscribed_players.lock().await.clone();
-                        if new_g != prev_game_ids || new_p != prev_player_ids {
+                        let new_lobby = !app_state.lobby_subscribers.lock().await.is_empty();
+                        if new_g != prev_game_ids || new_p != prev_player_ids || new_lobby != lobby
+                        {
                             break; // subscription set changed, re-subscribe
                         }
                     }
@@ -295,6 +318,96 @@ pub async fn handle_socket(socket: WebSocket, user_id: String, state: Arc<AppSta
                             eprintln!("Team up response error: {}", e);
                         }
                     }
+                    "lobby_subscribe" => {
+                        if let Err(e) =
+                            handle_lobby_subscribe(&state, &handler_user_id, &mut redis_conn).await
+                        {
+                            eprintln!("Lobby subscribe error: {}", e);
+                        }
+                    }
+                    "lobby_unsubscribe" => {
+                        handle_lobby_unsubscribe(&state, &handler_user_id).await;
+                    }
+                    "kick_player" => {
+                        if let Err(e) = handle_kick_player(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Kick player error: {}", e);
+                        }
+                    }
+                    "transfer_host" => {
+                        if let Err(e) = handle_transfer_host(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Transfer host error: {}", e);
+                        }
+                    }
+                    "lock_room" => {
+                        if let Err(e) = handle_lock_room(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Lock room error: {}", e);
+                        }
+                    }
+                    "set_ready" => {
+                        if let Err(e) = handle_set_ready(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Set ready error: {}", e);
+                        }
+                    }
+                    "cancel_auto_start" => {
+                        if let Err(e) =
+                            handle_cancel_auto_start(&state, &handler_user_id, &mut redis_conn)
+                                .await
+                        {
+                            eprintln!("Cancel auto-start error: {}", e);
+                        }
+                    }
+                    "takeback_request" => {
+                        if let Err(e) = handle_takeback_request(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Takeback request error: {}", e);
+                        }
+                    }
+                    "takeback_response" => {
+                        if let Err(e) = handle_takeback_response(
+                            &state,
+                            &handler_user_id,
+                            &game_msg.data,
+                            &mut redis_conn,
+                        )
+                        .await
+                        {
+                            eprintln!("Takeback response error: {}", e);
+                        }
+                    }
                     "bid_made" => {
                         if let Err(e) =
                             handle_bid_made_event(&state, &game_msg.data, &mut redis_conn).await
@@ -354,11 +467,15 @@ pub async fn handle_socket(socket: WebSocket, user_id: String, state: Arc<AppSta
     // Remove user from connection registry
     state.user_connections.remove(&cleanup_user_id);
 
-    // Remove user from subscribed players
+    // Remove user from subscribed players and the lobby
     {
         let mut subscribed_players = state.subscribed_players.lock().await;
         subscribed_players.remove(&cleanup_user_id);
     }
+    {
+        let mut lobby_subscribers = state.lobby_subscribers.lock().await;
+        lobby_subscribers.remove(&cleanup_user_id);
+    }
 
     // Remove user from any games they were in and check if game subscriptions can be cleaned up
     let mut games_to_remove = Vec::new();
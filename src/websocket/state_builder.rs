@@ -1,6 +1,6 @@
 use crate::redis::normal_match::id::{NormalMatch, NormalMatchStatus};
 use crate::redis::normal_match::repository::NormalMatchRepository;
-use crate::redis::player::repository::PlayerRepository;
+use crate::redis::player::repository::{player_in_seat, PlayerRepository};
 use crate::websocket::timestamp::TimestampManager;
 use crate::websocket::types::*;
 use deadpool_redis::Connection;
@@ -36,6 +36,12 @@ impl StateBuilder {
             current_cross: game_match.current_cross,
             created_timestamp: game_match.created_timestamp,
             host: host_id.unwrap_or_default(),
+            rules: game_match.rules.clone(),
+            allow_takebacks: game_match.allow_takebacks,
+            allow_hints: game_match.allow_hints,
+            is_public: game_match.is_public,
+            is_locked: game_match.is_locked,
+            auto_start: game_match.auto_start,
         };
 
         // Get all players in the game
@@ -108,17 +114,13 @@ impl StateBuilder {
         user_id: &str,
         redis_conn: &mut Connection,
     ) -> Result<u8, Box<dyn std::error::Error + Send + Sync>> {
-        // This would be implemented based on your position storage system
-        // For now, return a placeholder - you'll need to implement based on your Redis schema
-        let position_key = format!("game_positions:{}", game_id);
-        let position: Option<u8> = redis::cmd("HGET")
-            .arg(&position_key)
-            .arg(user_id)
-            .query_async(redis_conn)
-            .await
-            .unwrap_or(None);
+        // Seats chosen in the waiting room are the positions at the table
+        let seating = NormalMatchRepository::get_seating(redis_conn, game_id).await?;
 
-        position.ok_or_else(|| "Player position not found".into())
+        seating
+            .seat_of(user_id)
+            .map(|seat| seat as u8)
+            .ok_or_else(|| "Player position not found".into())
     }
 
     /// Check if user is the host of the game
@@ -194,9 +196,15 @@ impl StateBuilder {
             .await
             .unwrap_or(0);
 
-        // Game can be started if host has 4 players and game is in waiting status
-        let can_start_game =
-            is_host && player_count >= 4 && common_state.match_info.status == "Waiting";
+        // Who still has to get ready, and whether the game is counting down to start
+        let ready_check = NormalMatchRepository::get_ready_check(redis_conn, game_id).await?;
+        let auto_start_at = NormalMatchRepository::get_countdown(redis_conn, game_id).await?;
+
+        // Game can be started if host has 4 players, all ready, and game is in waiting status
+        let can_start_game = is_host
+            && player_count >= 4
+            && ready_check.everyone_ready()
+            && common_state.match_info.status == "Waiting";
 
         let players_needed = if player_count >= 4 {
             0
@@ -209,6 +217,10 @@ impl StateBuilder {
             can_start_game,
             players_needed,
             is_host,
+            is_ready: ready_check.is_ready(user_id),
+            ready_players: ready_check.ready,
+            waiting_for: ready_check.waiting_for,
+            auto_start_at,
         })
     }
 
@@ -384,7 +396,8 @@ impl StateBuilder {
                     Self::get_player_hand(game_id, position as usize, redis_conn).await?
                 {
                     let current_highest = game_match.highest_bid_length;
-                    let available_bids = hand.get_available_bids(current_highest);
+                    let available_bids =
+                        hand.get_available_bids(current_highest, game_match.rules.min_bid);
                     let is_turn = position == current_bidder;
 
                     let player_hand_data = PlayerHand {
@@ -492,8 +505,8 @@ impl StateBuilder {
 
         let players = PlayerRepository::get_players_in_game(redis_conn, game_id).await?;
 
-        if player_position < players.len() {
-            let user_id = &players[player_position].user_id;
+        if let Some(player) = player_in_seat(&players, player_position) {
+            let user_id = &player.user_id;
             let username: String = redis::cmd("HGET")
                 .arg("usernames")
                 .arg(user_id)
@@ -653,26 +666,26 @@ impl StateBuilder {
 
         // Build trump team player info
         for pos in trump_team_positions {
-            if pos < players.len() {
-                let username = Self::get_username(&players[pos].user_id, redis_conn).await?;
+            if let Some(player) = player_in_seat(&players, pos) {
+                let username = Self::get_username(&player.user_id, redis_conn).await?;
                 trump_team.push(PlayerInfo {
-                    user_id: players[pos].user_id.clone(),
+                    user_id: player.user_id.clone(),
                     username,
                     position: Some(pos as u8),
-                    role: players[pos].role.clone(),
+                    role: player.role.clone(),
                 });
             }
         }
 
         // Build opponent team player info
         for pos in opponent_team_positions {
-            if pos < players.len() {
-                let username = Self::get_username(&players[pos].user_id, redis_conn).await?;
+            if let Some(player) = player_in_seat(&players, pos) {
+                let username = Self::get_username(&player.user_id, redis_conn).await?;
                 opponent_team.push(PlayerInfo {
-                    user_id: players[pos].user_id.clone(),
+                    user_id: player.user_id.clone(),
                     username,
                     position: Some(pos as u8),
-                    role: players[pos].role.clone(),
+                    role: player.role.clone(),
                 });
             }
         }
@@ -800,7 +813,7 @@ impl StateBuilder {
         let final_scores = Self::get_final_game_results(game_id, &game_match, redis_conn).await?;
 
         // Get cross/rubber scores
-        let cross_scores = Self::get_cross_scores(game_id, redis_conn).await?;
+        let cross_scores = Self::get_cross_scores(&game_match, redis_conn).await?;
 
         // Build winner information
         let winner_info = Self::build_winner_info(&final_scores, &game_match, redis_conn).await?;
@@ -879,36 +892,38 @@ impl StateBuilder {
 
     /// Get cross/rubber scores
     async fn get_cross_scores(
-        game_id: &str,
+        game_match: &NormalMatch,
         redis_conn: &mut Connection,
     ) -> Result<CrossScores, Box<dyn std::error::Error + Send + Sync>> {
         use crate::redis::cross_state::repository::CrossStateRepository;
 
+        let starting_score = game_match.rules.cross_start as i8;
+
         // Try to get cross state from Redis
-        match CrossStateRepository::get_cross_state(redis_conn, game_id).await {
+        match CrossStateRepository::get_cross_state(redis_conn, &game_match.id).await {
             Ok(Some(cross_state)) => Ok(CrossScores {
-                trump_team_remaining: cross_state.trump_team_score,
-                opponent_team_remaining: cross_state.opponent_team_score,
-                trump_team_crosses: cross_state.trump_team_crosses,
-                opponent_team_crosses: cross_state.opponent_team_crosses,
+                team_a_remaining: cross_state.trump_team_score,
+                team_b_remaining: cross_state.opponent_team_score,
+                team_a_crosses: cross_state.trump_team_crosses,
+                team_b_crosses: cross_state.opponent_team_crosses,
             }),
             Ok(None) => {
                 // No cross state found - create default scores
                 Ok(CrossScores {
-                    trump_team_remaining: 24,
-                    opponent_team_remaining: 24,
-                    trump_team_crosses: 0,
-                    opponent_team_crosses: 0,
+                    team_a_remaining: starting_score,
+                    team_b_remaining: starting_score,
+                    team_a_crosses: 0,
+                    team_b_crosses: 0,
                 })
             }
             Err(e) => {
                 eprintln!("Failed to get cross state: {}", e);
                 // Return default scores on error
                 Ok(CrossScores {
-                    trump_team_remaining: 24,
-                    opponent_team_remaining: 24,
-                    trump_team_crosses: 0,
-                    opponent_team_crosses: 0,
+                    team_a_remaining: starting_score,
+                    team_b_remaining: starting_score,
+                    team_a_crosses: 0,
+                    team_b_crosses: 0,
                 })
             }
         }
@@ -933,26 +948,24 @@ impl StateBuilder {
             let players = PlayerRepository::get_players_in_game(redis_conn, &game_match.id).await?;
 
             // Add trump declarer
-            if trump_declarer < players.len() {
-                let username =
-                    Self::get_username(&players[trump_declarer].user_id, redis_conn).await?;
+            if let Some(player) = player_in_seat(&players, trump_declarer) {
+                let username = Self::get_username(&player.user_id, redis_conn).await?;
                 winning_players.push(PlayerInfo {
-                    user_id: players[trump_declarer].user_id.clone(),
+                    user_id: player.user_id.clone(),
                     username,
                     position: Some(trump_declarer as u8),
-                    role: players[trump_declarer].role.clone(),
+                    role: player.role.clone(),
                 });
             }
 
             // Add partner
-            if partner_position < players.len() {
-                let username =
-                    Self::get_username(&players[partner_position].user_id, redis_conn).await?;
+            if let Some(player) = player_in_seat(&players, partner_position) {
+                let username = Self::get_username(&player.user_id, redis_conn).await?;
                 winning_players.push(PlayerInfo {
-                    user_id: players[partner_position].user_id.clone(),
+                    user_id: player.user_id.clone(),
                     username,
                     position: Some(partner_position as u8),
-                    role: players[partner_position].role.clone(),
+                    role: player.role.clone(),
                 });
             }
 
@@ -971,13 +984,13 @@ impl StateBuilder {
             let players = PlayerRepository::get_players_in_game(redis_conn, &game_match.id).await?;
 
             for pos in opponent_positions {
-                if pos < players.len() {
-                    let username = Self::get_username(&players[pos].user_id, redis_conn).await?;
+                if let Some(player) = player_in_seat(&players, pos) {
+                    let username = Self::get_username(&player.user_id, redis_conn).await?;
                     winning_players.push(PlayerInfo {
-                        user_id: players[pos].user_id.clone(),
+                        user_id: player.user_id.clone(),
                         username,
                         position: Some(pos as u8),
-                        role: players[pos].role.clone(),
+                        role: player.role.clone(),
                     });
                 }
             }
@@ -1002,7 +1015,7 @@ impl StateBuilder {
 
         // Check if rubber is complete (either team has won the rubber)
         let rubber_complete =
-            cross_scores.trump_team_remaining <= 0 || cross_scores.opponent_team_remaining <= 0;
+            cross_scores.team_a_remaining <= 0 || cross_scores.team_b_remaining <= 0;
 
         // Can start new game if:
         // 1. User is the host AND
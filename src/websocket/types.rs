@@ -1,3 +1,4 @@
+use crate::game::rules::RuleSet;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -69,6 +70,12 @@ pub struct MatchInfo {
     pub current_cross: u32,
     pub created_timestamp: u64,
     pub host: String,
+    pub rules: RuleSet,
+    pub allow_takebacks: bool,
+    pub allow_hints: bool,
+    pub is_public: bool,
+    pub is_locked: bool,
+    pub auto_start: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -86,6 +93,10 @@ pub struct WaitingStateData {
     pub can_start_game: bool,
     pub players_needed: u8,
     pub is_host: bool,
+    pub is_ready: bool,
+    pub ready_players: Vec<String>, // Bots included
+    pub waiting_for: Vec<String>,   // Players who haven't marked themselves ready
+    pub auto_start_at: Option<i64>, // When a running auto-start countdown ends (ms)
 }
 
 /// Dealing phase state (cards being dealt)
@@ -223,12 +234,13 @@ pub struct GameResult {
     pub individual_vol: bool,
 }
 
+/// Cross scores per partnership: team A is seats 0 & 2, team B seats 1 & 3
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CrossScores {
-    pub trump_team_remaining: i8,
-    pub opponent_team_remaining: i8,
-    pub trump_team_crosses: u8,
-    pub opponent_team_crosses: u8,
+    pub team_a_remaining: i8,
+    pub team_b_remaining: i8,
+    pub team_a_crosses: u8,
+    pub team_b_crosses: u8,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]